@@ -3,16 +3,25 @@
 
 use std::{fmt, sync::Arc};
 
+use rustc_hash::{FxHashMap, FxHashSet};
 use salsa::Durability;
 use vfs::FileId;
 
-use crate::{SourceDatabase, SourceRoot, SourceRootId};
+use syntax::{TextRange, TextSize};
+use text_edit::Indel;
+
+use crate::{Dialect, ReparseBase, SourceDatabase, SourceRoot, SourceRootId};
 
 /// Encapsulate a bunch of raw `.set` calls on the database.
 #[derive(Default)]
 pub struct Change {
     pub roots: Option<Vec<SourceRoot>>,
     pub files_changed: Vec<(FileId, Option<Arc<String>>)>,
+    /// The changed files whose new text is an edit of the previous one, so
+    /// they are reparsed incrementally.
+    pub files_edited: FxHashSet<FileId>,
+    pub dialect: Option<Dialect>,
+    pub named_addresses: Option<FxHashMap<String, String>>,
 }
 
 impl fmt::Debug for Change {
@@ -24,6 +33,12 @@ impl fmt::Debug for Change {
         if !self.files_changed.is_empty() {
             d.field("files_changed", &self.files_changed.len());
         }
+        if let Some(dialect) = &self.dialect {
+            d.field("dialect", dialect);
+        }
+        if let Some(named_addresses) = &self.named_addresses {
+            d.field("named_addresses", &named_addresses.len());
+        }
         d.finish()
     }
 }
@@ -41,16 +56,47 @@ impl Change {
         self.files_changed.push((file_id, new_text))
     }
 
+    /// Like `change_file`, for a file which already has a text in the
+    /// database, like a file open in the editor. Its new text is reparsed
+    /// from its previous parse, around the part which changed.
+    pub fn edit_file(&mut self, file_id: FileId, new_text: Arc<String>) {
+        self.files_edited.insert(file_id);
+        self.files_changed.push((file_id, Some(new_text)))
+    }
+
+    pub fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect = Some(dialect);
+    }
+
+    pub fn set_named_addresses(&mut self, named_addresses: FxHashMap<String, String>) {
+        self.named_addresses = Some(named_addresses);
+    }
+
     pub fn apply(self, db: &mut dyn SourceDatabase) {
+        if let Some(dialect) = self.dialect {
+            db.set_dialect_with_durability(dialect, Durability::HIGH);
+        }
+        if let Some(named_addresses) = self.named_addresses {
+            db.set_named_addresses_with_durability(Arc::new(named_addresses), Durability::HIGH);
+        }
         if let Some(roots) = self.roots {
+            let mut local_roots = FxHashSet::default();
+            let mut library_roots = FxHashSet::default();
             for (idx, root) in roots.into_iter().enumerate() {
                 let root_id = SourceRootId(idx as u32);
+                if root.is_library {
+                    library_roots.insert(root_id);
+                } else {
+                    local_roots.insert(root_id);
+                }
                 let durability = durability(&root);
                 for file_id in root.iter() {
                     db.set_file_source_root_with_durability(file_id, root_id, durability);
                 }
                 db.set_source_root_with_durability(root_id, Arc::new(root), durability);
             }
+            db.set_local_roots_with_durability(Arc::new(local_roots), Durability::HIGH);
+            db.set_library_roots_with_durability(Arc::new(library_roots), Durability::HIGH);
         }
 
         for (file_id, text) in self.files_changed {
@@ -59,11 +105,42 @@ impl Change {
             let durability = durability(&source_root);
             // XXX: can't actually remove the file, just reset the text
             let text = text.unwrap_or_default();
+            let base = if self.files_edited.contains(&file_id) {
+                let parse = db.parse(file_id);
+                let edit = diff(&db.file_text(file_id), &text);
+                Some(Arc::new(ReparseBase { parse, edit }))
+            } else {
+                None
+            };
+            db.set_file_reparse_base_with_durability(file_id, base, durability);
             db.set_file_text_with_durability(file_id, text, durability)
         }
     }
 }
 
+/// The edit which turns `old` into `new`, replacing the text between their
+/// common prefix and their common suffix.
+fn diff(old: &str, new: &str) -> Indel {
+    let mut prefix = old.bytes().zip(new.bytes()).take_while(|(a, b)| a == b).count();
+    while !old.is_char_boundary(prefix) || !new.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let mut suffix = old
+        .bytes()
+        .rev()
+        .zip(new.bytes().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !old.is_char_boundary(old.len() - suffix) || !new.is_char_boundary(new.len() - suffix) {
+        suffix -= 1;
+    }
+    let delete =
+        TextRange::new(TextSize::from(prefix as u32), TextSize::from((old.len() - suffix) as u32));
+    Indel::replace(delete, new[prefix..new.len() - suffix].to_string())
+}
+
 fn durability(source_root: &SourceRoot) -> Durability {
     if source_root.is_library {
         Durability::HIGH
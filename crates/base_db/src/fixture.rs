@@ -1,6 +1,8 @@
 //! A set of high-level utility fixture methods to use in tests.
 use std::{mem, sync::Arc};
 
+use rustc_hash::FxHashMap;
+
 use crate::change::Change;
 use crate::input::{SourceRoot, SourceRootId};
 use crate::{FilePosition, FileRange, SourceDatabase};
@@ -80,6 +82,7 @@ impl ChangeFixture {
         let mut roots = Vec::new();
 
         let mut file_position = None;
+        let mut named_addresses = FxHashMap::default();
 
         for entry in fixture {
             let text = if entry.text.contains(CURSOR_MARKER) {
@@ -95,6 +98,7 @@ impl ChangeFixture {
                 entry.text.clone()
             };
 
+            named_addresses.extend(entry.addresses.clone());
             let meta = FileMeta::from(entry);
             assert!(meta.path.starts_with(&source_root_prefix));
             if !meta.deps.is_empty() {
@@ -123,6 +127,9 @@ impl ChangeFixture {
         };
         roots.push(root);
         change.set_roots(roots);
+        if !named_addresses.is_empty() {
+            change.set_named_addresses(named_addresses);
+        }
 
         ChangeFixture { file_position, files, change }
     }
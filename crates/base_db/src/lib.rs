@@ -3,10 +3,13 @@ mod input;
 mod change;
 pub mod fixture;
 
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::{panic, sync::Arc};
 
 use syntax::{ast, Parse, SourceFile, TextRange, TextSize};
+use text_edit::Indel;
+
+pub use syntax::Dialect;
 
 pub use crate::input::{SourceRoot, SourceRootId};
 pub use change::Change;
@@ -56,6 +59,11 @@ pub trait SourceDatabase: std::fmt::Debug {
     #[salsa::input]
     fn file_text(&self, file_id: FileId) -> Arc<String>;
 
+    /// The parse of the previous text of the file and the edit which turned
+    /// it into the current text, if the file was edited.
+    #[salsa::input]
+    fn file_reparse_base(&self, file_id: FileId) -> Option<Arc<ReparseBase>>;
+
     /// Path to a file, relative to the root of its source root.
     /// Source root of the file.
     #[salsa::input]
@@ -74,9 +82,35 @@ pub trait SourceDatabase: std::fmt::Debug {
     /// Files in libraries are assumed to never change.
     #[salsa::input]
     fn library_roots(&self) -> Arc<FxHashSet<SourceRootId>>;
+
+    /// Dialect of the workspace, from the `dialect` key of `Dove.toml`.
+    #[salsa::input]
+    fn dialect(&self) -> Dialect;
+
+    /// Named addresses of the workspace and their values, as written in the
+    /// `[addresses]` table of `Dove.toml`.
+    #[salsa::input]
+    fn named_addresses(&self) -> Arc<FxHashMap<String, String>>;
+}
+
+/// The previous parse of an edited file, which the next parse reparses
+/// incrementally instead of parsing the new text from scratch.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReparseBase {
+    pub parse: Parse<ast::SourceFile>,
+    pub edit: Indel,
 }
 
 fn parse_query(db: &dyn SourceDatabase, file_id: FileId) -> Parse<ast::SourceFile> {
     let text = db.file_text(file_id);
-    SourceFile::parse(&*text)
+    let dialect = db.dialect();
+    if let Some(base) = db.file_reparse_base(file_id) {
+        // The dialect may have changed since the base was parsed.
+        if base.parse.dialect() == Some(dialect) {
+            let parse = base.parse.reparse(&base.edit);
+            debug_assert_eq!(parse.syntax_node().text(), text.as_str());
+            return parse;
+        }
+    }
+    SourceFile::parse_with_dialect(&*text, dialect)
 }
@@ -98,7 +98,7 @@ impl GrammarNodeField {
 pub(crate) fn lower(grammar: &Grammar) -> GrammarAST {
     let mut grammar_ast = GrammarAST::default();
 
-    grammar_ast.tokens = "Whitespace Comment HexString ByteString IntegerNumber"
+    grammar_ast.tokens = "Whitespace Comment DocComment HexString ByteString IntegerNumber"
         .split_ascii_whitespace()
         .map(|it| it.to_string())
         .collect::<Vec<_>>();
@@ -189,21 +189,18 @@ fn lower_rule(
         }
         Rule::Labeled { label: l, rule } => {
             assert!(label.is_none());
-            // let manually_implemented = matches!(
-            //     l.as_str(),
-            //         "then_branch"
-            //         | "else_branch"
-            //         | "start"
-            //         | "end"
-            //         | "index"
-            //         | "base"
-            //         | "value"
-            //         | "trait"
-            //         | "self_ty"
-            // );
-            // if manually_implemented {
-            //     return;
-            // }
+            // Several `Expr` children of one node can only be told apart by
+            // position, so their accessors are written by hand.
+            let is_expr = matches!(&**rule, Rule::Node(it) if grammar[*it].name == "Expr");
+            let manually_implemented = match l.as_str() {
+                "op" => true,
+                "lhs" | "rhs" | "then_branch" | "else_branch" | "body" | "base" | "index"
+                | "predicate" => is_expr,
+                _ => false,
+            };
+            if manually_implemented {
+                return;
+            }
             lower_rule(acc, grammar, Some(l), rule);
         }
         Rule::Seq(rules) | Rule::Alt(rules) => {
@@ -215,7 +212,7 @@ fn lower_rule(
     }
 }
 
-// (T (',' T)* ','?)
+// (T (',' T)* ','?), or (T ('+' T)*) without the trailing separator
 fn lower_comma_list(
     acc: &mut Vec<GrammarNodeField>,
     grammar: &Grammar,
@@ -228,8 +225,9 @@ fn lower_comma_list(
     };
     let (node, repeat, trailing_comma) = match rule.as_slice() {
         [Rule::Node(node), Rule::Rep(repeat), Rule::Opt(trailing_comma)] => {
-            (node, repeat, trailing_comma)
+            (node, repeat, Some(trailing_comma))
         }
+        [Rule::Node(node), Rule::Rep(repeat)] => (node, repeat, None),
         _ => return false,
     };
     let repeat = match &**repeat {
@@ -237,7 +235,13 @@ fn lower_comma_list(
         _ => return false,
     };
     match repeat.as_slice() {
-        [comma, Rule::Node(n)] if comma == &**trailing_comma && n == node => (),
+        [comma, Rule::Node(n)] if n == node => {
+            if let Some(trailing_comma) = trailing_comma {
+                if comma != &**trailing_comma {
+                    return false;
+                }
+            }
+        }
         _ => return false,
     }
     let ty = grammar[*node].name.clone();
@@ -290,20 +294,33 @@ fn extract_enums(ast: &mut GrammarAST) {
 
 fn extract_struct_traits(ast: &mut GrammarAST) {
     let traits: &[(&str, &[&str])] = &[
-        ("AttrsOwner", &["attrs"]),
-        ("NameOwner", &["name"]),
-        ("VisibilityOwner", &["visibility"]),
-        ("GenericParamsOwner", &["generic_param_list", "where_clause"]),
-        ("TypeBoundsOwner", &["type_bound_list", "colon_token"]),
-        ("ModuleItemOwner", &["items"]),
-        ("LoopBodyOwner", &["label", "loop_body"]),
-        ("ArgListOwner", &["arg_list"]),
+        ("HasAttrs", &["attr_lists"]),
+        ("HasName", &["name"]),
+        ("HasVisibility", &["visibility"]),
+        ("HasTypeParams", &["generic_param_list"]),
+        ("HasModuleItem", &["items"]),
+    ];
+
+    // Doc comments are trivia, so there are no fields to extract them from.
+    let nodes_with_doc_comments = [
+        "AddressDef",
+        "ModuleDef",
+        "ScriptDef",
+        "FunctionDef",
+        "Struct",
+        "RecordField",
+        "ConstDef",
+        "SpecDef",
+        "SpecSchema",
     ];
 
     for node in &mut ast.nodes {
         for (name, methods) in traits {
             extract_struct_trait(node, name, methods);
         }
+        if nodes_with_doc_comments.contains(&node.name.as_str()) {
+            node.traits.push("HasDocComments".to_string());
+        }
     }
 }
 
@@ -323,9 +340,6 @@ fn extract_struct_trait(node: &mut GrammarNode, trait_name: &str, methods: &[&st
 
 fn extract_enum_traits(ast: &mut GrammarAST) {
     for enm in &mut ast.enums {
-        if enm.name == "Stmt" {
-            continue;
-        }
         let nodes = &ast.nodes;
         let mut variant_traits = enm
             .variants
@@ -8,10 +8,10 @@ pub(crate) fn generate_nodes(kinds: SymbolKindsSrc<'_>, grammar: &GrammarAST) ->
             let grammar_name = format_ident!("{}", node.name);
             let syntax_kind_name = format_ident!("{}", to_upper_snake_case(&node.name));
 
-            // let traits = node.traits.iter().map(|trait_name| {
-            //     let trait_name = format_ident!("{}", trait_name);
-            //     quote!(impl ast::#trait_name for #grammar_name {})
-            // });
+            let traits = node.traits.iter().map(|trait_name| {
+                let trait_name = format_ident!("{}", trait_name);
+                quote!(impl ast::#trait_name for #grammar_name {})
+            });
 
             let methods = node.fields.iter().map(|field| {
                 let method_name = field.method_name();
@@ -44,7 +44,7 @@ pub(crate) fn generate_nodes(kinds: SymbolKindsSrc<'_>, grammar: &GrammarAST) ->
                     pub(crate) syntax: SyntaxNode,
                 }
 
-                // #(#traits)*
+                #(#traits)*
 
                 impl #grammar_name {
                     #(#methods)*
@@ -80,33 +80,29 @@ pub(crate) fn generate_nodes(kinds: SymbolKindsSrc<'_>, grammar: &GrammarAST) ->
                 quote!(impl ast::#trait_name for #name {})
             });
 
-            let ast_node = if en.name == "Stmt" {
-                quote! {}
-            } else {
-                quote! {
-                    impl AstNode for #name {
-                        fn can_cast(kind: SyntaxKind) -> bool {
-                            matches!(kind, #(#kinds)|*)
-                            // match kind {
-                            //     #(#kinds)|* => true,
-                            //     _ => false,
-                            // }
-                        }
-                        fn cast(syntax: SyntaxNode) -> Option<Self> {
-                            let res = match syntax.kind() {
-                                #(
-                                #kinds => #name::#variants(#variants { syntax }),
-                                )*
-                                _ => return None,
-                            };
-                            Some(res)
-                        }
-                        fn syntax(&self) -> &SyntaxNode {
-                            match self {
-                                #(
-                                #name::#variants(it) => &it.syntax,
-                                )*
-                            }
+            let ast_node = quote! {
+                impl AstNode for #name {
+                    fn can_cast(kind: SyntaxKind) -> bool {
+                        matches!(kind, #(#kinds)|*)
+                        // match kind {
+                        //     #(#kinds)|* => true,
+                        //     _ => false,
+                        // }
+                    }
+                    fn cast(syntax: SyntaxNode) -> Option<Self> {
+                        let res = match syntax.kind() {
+                            #(
+                            #kinds => #name::#variants(#variants { syntax }),
+                            )*
+                            _ => return None,
+                        };
+                        Some(res)
+                    }
+                    fn syntax(&self) -> &SyntaxNode {
+                        match self {
+                            #(
+                            #name::#variants(it) => &it.syntax,
+                            )*
                         }
                     }
                 }
@@ -0,0 +1,153 @@
+//! Extracts the `// test name` and `// test_err name` examples from the
+//! comments of the parser grammar into `crates/syntax/test_data/parser/inline`,
+//! where the syntax tests check them against their tree dumps.
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use test_utils::{project_root, try_ensure_file_contents};
+
+#[derive(Debug)]
+struct Test {
+    name: String,
+    text: String,
+    ok: bool,
+}
+
+#[test]
+fn generate_parser_inline_tests() {
+    let grammar_dir = project_root().join("crates/parser/src/grammar");
+    let tests = collect_tests(&grammar_dir);
+
+    let inline_dir = project_root().join("crates/syntax/test_data/parser/inline");
+    let ok = tests.values().filter(|t| t.ok);
+    let err = tests.values().filter(|t| !t.ok);
+    let mut up_to_date = true;
+    up_to_date &= install_tests(ok, &inline_dir.join("ok"));
+    up_to_date &= install_tests(err, &inline_dir.join("err"));
+    if !up_to_date {
+        panic!("Some inline parser tests were not up-to-date");
+    }
+}
+
+fn collect_tests(grammar_dir: &Path) -> BTreeMap<String, Test> {
+    let mut res: BTreeMap<String, Test> = BTreeMap::new();
+    for path in rust_files(grammar_dir) {
+        let text = fs::read_to_string(&path).unwrap();
+        for test in tests_from_text(&text) {
+            if let Some(existing) = res.get(&test.name) {
+                panic!("Duplicate inline test `{}`:\n{}\n{}", test.name, existing.text, test.text);
+            }
+            res.insert(test.name.clone(), test);
+        }
+    }
+    res
+}
+
+fn tests_from_text(text: &str) -> Vec<Test> {
+    let mut res = Vec::new();
+    for block in comment_blocks(text) {
+        let (first, rest) = match block.split_first() {
+            Some(it) => it,
+            None => continue,
+        };
+        let (name, ok) = if let Some(name) = first.strip_prefix("test ") {
+            (name, true)
+        } else if let Some(name) = first.strip_prefix("test_err ") {
+            (name, false)
+        } else {
+            continue;
+        };
+        let mut text = rest.join("\n");
+        text.push('\n');
+        res.push(Test { name: name.trim().to_string(), text, ok });
+    }
+    res
+}
+
+/// Groups consecutive `//` comment lines, with the comment markers stripped.
+fn comment_blocks(text: &str) -> Vec<Vec<String>> {
+    let mut res = Vec::new();
+    let mut block = Vec::new();
+    for line in text.lines() {
+        let line = line.trim_start();
+        let is_comment = line.starts_with("//") && !line.starts_with("///");
+        if is_comment {
+            let content = line["//".len()..].strip_prefix(' ').unwrap_or(&line["//".len()..]);
+            block.push(content.to_string());
+        } else if !block.is_empty() {
+            res.push(std::mem::take(&mut block));
+        }
+    }
+    if !block.is_empty() {
+        res.push(block);
+    }
+    res
+}
+
+/// Writes the tests as `NNNN_name.move` files, keeping the numbers of the
+/// tests which already exist. Returns `false` if any file had to be changed.
+fn install_tests<'a>(tests: impl Iterator<Item = &'a Test>, dir: &Path) -> bool {
+    let existing = existing_tests(dir);
+    let mut next_idx = existing.values().map(|(idx, _)| *idx).max().unwrap_or(0) + 1;
+    let mut up_to_date = true;
+    let mut seen = Vec::new();
+    for test in tests {
+        let path = match existing.get(&test.name) {
+            Some((_, path)) => path.clone(),
+            None => {
+                let path = dir.join(format!("{:04}_{}.move", next_idx, test.name));
+                next_idx += 1;
+                path
+            }
+        };
+        up_to_date &= try_ensure_file_contents(&path, &test.text).is_ok();
+        seen.push(test.name.as_str());
+    }
+    for (name, (_, path)) in existing.iter() {
+        if !seen.contains(&name.as_str()) {
+            panic!(
+                "Inline test `{}` is gone from the grammar, delete {} and its dump",
+                name,
+                path.display()
+            );
+        }
+    }
+    up_to_date
+}
+
+fn existing_tests(dir: &Path) -> BTreeMap<String, (usize, PathBuf)> {
+    let mut res = BTreeMap::new();
+    let entries = match fs::read_dir(dir) {
+        Ok(it) => it,
+        Err(_) => return res,
+    };
+    for entry in entries {
+        let path = entry.unwrap().path();
+        if path.extension().unwrap_or_default() != "move" {
+            continue;
+        }
+        let file_name = path.file_stem().unwrap().to_str().unwrap();
+        let (idx, name) = file_name.split_once('_').unwrap();
+        let idx: usize = idx.parse().unwrap();
+        res.insert(name.to_string(), (idx, path));
+    }
+    res
+}
+
+fn rust_files(dir: &Path) -> Vec<PathBuf> {
+    let mut res = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().unwrap_or_default() == "rs" {
+                res.push(path);
+            }
+        }
+    }
+    res.sort();
+    res
+}
@@ -2,6 +2,7 @@
 
 mod ast;
 mod generate_all;
+mod inline_tests;
 mod syntax_kind;
 mod utils;
 
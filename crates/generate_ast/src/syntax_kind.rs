@@ -7,7 +7,7 @@ use stdx::to_upper_snake_case;
 pub(crate) struct SymbolKindsSrc<'a> {
     pub(crate) punct: &'a [(&'a str, &'a str)],
     pub(crate) keywords: &'a [&'a str],
-    // pub(crate) contextual_keywords: &'a [&'a str],
+    pub(crate) contextual_keywords: &'a [&'a str],
     pub(crate) literals: &'a [&'a str],
     pub(crate) tokens: &'a [&'a str],
     pub(crate) nodes: &'a [&'a str],
@@ -56,7 +56,43 @@ pub(crate) const KINDS_SRC: SymbolKindsSrc = SymbolKindsSrc {
     ],
     keywords: &[
         "struct", "script", "module", "const", "use", "as", "let", "mut", "return", "fun", "true",
-        "false", "move", "while", "if", "else", "break", "continue", "address",
+        "false", "move", "while", "if", "else", "break", "continue", "address", "public", "native",
+        "friend", "copy", "acquires", "loop", "abort",
+    ],
+    contextual_keywords: &[
+        "entry",
+        "has",
+        "phantom",
+        "vector",
+        "spec",
+        "schema",
+        "pragma",
+        "include",
+        "local",
+        "global",
+        "requires",
+        "ensures",
+        "aborts_if",
+        "aborts_with",
+        "succeeds_if",
+        "modifies",
+        "invariant",
+        "assert",
+        "assume",
+        "decreases",
+        "emits",
+        "axiom",
+        "update",
+        "pack",
+        "unpack",
+        "post",
+        "to",
+        "with",
+        "forall",
+        "exists",
+        "choose",
+        "where",
+        "in",
     ],
     literals: &["INTEGER_NUMBER", "BYTE_STRING", "HEX_STRING"],
     nodes: &[
@@ -65,6 +101,7 @@ pub(crate) const KINDS_SRC: SymbolKindsSrc = SymbolKindsSrc {
         "ADDRESS_ITEM_LIST",
         "MODULE_DEF",
         "ADDRESS_IDENT",
+        "NAMED_ADDRESS",
         "SCRIPT_DEF",
         "FUNCTION_DEF",
         "ITEM_LIST",
@@ -74,8 +111,9 @@ pub(crate) const KINDS_SRC: SymbolKindsSrc = SymbolKindsSrc {
         "RECORD_FIELD",
         "GENERIC_PARAM_LIST",
         "TYPE_PARAM",
-        "TYPE_BOUND_LIST",
-        "TYPE_BOUND",
+        "ABILITY_BOUND_LIST",
+        "ABILITY_LIST",
+        "ABILITY",
         "PARAM_LIST",
         "PARAM",
         "RET_TYPE",
@@ -90,15 +128,32 @@ pub(crate) const KINDS_SRC: SymbolKindsSrc = SymbolKindsSrc {
         "LITERAL",
         "PATH",
         "PATH_SEGMENT",
+        "TYPE_ARG_LIST",
         "NAME",
         "BIN_EXPR",
         "PREFIX_EXPR",
+        "BORROW_EXPR",
+        "MOVE_EXPR",
+        "COPY_EXPR",
         "PATH_EXPR",
         "PAREN_EXPR",
+        "TUPLE_EXPR",
+        "UNIT_EXPR",
+        "CAST_EXPR",
+        "VECTOR_LIT_EXPR",
+        "IF_EXPR",
+        "WHILE_EXPR",
+        "LOOP_EXPR",
+        "RETURN_EXPR",
+        "ABORT_EXPR",
+        "BREAK_EXPR",
+        "CONTINUE_EXPR",
         "EXPR_STMT",
         "LET_STMT",
         "REF_TYPE",
         "PATH_TYPE",
+        "TUPLE_TYPE",
+        "PAREN_TYPE",
         "PAREN_PAT",
         "TUPLE_PAT",
         "RECORD_PAT",
@@ -109,8 +164,32 @@ pub(crate) const KINDS_SRC: SymbolKindsSrc = SymbolKindsSrc {
         "REST_PAT",
         "IDENT_PAT",
         "ADDRESS_LIT",
+        "USE_DECL",
+        "USE_TREE",
+        "USE_TREE_LIST",
+        "RENAME",
+        "CONST_DEF",
+        "FRIEND_DECL",
+        "SPEC_DEF",
+        "SPEC_SCHEMA",
+        "SPEC_BLOCK",
+        "SPEC_BLOCK_EXPR",
+        "SPEC_CONDITION",
+        "SPEC_PROPERTY_LIST",
+        "SPEC_PROPERTY",
+        "SPEC_PRAGMA",
+        "SPEC_INCLUDE",
+        "SPEC_VAR",
+        "SPEC_LET",
+        "QUANT_EXPR",
+        "QUANT_BINDING",
+        "INDEX_EXPR",
+        "VISIBILITY",
+        "ACQUIRES_LIST",
+        "ATTR_LIST",
+        "ATTR",
     ],
-    tokens: &["ERROR", "IDENT", "WHITESPACE", "COMMENT", "DIEM_ADDRESS"],
+    tokens: &["ERROR", "IDENT", "WHITESPACE", "COMMENT", "DOC_COMMENT", "DIEM_ADDRESS"],
 };
 
 pub(crate) fn generate_syntax_kinds(grammar: SymbolKindsSrc<'_>) -> String {
@@ -132,12 +211,18 @@ pub(crate) fn generate_syntax_kinds(grammar: SymbolKindsSrc<'_>) -> String {
     });
     let punctuation =
         grammar.punct.iter().map(|(_token, name)| format_ident!("{}", name)).collect::<Vec<_>>();
+    let punctuation_texts = grammar.punct.iter().map(|(token, _name)| token);
 
     let full_keywords_values = &grammar.keywords;
     let full_keywords =
         full_keywords_values.iter().map(|kw| format_ident!("{}_KW", to_upper_snake_case(kw)));
 
-    let all_keywords_values = grammar.keywords.iter().collect::<Vec<_>>();
+    let contextual_keywords_values = &grammar.contextual_keywords;
+    let contextual_keywords =
+        contextual_keywords_values.iter().map(|kw| format_ident!("{}_KW", to_upper_snake_case(kw)));
+
+    let all_keywords_values =
+        grammar.keywords.iter().chain(grammar.contextual_keywords.iter()).collect::<Vec<_>>();
     let all_keywords_idents = all_keywords_values.iter().map(|kw| format_ident!("{}", kw));
     let all_keywords = all_keywords_values
         .iter()
@@ -201,6 +286,16 @@ pub(crate) fn generate_syntax_kinds(grammar: SymbolKindsSrc<'_>) -> String {
                 // }
             }
 
+            /// The text of a punctuation or keyword token.
+            pub fn text(self) -> Option<&'static str> {
+                let text = match self {
+                    #(#punctuation => #punctuation_texts,)*
+                    #(#all_keywords => #all_keywords_values,)*
+                    _ => return None,
+                };
+                Some(text)
+            }
+
             pub fn from_keyword(ident: &str) -> Option<SyntaxKind> {
                 let kw = match ident {
                     #(#full_keywords_values => #full_keywords,)*
@@ -209,6 +304,14 @@ pub(crate) fn generate_syntax_kinds(grammar: SymbolKindsSrc<'_>) -> String {
                 Some(kw)
             }
 
+            pub fn from_contextual_keyword(ident: &str) -> Option<SyntaxKind> {
+                let kw = match ident {
+                    #(#contextual_keywords_values => #contextual_keywords,)*
+                    _ => return None,
+                };
+                Some(kw)
+            }
+
             pub fn from_char(c: char) -> Option<SyntaxKind> {
                 let tok = match c {
                     #(#single_byte_tokens_values => #single_byte_tokens,)*
@@ -223,6 +326,7 @@ pub(crate) fn generate_syntax_kinds(grammar: SymbolKindsSrc<'_>) -> String {
             #([#punctuation_values] => { $crate::SyntaxKind::#punctuation };)*
             #([#all_keywords_idents] => { $crate::SyntaxKind::#all_keywords };)*
             [ident] => { $crate::SyntaxKind::IDENT };
+            [diem_address] => { $crate::SyntaxKind::DIEM_ADDRESS };
             [shebang] => { $crate::SyntaxKind::SHEBANG };
         }
     };
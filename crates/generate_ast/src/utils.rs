@@ -1,7 +1,10 @@
 use xshell::{cmd, pushenv};
 
 pub(crate) fn pluralize(s: &str) -> String {
-    format!("{}s", s)
+    match s.strip_suffix('y') {
+        Some(stem) if !stem.ends_with(|c| "aeiou".contains(c)) => format!("{}ies", stem),
+        _ => format!("{}s", s),
+    }
 }
 
 pub(crate) fn ensure_rustfmt() {
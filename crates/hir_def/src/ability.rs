@@ -0,0 +1,123 @@
+//! The abilities of structs and the ability bounds of type parameters.
+use std::fmt;
+
+use syntax::ast;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Ability {
+    Copy,
+    Drop,
+    Store,
+    Key,
+}
+
+impl Ability {
+    pub const ALL: [Ability; 4] = [Ability::Copy, Ability::Drop, Ability::Store, Ability::Key];
+
+    pub fn from_ast(ability: &ast::Ability) -> Option<Ability> {
+        if ability.copy_token().is_some() {
+            return Some(Ability::Copy);
+        }
+        Ability::from_name(ability.ident_token()?.text())
+    }
+
+    pub fn from_name(name: &str) -> Option<Ability> {
+        let res = match name {
+            "copy" => Ability::Copy,
+            "drop" => Ability::Drop,
+            "store" => Ability::Store,
+            "key" => Ability::Key,
+            _ => return None,
+        };
+        Some(res)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Ability::Copy => "copy",
+            Ability::Drop => "drop",
+            Ability::Store => "store",
+            Ability::Key => "key",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl fmt::Display for Ability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A set of abilities.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Abilities(u8);
+
+impl Abilities {
+    pub const EMPTY: Abilities = Abilities(0);
+    pub const ALL: Abilities = Abilities(0b1111);
+
+    /// Collects the known abilities, ignoring the names which aren't ones.
+    pub fn from_ast(abilities: impl Iterator<Item = ast::Ability>) -> Abilities {
+        abilities.filter_map(|it| Ability::from_ast(&it)).collect()
+    }
+
+    pub fn contains(self, ability: Ability) -> bool {
+        self.0 & ability.bit() != 0
+    }
+
+    pub fn insert(&mut self, ability: Ability) {
+        self.0 |= ability.bit();
+    }
+
+    pub fn union(self, other: Abilities) -> Abilities {
+        Abilities(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Abilities) -> Abilities {
+        Abilities(self.0 & other.0)
+    }
+
+    pub fn is_subset(self, other: Abilities) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Ability> {
+        Ability::ALL.iter().copied().filter(move |it| self.contains(*it))
+    }
+}
+
+impl std::iter::FromIterator<Ability> for Abilities {
+    fn from_iter<I: IntoIterator<Item = Ability>>(iter: I) -> Abilities {
+        let mut res = Abilities::EMPTY;
+        for ability in iter {
+            res.insert(ability);
+        }
+        res
+    }
+}
+
+impl fmt::Debug for Abilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl fmt::Display for Abilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, ability) in self.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", ability)?;
+        }
+        Ok(())
+    }
+}
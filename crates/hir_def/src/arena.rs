@@ -0,0 +1,169 @@
+//! A minimal typed arena: values are allocated into a `Vec` and referred to
+//! by their `Idx`. An `ArenaMap` attaches more data to the values of an arena,
+//! like the inferred type of every expression of a body.
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+/// The index of a value allocated in an arena.
+pub struct Idx<T> {
+    raw: u32,
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<T> Idx<T> {
+    pub(crate) fn from_raw(raw: usize) -> Idx<T> {
+        Idx { raw: raw as u32, _ty: PhantomData }
+    }
+
+    pub fn into_raw(self) -> u32 {
+        self.raw
+    }
+}
+
+impl<T> Clone for Idx<T> {
+    fn clone(&self) -> Idx<T> {
+        *self
+    }
+}
+impl<T> Copy for Idx<T> {}
+
+impl<T> PartialEq for Idx<T> {
+    fn eq(&self, other: &Idx<T>) -> bool {
+        self.raw == other.raw
+    }
+}
+impl<T> Eq for Idx<T> {}
+
+impl<T> PartialOrd for Idx<T> {
+    fn partial_cmp(&self, other: &Idx<T>) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T> Ord for Idx<T> {
+    fn cmp(&self, other: &Idx<T>) -> std::cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl<T> Hash for Idx<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
+
+impl<T> fmt::Debug for Idx<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let type_name = std::any::type_name::<T>();
+        let type_name = type_name.rsplit("::").next().unwrap_or(type_name);
+        write!(f, "Idx::<{}>({})", type_name, self.raw)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Arena<T> {
+    data: Vec<T>,
+}
+
+impl<T> Arena<T> {
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn alloc(&mut self, value: T) -> Idx<T> {
+        let idx = Idx::from_raw(self.data.len());
+        self.data.push(value);
+        idx
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Idx<T>, &T)> {
+        self.data.iter().enumerate().map(|(idx, value)| (Idx::from_raw(idx), value))
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Arena<T> {
+        Arena { data: Vec::new() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Arena<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Arena").field("len", &self.data.len()).field("data", &self.data).finish()
+    }
+}
+
+impl<T> Index<Idx<T>> for Arena<T> {
+    type Output = T;
+    fn index(&self, idx: Idx<T>) -> &T {
+        &self.data[idx.raw as usize]
+    }
+}
+
+impl<T> IndexMut<Idx<T>> for Arena<T> {
+    fn index_mut(&mut self, idx: Idx<T>) -> &mut T {
+        &mut self.data[idx.raw as usize]
+    }
+}
+
+impl<T> FromIterator<T> for Arena<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Arena<T> {
+        Arena { data: Vec::from_iter(iter) }
+    }
+}
+
+/// A map from the indices of an arena to values, stored densely.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ArenaMap<K, V> {
+    data: Vec<Option<V>>,
+    _ty: PhantomData<K>,
+}
+
+impl<T, V> ArenaMap<Idx<T>, V> {
+    pub fn insert(&mut self, idx: Idx<T>, value: V) {
+        let idx = idx.raw as usize;
+        if self.data.len() <= idx {
+            self.data.resize_with(idx + 1, || None);
+        }
+        self.data[idx] = Some(value);
+    }
+
+    pub fn get(&self, idx: Idx<T>) -> Option<&V> {
+        self.data.get(idx.raw as usize).and_then(|it| it.as_ref())
+    }
+
+    pub fn get_mut(&mut self, idx: Idx<T>) -> Option<&mut V> {
+        self.data.get_mut(idx.raw as usize).and_then(|it| it.as_mut())
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.data.iter_mut().filter_map(|it| it.as_mut())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Idx<T>, &V)> {
+        self.data
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, value)| Some((Idx::from_raw(idx), value.as_ref()?)))
+    }
+}
+
+impl<K, V> Default for ArenaMap<K, V> {
+    fn default() -> ArenaMap<K, V> {
+        ArenaMap { data: Vec::new(), _ty: PhantomData }
+    }
+}
+
+impl<K, V: fmt::Debug> fmt::Debug for ArenaMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.data.iter().flatten()).finish()
+    }
+}
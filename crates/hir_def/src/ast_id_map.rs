@@ -0,0 +1,142 @@
+//! `AstIdMap` allows to create stable IDs for "large" syntax nodes like
+//! modules and functions.
+//!
+//! Specifically, it enumerates all items in a file and uses the position of an
+//! item in that list as its ID. That way, IDs don't change unless the set of
+//! items itself changes: editing the body of a function or inserting
+//! whitespace keeps them all the same.
+use std::{
+    any::type_name,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+    sync::Arc,
+};
+
+use base_db::FileId;
+use rustc_hash::FxHashMap;
+use syntax::{ast, syntax_node::SyntaxNode, AstNode, AstPtr, SyntaxKind::*, SyntaxNodePtr};
+
+use crate::db::DefDatabase;
+
+/// `AstId` points to an AST node in a specific file.
+pub struct FileAstId<N: AstNode> {
+    raw: ErasedFileAstId,
+    _ty: PhantomData<fn() -> N>,
+}
+
+impl<N: AstNode> Clone for FileAstId<N> {
+    fn clone(&self) -> FileAstId<N> {
+        *self
+    }
+}
+impl<N: AstNode> Copy for FileAstId<N> {}
+
+impl<N: AstNode> PartialEq for FileAstId<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+impl<N: AstNode> Eq for FileAstId<N> {}
+impl<N: AstNode> Hash for FileAstId<N> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.raw.hash(hasher);
+    }
+}
+
+impl<N: AstNode> fmt::Debug for FileAstId<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FileAstId::<{}>({})", type_name::<N>(), self.raw.0)
+    }
+}
+
+impl<N: AstNode> FileAstId<N> {
+    pub fn upcast<M: AstNode>(self) -> FileAstId<M>
+    where
+        N: Into<M>,
+    {
+        FileAstId { raw: self.raw, _ty: PhantomData }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ErasedFileAstId(u32);
+
+/// Maps items' `SyntaxNode`s to `ErasedFileAstId`s and back.
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct AstIdMap {
+    arena: Vec<SyntaxNodePtr>,
+    map: FxHashMap<SyntaxNodePtr, ErasedFileAstId>,
+}
+
+impl AstIdMap {
+    pub(crate) fn ast_id_map_query(db: &dyn DefDatabase, file_id: FileId) -> Arc<AstIdMap> {
+        let map = AstIdMap::from_source(&db.parse(file_id).tree().syntax().clone());
+        Arc::new(map)
+    }
+
+    fn from_source(node: &SyntaxNode) -> AstIdMap {
+        assert!(node.parent().is_none());
+        let mut res = AstIdMap::default();
+        // By walking the tree in breadth-first order we make sure that
+        // addresses and modules get lower ids than the items inside them. That
+        // is, adding a new function to a module does not change the ids of
+        // the modules after it.
+        let mut curr_layer = vec![node.clone()];
+        let mut next_layer = vec![];
+        while !curr_layer.is_empty() {
+            for node in curr_layer.drain(..) {
+                for child in node.children() {
+                    if is_item(&child) {
+                        res.alloc(&child);
+                    }
+                    if matches!(
+                        child.kind(),
+                        ADDRESS_DEF | ADDRESS_ITEM_LIST | MODULE_DEF | SCRIPT_DEF | ITEM_LIST
+                    ) {
+                        next_layer.push(child);
+                    }
+                }
+            }
+            mem::swap(&mut curr_layer, &mut next_layer);
+        }
+        res
+    }
+
+    pub fn ast_id<N: AstNode>(&self, item: &N) -> FileAstId<N> {
+        let raw = self.erased_ast_id(item.syntax());
+        FileAstId { raw, _ty: PhantomData }
+    }
+
+    fn erased_ast_id(&self, item: &SyntaxNode) -> ErasedFileAstId {
+        let ptr = SyntaxNodePtr::new(item);
+        match self.map.get(&ptr) {
+            Some(it) => *it,
+            None => panic!(
+                "Can't find {:?} in AstIdMap:\n{:?}",
+                item,
+                self.arena.iter().collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    pub fn get<N: AstNode>(&self, id: FileAstId<N>) -> AstPtr<N> {
+        self.arena[id.raw.0 as usize].clone().cast::<N>().unwrap()
+    }
+
+    fn alloc(&mut self, item: &SyntaxNode) -> ErasedFileAstId {
+        let ptr = SyntaxNodePtr::new(item);
+        let id = ErasedFileAstId(self.arena.len() as u32);
+        self.arena.push(ptr.clone());
+        self.map.insert(ptr, id);
+        id
+    }
+}
+
+fn is_item(node: &SyntaxNode) -> bool {
+    ast::AddressDef::can_cast(node.kind())
+        || ast::ModuleDef::can_cast(node.kind())
+        || ast::ScriptDef::can_cast(node.kind())
+        || ast::Item::can_cast(node.kind())
+}
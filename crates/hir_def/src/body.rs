@@ -0,0 +1,99 @@
+//! Defines `Body`: the lowered expressions and patterns of a function, and the
+//! `BodySourceMap` which maps them back to the syntax tree.
+mod lower;
+pub mod scope;
+#[cfg(test)]
+mod tests;
+
+use std::{ops::Index, sync::Arc};
+
+use rustc_hash::FxHashMap;
+use syntax::{ast, AstPtr};
+
+use crate::{
+    arena::Arena,
+    db::DefDatabase,
+    expr::{Expr, ExprId, Pat, PatId},
+    FunctionId, HasSource,
+};
+
+/// The body of a function.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Body {
+    pub exprs: Arena<Expr>,
+    pub pats: Arena<Pat>,
+    /// The bindings of the parameters, in order.
+    pub params: Vec<PatId>,
+    /// The block of the function, `Expr::Missing` for native functions.
+    pub body_expr: ExprId,
+}
+
+pub type ExprPtr = AstPtr<ast::Expr>;
+
+/// A pattern comes either from a pattern or from a function parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatPtr {
+    Pat(AstPtr<ast::Pat>),
+    Param(AstPtr<ast::Param>),
+}
+
+/// Maps the expressions and patterns of a `Body` to the syntax nodes they are
+/// lowered from, and back. All nodes are in the file of the function.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BodySourceMap {
+    expr_map: FxHashMap<ExprPtr, ExprId>,
+    expr_map_back: FxHashMap<ExprId, ExprPtr>,
+    pat_map: FxHashMap<PatPtr, PatId>,
+    pat_map_back: FxHashMap<PatId, PatPtr>,
+}
+
+impl Body {
+    pub(crate) fn body_with_source_map_query(
+        db: &dyn DefDatabase,
+        id: FunctionId,
+    ) -> (Arc<Body>, Arc<BodySourceMap>) {
+        let fun = id.source(db).value;
+        let (body, source_map) = lower::lower(&fun);
+        (Arc::new(body), Arc::new(source_map))
+    }
+
+    pub(crate) fn body_query(db: &dyn DefDatabase, id: FunctionId) -> Arc<Body> {
+        db.body_with_source_map(id).0
+    }
+}
+
+impl Index<ExprId> for Body {
+    type Output = Expr;
+    fn index(&self, expr: ExprId) -> &Expr {
+        &self.exprs[expr]
+    }
+}
+
+impl Index<PatId> for Body {
+    type Output = Pat;
+    fn index(&self, pat: PatId) -> &Pat {
+        &self.pats[pat]
+    }
+}
+
+impl BodySourceMap {
+    pub fn expr_syntax(&self, expr: ExprId) -> Option<ExprPtr> {
+        self.expr_map_back.get(&expr).cloned()
+    }
+
+    pub fn node_expr(&self, node: &ast::Expr) -> Option<ExprId> {
+        self.expr_map.get(&AstPtr::new(node)).copied()
+    }
+
+    pub fn pat_syntax(&self, pat: PatId) -> Option<PatPtr> {
+        self.pat_map_back.get(&pat).cloned()
+    }
+
+    pub fn node_pat(&self, node: &ast::Pat) -> Option<PatId> {
+        self.pat_map.get(&PatPtr::Pat(AstPtr::new(node))).copied()
+    }
+
+    pub fn node_param(&self, node: &ast::Param) -> Option<PatId> {
+        self.pat_map.get(&PatPtr::Param(AstPtr::new(node))).copied()
+    }
+}
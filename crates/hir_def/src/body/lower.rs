@@ -0,0 +1,349 @@
+//! Transforms the syntax tree of a function into a `Body`.
+use syntax::{
+    ast::{self, HasName},
+    AstNode, AstPtr, SyntaxKind,
+};
+
+use super::{Body, BodySourceMap, PatPtr};
+use crate::{
+    arena::Arena,
+    builtin::BuiltinType,
+    expr::{
+        ArithOp, BinaryOp, CmpOp, Expr, ExprId, Literal, LogicOp, Pat, PatId, RecordFieldPat,
+        RecordLitField, Statement, UnaryOp,
+    },
+    name::{AsName, Name},
+    path::{Address, Path},
+    type_ref::{Mutability, TypeRef},
+};
+
+pub(super) fn lower(fun: &ast::FunctionDef) -> (Body, BodySourceMap) {
+    let mut ctx = ExprCollector {
+        exprs: Arena::default(),
+        pats: Arena::default(),
+        source_map: BodySourceMap::default(),
+    };
+    let params = fun
+        .param_list()
+        .into_iter()
+        .flat_map(|it| it.params())
+        .map(|param| {
+            let name = param.name().map(|it| it.as_name()).unwrap_or_else(Name::missing);
+            ctx.alloc_pat(Pat::Bind { name }, PatPtr::Param(AstPtr::new(&param)))
+        })
+        .collect();
+    let body_expr = match fun.body() {
+        Some(block) => ctx.collect_expr(ast::Expr::BlockExpr(block)),
+        None => ctx.missing_expr(),
+    };
+    let body = Body { exprs: ctx.exprs, pats: ctx.pats, params, body_expr };
+    (body, ctx.source_map)
+}
+
+struct ExprCollector {
+    exprs: Arena<Expr>,
+    pats: Arena<Pat>,
+    source_map: BodySourceMap,
+}
+
+impl ExprCollector {
+    fn alloc_expr(&mut self, expr: Expr, ptr: AstPtr<ast::Expr>) -> ExprId {
+        let id = self.exprs.alloc(expr);
+        self.source_map.expr_map.insert(ptr.clone(), id);
+        self.source_map.expr_map_back.insert(id, ptr);
+        id
+    }
+
+    fn missing_expr(&mut self) -> ExprId {
+        self.exprs.alloc(Expr::Missing)
+    }
+
+    fn alloc_pat(&mut self, pat: Pat, ptr: PatPtr) -> PatId {
+        let id = self.pats.alloc(pat);
+        self.source_map.pat_map.insert(ptr.clone(), id);
+        self.source_map.pat_map_back.insert(id, ptr);
+        id
+    }
+
+    fn missing_pat(&mut self) -> PatId {
+        self.pats.alloc(Pat::Missing)
+    }
+
+    fn collect_expr_opt(&mut self, expr: Option<ast::Expr>) -> ExprId {
+        match expr {
+            Some(expr) => self.collect_expr(expr),
+            None => self.missing_expr(),
+        }
+    }
+
+    fn collect_expr(&mut self, expr: ast::Expr) -> ExprId {
+        let ptr = AstPtr::new(&expr);
+        let expr = match &expr {
+            ast::Expr::BlockExpr(block) => self.collect_block(block),
+            ast::Expr::BinExpr(e) => {
+                let lhs = self.collect_expr_opt(e.lhs());
+                let rhs = self.collect_expr_opt(e.rhs());
+                let op = e.op_kind().and_then(binary_op);
+                Expr::BinaryOp { lhs, rhs, op }
+            }
+            ast::Expr::Literal(e) => Expr::Literal(lower_literal(e)),
+            ast::Expr::AddressLit(e) => {
+                let address = match e.named_address() {
+                    Some(named) => Some(Address::from_name(named.as_name())),
+                    None => e.diem_address_token().and_then(|it| Address::from_literal(it.text())),
+                };
+                match address {
+                    Some(address) => Expr::Literal(Literal::Address(address)),
+                    None => Expr::Missing,
+                }
+            }
+            ast::Expr::PathExpr(e) => match e.path().and_then(Path::from_ast) {
+                Some(path) => Expr::Path(path),
+                None => Expr::Missing,
+            },
+            ast::Expr::RecordExpr(e) => {
+                let path = e.path().and_then(Path::from_ast);
+                let fields = e
+                    .record_expr_field_list()
+                    .into_iter()
+                    .flat_map(|it| it.record_expr_fields())
+                    .map(|field| {
+                        let name = field_name(&field);
+                        RecordLitField { name, expr: self.collect_expr_opt(field.expr()) }
+                    })
+                    .collect();
+                Expr::Record { path, fields }
+            }
+            ast::Expr::CallExpr(e) => {
+                let callee = self.collect_expr_opt(e.expr());
+                let args = e
+                    .arg_list()
+                    .into_iter()
+                    .flat_map(|it| it.args())
+                    .map(|arg| self.collect_expr(arg))
+                    .collect();
+                Expr::Call { callee, args }
+            }
+            ast::Expr::DotExpr(e) => {
+                let expr = self.collect_expr_opt(e.expr());
+                let name = e.name_ref().map(|it| it.as_name()).unwrap_or_else(Name::missing);
+                Expr::Field { expr, name }
+            }
+            ast::Expr::PrefixExpr(e) => {
+                let expr = self.collect_expr_opt(e.expr());
+                let op = if e.star_token().is_some() { UnaryOp::Deref } else { UnaryOp::Not };
+                Expr::UnaryOp { expr, op }
+            }
+            ast::Expr::BorrowExpr(e) => {
+                let expr = self.collect_expr_opt(e.expr());
+                let mutability =
+                    if e.amp_mut_token().is_some() { Mutability::Mut } else { Mutability::Shared };
+                Expr::Ref { expr, mutability }
+            }
+            ast::Expr::MoveExpr(e) => Expr::Move {
+                name: e.name_ref().map(|it| it.as_name()).unwrap_or_else(Name::missing),
+            },
+            ast::Expr::CopyExpr(e) => Expr::Copy {
+                name: e.name_ref().map(|it| it.as_name()).unwrap_or_else(Name::missing),
+            },
+            ast::Expr::ParenExpr(e) => {
+                // Parentheses don't get an expression of their own, but the
+                // source map points both nodes to the inner expression.
+                let inner = self.collect_expr_opt(e.expr());
+                self.source_map.expr_map.insert(ptr, inner);
+                return inner;
+            }
+            ast::Expr::TupleExpr(e) => {
+                Expr::Tuple { exprs: e.exprs().map(|it| self.collect_expr(it)).collect() }
+            }
+            ast::Expr::UnitExpr(_) => Expr::Tuple { exprs: Box::new([]) },
+            ast::Expr::CastExpr(e) => {
+                let expr = self.collect_expr_opt(e.expr());
+                Expr::Cast { expr, type_ref: TypeRef::from_ast_opt(e.ty()) }
+            }
+            ast::Expr::VectorLitExpr(e) => {
+                let type_arg =
+                    e.type_arg_list().map(|list| TypeRef::from_ast_opt(list.types().next()));
+                let elements = e.exprs().map(|it| self.collect_expr(it)).collect();
+                Expr::Vector { type_arg, elements }
+            }
+            ast::Expr::IfExpr(e) => {
+                let condition = self.collect_expr_opt(e.condition());
+                let then_branch = self.collect_expr_opt(e.then_branch());
+                let else_branch = e.else_branch().map(|it| self.collect_expr(it));
+                Expr::If { condition, then_branch, else_branch }
+            }
+            ast::Expr::WhileExpr(e) => {
+                let condition = self.collect_expr_opt(e.condition());
+                let body = self.collect_expr_opt(e.body());
+                Expr::While { condition, body }
+            }
+            ast::Expr::LoopExpr(e) => Expr::Loop { body: self.collect_expr_opt(e.body()) },
+            ast::Expr::ReturnExpr(e) => {
+                Expr::Return { expr: e.expr().map(|it| self.collect_expr(it)) }
+            }
+            ast::Expr::AbortExpr(e) => Expr::Abort { expr: self.collect_expr_opt(e.expr()) },
+            ast::Expr::BreakExpr(_) => Expr::Break,
+            ast::Expr::ContinueExpr(_) => Expr::Continue,
+            ast::Expr::IndexExpr(e) => {
+                let base = self.collect_expr_opt(e.base());
+                let index = self.collect_expr_opt(e.index());
+                Expr::Index { base, index }
+            }
+            ast::Expr::SpecBlockExpr(_) | ast::Expr::QuantExpr(_) => Expr::Spec,
+        };
+        self.alloc_expr(expr, ptr)
+    }
+
+    fn collect_block(&mut self, block: &ast::BlockExpr) -> Expr {
+        let stmts: Vec<ast::Stmt> = block.stmts().collect();
+        let mut statements = Vec::new();
+        let mut tail = None;
+        for (idx, stmt) in stmts.iter().enumerate() {
+            match stmt {
+                ast::Stmt::LetStmt(stmt) => {
+                    let pat = self.collect_pat_opt(stmt.pat());
+                    let type_ref = stmt.ty().map(TypeRef::from_ast);
+                    let initializer = stmt.initializer().map(|it| self.collect_expr(it));
+                    statements.push(Statement::Let { pat, type_ref, initializer });
+                }
+                ast::Stmt::ExprStmt(stmt) => {
+                    let expr = self.collect_expr_opt(stmt.expr());
+                    let is_last = idx + 1 == stmts.len();
+                    if is_last && stmt.semicolon_token().is_none() {
+                        tail = Some(expr);
+                    } else {
+                        statements.push(Statement::Expr(expr));
+                    }
+                }
+            }
+        }
+        Expr::Block { statements: statements.into(), tail }
+    }
+
+    fn collect_pat_opt(&mut self, pat: Option<ast::Pat>) -> PatId {
+        match pat {
+            Some(pat) => self.collect_pat(pat),
+            None => self.missing_pat(),
+        }
+    }
+
+    fn collect_pat(&mut self, pat: ast::Pat) -> PatId {
+        let ptr = PatPtr::Pat(AstPtr::new(&pat));
+        let pattern = match &pat {
+            ast::Pat::IdentPat(p) => {
+                Pat::Bind { name: p.name().map(|it| it.as_name()).unwrap_or_else(Name::missing) }
+            }
+            ast::Pat::WildcardPat(_) => Pat::Wild,
+            ast::Pat::RestPat(_) => Pat::Missing,
+            ast::Pat::TuplePat(p) => {
+                Pat::Tuple { pats: p.pats().map(|it| self.collect_pat(it)).collect() }
+            }
+            ast::Pat::ParenPat(p) => {
+                let inner = self.collect_pat_opt(p.pat());
+                self.source_map.pat_map.insert(ptr, inner);
+                return inner;
+            }
+            ast::Pat::PathPat(p) => match p.path().and_then(Path::from_ast) {
+                Some(path) => Pat::Path(path),
+                None => Pat::Missing,
+            },
+            ast::Pat::RecordPat(p) => {
+                let path = p.path().and_then(Path::from_ast);
+                let field_list = p.record_pat_field_list();
+                let ellipsis = field_list.as_ref().is_some_and(|it| it.dotdot_token().is_some());
+                let fields = field_list
+                    .into_iter()
+                    .flat_map(|it| it.record_pat_fields())
+                    .map(|field| {
+                        let pat = field.pat();
+                        // `S { f }` binds the field `f` to a local of the same name.
+                        let name = match (field.name_ref(), &pat) {
+                            (Some(name_ref), _) => name_ref.as_name(),
+                            (None, Some(ast::Pat::IdentPat(ident))) => {
+                                ident.name().map(|it| it.as_name()).unwrap_or_else(Name::missing)
+                            }
+                            (None, _) => Name::missing(),
+                        };
+                        RecordFieldPat { name, pat: self.collect_pat_opt(pat) }
+                    })
+                    .collect();
+                Pat::Record { path, fields, ellipsis }
+            }
+        };
+        self.alloc_pat(pattern, ptr)
+    }
+}
+
+/// The name of the field in `S { f: x }` or `S { f }`.
+fn field_name(field: &ast::RecordExprField) -> Name {
+    if let Some(name_ref) = field.name_ref() {
+        return name_ref.as_name();
+    }
+    match field.expr() {
+        Some(ast::Expr::PathExpr(e)) => e
+            .path()
+            .and_then(Path::from_ast)
+            .and_then(|path| path.as_ident().cloned())
+            .unwrap_or_else(Name::missing),
+        _ => Name::missing(),
+    }
+}
+
+fn lower_literal(literal: &ast::Literal) -> Literal {
+    let token = match literal.syntax().first_token() {
+        Some(it) => it,
+        None => return Literal::Integer { value: None, suffix: None },
+    };
+    match token.kind() {
+        SyntaxKind::TRUE_KW => Literal::Bool(true),
+        SyntaxKind::FALSE_KW => Literal::Bool(false),
+        SyntaxKind::BYTE_STRING | SyntaxKind::HEX_STRING => Literal::ByteString,
+        _ => lower_integer(token.text()),
+    }
+}
+
+/// Lowers `42`, `0x2A` or `42u8`.
+fn lower_integer(text: &str) -> Literal {
+    let (digits, suffix) = match [BuiltinType::U8, BuiltinType::U64, BuiltinType::U128]
+        .iter()
+        .find(|ty| text.ends_with(ty.as_str()))
+    {
+        Some(ty) => (&text[..text.len() - ty.as_str().len()], Some(*ty)),
+        None => (text, None),
+    };
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => u128::from_str_radix(hex, 16).ok(),
+        None => digits.parse::<u128>().ok(),
+    };
+    Literal::Integer { value, suffix }
+}
+
+fn binary_op(kind: SyntaxKind) -> Option<BinaryOp> {
+    let op = match kind {
+        SyntaxKind::EQ => BinaryOp::Assignment,
+        SyntaxKind::PIPE_PIPE => BinaryOp::Logic(LogicOp::Or),
+        SyntaxKind::AMP_AMP => BinaryOp::Logic(LogicOp::And),
+        SyntaxKind::EQ_EQ_GT => BinaryOp::Logic(LogicOp::Implies),
+        SyntaxKind::LT_EQ_EQ_GT => BinaryOp::Logic(LogicOp::Iff),
+        SyntaxKind::EQ_EQ => BinaryOp::Cmp(CmpOp::Eq),
+        SyntaxKind::BANG_EQ => BinaryOp::Cmp(CmpOp::NotEq),
+        SyntaxKind::LT => BinaryOp::Cmp(CmpOp::Lt),
+        SyntaxKind::GT => BinaryOp::Cmp(CmpOp::Gt),
+        SyntaxKind::LT_EQ => BinaryOp::Cmp(CmpOp::Le),
+        SyntaxKind::GT_EQ => BinaryOp::Cmp(CmpOp::Ge),
+        SyntaxKind::PLUS => BinaryOp::Arith(ArithOp::Add),
+        SyntaxKind::MINUS => BinaryOp::Arith(ArithOp::Sub),
+        SyntaxKind::STAR => BinaryOp::Arith(ArithOp::Mul),
+        SyntaxKind::SLASH => BinaryOp::Arith(ArithOp::Div),
+        SyntaxKind::MOD => BinaryOp::Arith(ArithOp::Rem),
+        SyntaxKind::PIPE => BinaryOp::Arith(ArithOp::BitOr),
+        SyntaxKind::CARET => BinaryOp::Arith(ArithOp::BitXor),
+        SyntaxKind::AMP => BinaryOp::Arith(ArithOp::BitAnd),
+        SyntaxKind::LT_LT => BinaryOp::Arith(ArithOp::Shl),
+        SyntaxKind::GT_GT => BinaryOp::Arith(ArithOp::Shr),
+        SyntaxKind::DOTDOT => BinaryOp::Range,
+        _ => return None,
+    };
+    Some(op)
+}
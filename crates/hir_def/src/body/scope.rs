@@ -0,0 +1,145 @@
+//! Name resolution for the locals of a function body.
+//!
+//! Every `let` starts a new scope, which contains the bindings of its pattern
+//! and lasts until the end of the enclosing block. The parameters are in the
+//! root scope.
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    arena::{Arena, Idx},
+    body::Body,
+    db::DefDatabase,
+    expr::{Expr, ExprId, Pat, PatId, Statement},
+    name::Name,
+    FunctionId,
+};
+
+pub type ScopeId = Idx<ScopeData>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExprScopes {
+    scopes: Arena<ScopeData>,
+    scope_by_expr: FxHashMap<ExprId, ScopeId>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScopeEntry {
+    name: Name,
+    pat: PatId,
+}
+
+impl ScopeEntry {
+    pub fn name(&self) -> &Name {
+        &self.name
+    }
+
+    pub fn pat(&self) -> PatId {
+        self.pat
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScopeData {
+    parent: Option<ScopeId>,
+    entries: Vec<ScopeEntry>,
+}
+
+impl ExprScopes {
+    pub(crate) fn expr_scopes_query(db: &dyn DefDatabase, id: FunctionId) -> Arc<ExprScopes> {
+        let body = db.body(id);
+        Arc::new(ExprScopes::new(&body))
+    }
+
+    fn new(body: &Body) -> ExprScopes {
+        let mut scopes =
+            ExprScopes { scopes: Arena::default(), scope_by_expr: FxHashMap::default() };
+        let root = scopes.root_scope();
+        for &param in &body.params {
+            scopes.add_bindings(body, root, param);
+        }
+        compute_expr_scopes(body.body_expr, body, &mut scopes, root);
+        scopes
+    }
+
+    pub fn entries(&self, scope: ScopeId) -> &[ScopeEntry] {
+        &self.scopes[scope].entries
+    }
+
+    /// The scope and its ancestors, innermost first.
+    pub fn scope_chain(&self, scope: Option<ScopeId>) -> impl Iterator<Item = ScopeId> + '_ {
+        std::iter::successors(scope, move |&scope| self.scopes[scope].parent)
+    }
+
+    pub fn scope_for(&self, expr: ExprId) -> Option<ScopeId> {
+        self.scope_by_expr.get(&expr).copied()
+    }
+
+    /// Finds the binding which `name` refers to in `scope`. Later bindings
+    /// shadow earlier ones.
+    pub fn resolve_name_in_scope(&self, scope: ScopeId, name: &Name) -> Option<&ScopeEntry> {
+        self.scope_chain(Some(scope))
+            .find_map(|scope| self.entries(scope).iter().rev().find(|it| it.name == *name))
+    }
+
+    fn root_scope(&mut self) -> ScopeId {
+        self.scopes.alloc(ScopeData { parent: None, entries: vec![] })
+    }
+
+    fn new_scope(&mut self, parent: ScopeId) -> ScopeId {
+        self.scopes.alloc(ScopeData { parent: Some(parent), entries: vec![] })
+    }
+
+    fn add_bindings(&mut self, body: &Body, scope: ScopeId, pat: PatId) {
+        if let Pat::Bind { name } = &body[pat] {
+            if !name.is_missing() {
+                self.scopes[scope].entries.push(ScopeEntry { name: name.clone(), pat });
+            }
+        }
+        body[pat].walk_child_pats(|pat| self.add_bindings(body, scope, pat));
+    }
+
+    fn set_scope(&mut self, expr: ExprId, scope: ScopeId) {
+        self.scope_by_expr.insert(expr, scope);
+    }
+}
+
+fn compute_block_scopes(
+    statements: &[Statement],
+    tail: Option<ExprId>,
+    body: &Body,
+    scopes: &mut ExprScopes,
+    mut scope: ScopeId,
+) {
+    for stmt in statements {
+        match stmt {
+            Statement::Let { pat, initializer, .. } => {
+                // The initializer can't see the bindings of its own `let`.
+                if let Some(expr) = initializer {
+                    compute_expr_scopes(*expr, body, scopes, scope);
+                }
+                scope = scopes.new_scope(scope);
+                scopes.add_bindings(body, scope, *pat);
+            }
+            Statement::Expr(expr) => compute_expr_scopes(*expr, body, scopes, scope),
+        }
+    }
+    if let Some(expr) = tail {
+        compute_expr_scopes(expr, body, scopes, scope);
+    }
+}
+
+fn compute_expr_scopes(expr: ExprId, body: &Body, scopes: &mut ExprScopes, scope: ScopeId) {
+    scopes.set_scope(expr, scope);
+    match &body[expr] {
+        Expr::Block { statements, tail } => {
+            let scope = scopes.new_scope(scope);
+            // The block itself is in the new scope, so that a position at the
+            // start of the block sees the right scope.
+            scopes.set_scope(expr, scope);
+            compute_block_scopes(statements, *tail, body, scopes, scope);
+        }
+        e => e.walk_child_exprs(|e| compute_expr_scopes(e, body, scopes, scope)),
+    }
+}
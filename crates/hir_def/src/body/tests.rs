@@ -0,0 +1,128 @@
+use base_db::{fixture::WithFixture, SourceDatabase};
+use syntax::ast::{self, AstNode};
+
+use crate::{
+    builtin::BuiltinType,
+    db::DefDatabase,
+    expr::{Expr, Literal, Statement},
+    source_analyzer::SourceAnalyzer,
+    test_db::TestDB,
+    InFile, ModuleDefId,
+};
+
+/// Checks the names of the locals in scope at the cursor, innermost first.
+fn check_scopes(ra_fixture: &str, expected: &[&str]) {
+    let (db, position) = TestDB::with_position(ra_fixture);
+    let file = db.parse(position.file_id).tree();
+    let token = file.syntax().token_at_offset(position.offset).right_biased().unwrap();
+    let expr = token.parent().unwrap().ancestors().find_map(ast::Expr::cast).unwrap();
+
+    let sa =
+        SourceAnalyzer::new_for_node(&db, InFile::new(position.file_id, expr.syntax())).unwrap();
+    let owner = sa.resolver().body_owner().unwrap();
+    let expr_id = sa.body_source_map().unwrap().node_expr(&expr).unwrap();
+    let scopes = db.expr_scopes(owner);
+    let actual: Vec<String> = scopes
+        .scope_chain(scopes.scope_for(expr_id))
+        .flat_map(|scope| scopes.entries(scope).iter().rev())
+        .map(|entry| entry.name().to_string())
+        .collect();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn params_and_lets() {
+    check_scopes(
+        r#"
+module 0x1::M {
+    fun f(a: u64, b: u64) {
+        let c = 1;
+        let (d, e) = (1, 2);
+        $0a;
+    }
+}
+"#,
+        &["e", "d", "c", "b", "a"],
+    );
+}
+
+#[test]
+fn initializer_does_not_see_its_own_let() {
+    check_scopes(
+        r#"
+module 0x1::M {
+    fun f(a: u64) {
+        let b = $0a;
+    }
+}
+"#,
+        &["a"],
+    );
+}
+
+#[test]
+fn block_scopes_end_with_the_block() {
+    check_scopes(
+        r#"
+module 0x1::M {
+    fun f() {
+        let a = 1;
+        if (true) { let b = 2; };
+        while (true) { let c = 3; $0c; };
+    }
+}
+"#,
+        &["c", "a"],
+    );
+}
+
+#[test]
+fn record_pattern_bindings() {
+    check_scopes(
+        r#"
+module 0x1::M {
+    struct S { f: u64, g: u64 }
+    fun f(s: S) {
+        let S { f, g: x } = s;
+        $0f;
+    }
+}
+"#,
+        &["x", "f", "s"],
+    );
+}
+
+#[test]
+fn lowers_tail_and_literals() {
+    let (db, _) = TestDB::with_single_file(
+        r#"
+module 0x1::M {
+    fun f(): u8 {
+        let x = 0xFFu8;
+        x;
+        (x as u8)
+    }
+}
+"#,
+    );
+    let def_map = db.def_map();
+    let module = def_map.modules().next().unwrap();
+    let fun = match module.scope.items().next().unwrap().1 {
+        ModuleDefId::FunctionId(it) => it,
+        _ => unreachable!(),
+    };
+    let body = db.body(fun);
+    let (statements, tail) = match &body[body.body_expr] {
+        Expr::Block { statements, tail } => (statements, tail),
+        _ => unreachable!(),
+    };
+    assert_eq!(statements.len(), 2);
+    match &statements[0] {
+        Statement::Let { initializer: Some(init), .. } => assert_eq!(
+            body[*init],
+            Expr::Literal(Literal::Integer { value: Some(255), suffix: Some(BuiltinType::U8) })
+        ),
+        _ => unreachable!(),
+    }
+    assert!(matches!(body[tail.unwrap()], Expr::Cast { .. }));
+}
@@ -0,0 +1,101 @@
+//! The primitive types and the builtin functions of Move. They are in scope
+//! everywhere, unless an item with the same name shadows them.
+use std::fmt;
+
+use crate::name::Name;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinType {
+    U8,
+    U64,
+    U128,
+    Bool,
+    Address,
+    Signer,
+    /// `vector<T>`, the only generic builtin type.
+    Vector,
+}
+
+impl BuiltinType {
+    pub const ALL: &'static [BuiltinType] = &[
+        BuiltinType::U8,
+        BuiltinType::U64,
+        BuiltinType::U128,
+        BuiltinType::Bool,
+        BuiltinType::Address,
+        BuiltinType::Signer,
+        BuiltinType::Vector,
+    ];
+
+    pub fn by_name(name: &Name) -> Option<BuiltinType> {
+        BuiltinType::ALL.iter().copied().find(|it| it.as_str() == name.as_str())
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BuiltinType::U8 => "u8",
+            BuiltinType::U64 => "u64",
+            BuiltinType::U128 => "u128",
+            BuiltinType::Bool => "bool",
+            BuiltinType::Address => "address",
+            BuiltinType::Signer => "signer",
+            BuiltinType::Vector => "vector",
+        }
+    }
+
+    pub fn is_integer(self) -> bool {
+        matches!(self, BuiltinType::U8 | BuiltinType::U64 | BuiltinType::U128)
+    }
+}
+
+impl fmt::Display for BuiltinType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The functions operating on global storage, plus `freeze` and `assert`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinFunction {
+    BorrowGlobal,
+    BorrowGlobalMut,
+    MoveTo,
+    MoveFrom,
+    Exists,
+    Freeze,
+    Assert,
+}
+
+impl BuiltinFunction {
+    pub const ALL: &'static [BuiltinFunction] = &[
+        BuiltinFunction::BorrowGlobal,
+        BuiltinFunction::BorrowGlobalMut,
+        BuiltinFunction::MoveTo,
+        BuiltinFunction::MoveFrom,
+        BuiltinFunction::Exists,
+        BuiltinFunction::Freeze,
+        BuiltinFunction::Assert,
+    ];
+
+    pub fn by_name(name: &Name) -> Option<BuiltinFunction> {
+        BuiltinFunction::ALL.iter().copied().find(|it| it.as_str() == name.as_str())
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BuiltinFunction::BorrowGlobal => "borrow_global",
+            BuiltinFunction::BorrowGlobalMut => "borrow_global_mut",
+            BuiltinFunction::MoveTo => "move_to",
+            BuiltinFunction::MoveFrom => "move_from",
+            BuiltinFunction::Exists => "exists",
+            BuiltinFunction::Freeze => "freeze",
+            BuiltinFunction::Assert => "assert",
+        }
+    }
+}
+
+impl fmt::Display for BuiltinFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,49 @@
+//! Defines database & queries for name resolution.
+use std::sync::Arc;
+
+use base_db::{salsa, FileId, SourceDatabase};
+
+use crate::{
+    ast_id_map::AstIdMap,
+    body::{scope::ExprScopes, Body, BodySourceMap},
+    item_tree::ItemTree,
+    nameres::DefMap,
+    ConstId, ConstLoc, FunctionId, FunctionLoc, ModuleId, ModuleLoc, ScriptId, ScriptLoc, StructId,
+    StructLoc,
+};
+
+#[salsa::query_group(InternDatabaseStorage)]
+pub trait InternDatabase: SourceDatabase {
+    #[salsa::interned]
+    fn intern_module(&self, loc: ModuleLoc) -> ModuleId;
+    #[salsa::interned]
+    fn intern_script(&self, loc: ScriptLoc) -> ScriptId;
+    #[salsa::interned]
+    fn intern_function(&self, loc: FunctionLoc) -> FunctionId;
+    #[salsa::interned]
+    fn intern_struct(&self, loc: StructLoc) -> StructId;
+    #[salsa::interned]
+    fn intern_const(&self, loc: ConstLoc) -> ConstId;
+}
+
+#[salsa::query_group(DefDatabaseStorage)]
+pub trait DefDatabase: InternDatabase {
+    #[salsa::invoke(AstIdMap::ast_id_map_query)]
+    fn ast_id_map(&self, file_id: FileId) -> Arc<AstIdMap>;
+
+    #[salsa::invoke(ItemTree::file_item_tree_query)]
+    fn file_item_tree(&self, file_id: FileId) -> Arc<ItemTree>;
+
+    /// All modules and scripts of the workspace and its libraries.
+    #[salsa::invoke(DefMap::def_map_query)]
+    fn def_map(&self) -> Arc<DefMap>;
+
+    #[salsa::invoke(Body::body_with_source_map_query)]
+    fn body_with_source_map(&self, id: FunctionId) -> (Arc<Body>, Arc<BodySourceMap>);
+
+    #[salsa::invoke(Body::body_query)]
+    fn body(&self, id: FunctionId) -> Arc<Body>;
+
+    #[salsa::invoke(ExprScopes::expr_scopes_query)]
+    fn expr_scopes(&self, id: FunctionId) -> Arc<ExprScopes>;
+}
@@ -0,0 +1,174 @@
+//! Diagnostics for the names which don't resolve.
+//!
+//! Every path of the file is resolved in the namespace implied by its
+//! position. A qualified path whose qualifier doesn't name a module is
+//! reported as an unresolved module, rather than as an unresolved item.
+//!
+//! Paths in specs and attributes are skipped: the specification language has
+//! builtins and scoping rules of its own, which aren't modelled yet.
+use base_db::FileId;
+use syntax::{
+    ast::{self, AstNode},
+    syntax_node::SyntaxNode,
+    AstPtr, SyntaxKind, SyntaxNodePtr,
+};
+
+use crate::{
+    db::DefDatabase,
+    path::Path,
+    source_analyzer::{PathNs, SourceAnalyzer},
+    InFile,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefDiagnostic {
+    /// A use tree whose path names neither a module nor an item of a module.
+    UnresolvedImport { use_tree: InFile<AstPtr<ast::UseTree>> },
+    /// A path which should name a module, like `M` in `M::f`.
+    UnresolvedModule { path: InFile<AstPtr<ast::Path>>, module: String },
+    /// A path in a type, or in a struct literal or pattern.
+    UnresolvedType { path: InFile<AstPtr<ast::Path>>, name: String },
+    /// A path in an expression, or the local of `move x` and `copy x`.
+    UnresolvedName { node: InFile<SyntaxNodePtr>, name: String },
+}
+
+/// Collects the diagnostics of all modules and scripts of the file.
+pub fn file_diagnostics(db: &dyn DefDatabase, file_id: FileId) -> Vec<DefDiagnostic> {
+    let mut acc = Vec::new();
+    unresolved_imports(db, file_id, &mut acc);
+
+    let source_file = db.parse(file_id).tree();
+    for node in source_file.syntax().descendants() {
+        if let Some(path) = ast::Path::cast(node.clone()) {
+            unresolved_path(db, InFile::new(file_id, &path), &mut acc);
+        } else if let Some(name_ref) = moved_or_copied_local(&node) {
+            unresolved_local(db, InFile::new(file_id, &name_ref), &mut acc);
+        }
+    }
+    acc
+}
+
+fn unresolved_imports(db: &dyn DefDatabase, file_id: FileId, acc: &mut Vec<DefDiagnostic>) {
+    let def_map = db.def_map();
+    for import in def_map.unresolved_imports() {
+        if import.use_decl.file_id != file_id {
+            continue;
+        }
+        let use_decl = import.use_decl.to_node(db);
+        // The index counts the use trees of the declaration in preorder.
+        let use_tree = use_decl
+            .syntax()
+            .descendants()
+            .filter_map(ast::UseTree::cast)
+            .nth(import.index as usize);
+        if let Some(use_tree) = use_tree {
+            let use_tree = InFile::new(file_id, AstPtr::new(&use_tree));
+            acc.push(DefDiagnostic::UnresolvedImport { use_tree });
+        }
+    }
+}
+
+fn unresolved_path(db: &dyn DefDatabase, path: InFile<&ast::Path>, acc: &mut Vec<DefDiagnostic>) {
+    // Imports are checked when the `DefMap` is built.
+    let ns = match PathNs::of(path.value) {
+        Some(PathNs::Import) | None => return,
+        Some(ns) => ns,
+    };
+    // Qualifiers are checked together with the path they qualify.
+    if path.value.syntax().parent().is_some_and(|it| it.kind() == SyntaxKind::PATH) {
+        return;
+    }
+    if is_in_spec_or_attr(path.value.syntax()) {
+        return;
+    }
+    let hir_path = match Path::from_ast(path.value.clone()) {
+        Some(it) => it,
+        None => return,
+    };
+    let sa = match SourceAnalyzer::new_for_node(db, path.map(|it| it.syntax())) {
+        Some(it) => it,
+        None => return,
+    };
+    if sa.resolve_path(db, path.value).is_some() {
+        return;
+    }
+
+    let file_id = path.file_id;
+    if ns == PathNs::Module {
+        let module = path_display(&hir_path);
+        let path = InFile::new(file_id, AstPtr::new(path.value));
+        acc.push(DefDiagnostic::UnresolvedModule { path, module });
+        return;
+    }
+    // `0x1::M::f` has the qualifier `0x1::M`, but the qualifier of `0x1::M`
+    // is a bare address, which isn't a path of its own.
+    if let Some((qualifier, hir_qualifier)) =
+        path.value.qualifier().and_then(|it| Some((it.clone(), Path::from_ast(it)?)))
+    {
+        if sa.resolve_path(db, &qualifier).is_none() {
+            let module = path_display(&hir_qualifier);
+            let path = InFile::new(file_id, AstPtr::new(&qualifier));
+            acc.push(DefDiagnostic::UnresolvedModule { path, module });
+            return;
+        }
+    }
+    let name = path_display(&hir_path);
+    match ns {
+        PathNs::Type => {
+            let path = InFile::new(file_id, AstPtr::new(path.value));
+            acc.push(DefDiagnostic::UnresolvedType { path, name });
+        }
+        _ => {
+            let node = InFile::new(file_id, SyntaxNodePtr::new(path.value.syntax()));
+            acc.push(DefDiagnostic::UnresolvedName { node, name });
+        }
+    }
+}
+
+fn unresolved_local(
+    db: &dyn DefDatabase,
+    name_ref: InFile<&ast::NameRef>,
+    acc: &mut Vec<DefDiagnostic>,
+) {
+    if is_in_spec_or_attr(name_ref.value.syntax()) {
+        return;
+    }
+    let sa = match SourceAnalyzer::new_for_node(db, name_ref.map(|it| it.syntax())) {
+        Some(it) => it,
+        None => return,
+    };
+    // Outside of a body there are no locals, and nothing to report.
+    if sa.body_source_map().is_none() || sa.resolve_local(name_ref.value).is_some() {
+        return;
+    }
+    let node = InFile::new(name_ref.file_id, SyntaxNodePtr::new(name_ref.value.syntax()));
+    acc.push(DefDiagnostic::UnresolvedName {
+        node,
+        name: name_ref.value.syntax().text().to_string(),
+    });
+}
+
+/// The name of `move x` or `copy x`.
+fn moved_or_copied_local(node: &SyntaxNode) -> Option<ast::NameRef> {
+    if let Some(it) = ast::MoveExpr::cast(node.clone()) {
+        return it.name_ref();
+    }
+    ast::CopyExpr::cast(node.clone())?.name_ref()
+}
+
+fn is_in_spec_or_attr(node: &SyntaxNode) -> bool {
+    node.ancestors().any(|it| {
+        matches!(
+            it.kind(),
+            SyntaxKind::SPEC_BLOCK
+                | SyntaxKind::SPEC_DEF
+                | SyntaxKind::SPEC_SCHEMA
+                | SyntaxKind::ATTR_LIST
+        )
+    })
+}
+
+/// The path without its type arguments, as it is shown in messages.
+fn path_display(path: &Path) -> String {
+    Path { type_args: None, ..path.clone() }.to_string()
+}
@@ -0,0 +1,299 @@
+//! The expressions and patterns of function bodies, lowered from the syntax
+//! tree.
+//!
+//! Everything is stored in the arenas of the `Body` and referred to by
+//! `ExprId` and `PatId`. Parts which are missing or broken in the source code
+//! become `Expr::Missing` and `Pat::Missing`, so that every syntax node which
+//! is lowered has an ID.
+//!
+//! Spec blocks are kept as opaque `Expr::Spec` nodes: the specification
+//! language has its own scoping rules and builtins.
+use crate::{
+    arena::Idx,
+    builtin::BuiltinType,
+    name::Name,
+    path::{Address, Path},
+    type_ref::{Mutability, TypeRef},
+};
+
+pub type ExprId = Idx<Expr>;
+pub type PatId = Idx<Pat>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Literal {
+    Bool(bool),
+    /// `1`, `0xFF` or `1u8`. The value is `None` if it doesn't fit in
+    /// `u128`.
+    Integer {
+        value: Option<u128>,
+        suffix: Option<BuiltinType>,
+    },
+    /// `b"bytes"` or `x"ff"`, both `vector<u8>`.
+    ByteString,
+    /// `@0x1` or `@Std`.
+    Address(Address),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Missing,
+    Path(Path),
+    Literal(Literal),
+    /// `{ stmts; tail }`. The tail is the last expression of the block, when
+    /// it isn't followed by a semicolon.
+    Block {
+        statements: Box<[Statement]>,
+        tail: Option<ExprId>,
+    },
+    If {
+        condition: ExprId,
+        then_branch: ExprId,
+        else_branch: Option<ExprId>,
+    },
+    While {
+        condition: ExprId,
+        body: ExprId,
+    },
+    Loop {
+        body: ExprId,
+    },
+    Break,
+    Continue,
+    Return {
+        expr: Option<ExprId>,
+    },
+    Abort {
+        expr: ExprId,
+    },
+    Call {
+        callee: ExprId,
+        args: Box<[ExprId]>,
+    },
+    /// `s.f`.
+    Field {
+        expr: ExprId,
+        name: Name,
+    },
+    Index {
+        base: ExprId,
+        index: ExprId,
+    },
+    /// `S { f: x }` or `S { f }`.
+    Record {
+        path: Option<Path>,
+        fields: Box<[RecordLitField]>,
+    },
+    UnaryOp {
+        expr: ExprId,
+        op: UnaryOp,
+    },
+    /// `&e` or `&mut e`.
+    Ref {
+        expr: ExprId,
+        mutability: Mutability,
+    },
+    /// `move x`.
+    Move {
+        name: Name,
+    },
+    /// `copy x`.
+    Copy {
+        name: Name,
+    },
+    /// Binary operations, including the assignment `lhs = rhs`. The operator
+    /// is `None` if it is missing in the source code.
+    BinaryOp {
+        lhs: ExprId,
+        rhs: ExprId,
+        op: Option<BinaryOp>,
+    },
+    /// `(e as u64)`.
+    Cast {
+        expr: ExprId,
+        type_ref: TypeRef,
+    },
+    /// `(a, b)`; `()` is the empty tuple.
+    Tuple {
+        exprs: Box<[ExprId]>,
+    },
+    /// `vector[1, 2]` or `vector<u8>[]`.
+    Vector {
+        type_arg: Option<TypeRef>,
+        elements: Box<[ExprId]>,
+    },
+    /// A spec block, or an expression which is only valid in specs.
+    Spec,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordLitField {
+    pub name: Name,
+    pub expr: ExprId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Statement {
+    Let { pat: PatId, type_ref: Option<TypeRef>, initializer: Option<ExprId> },
+    Expr(ExprId),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnaryOp {
+    /// `!`
+    Not,
+    /// `*`
+    Deref,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOp {
+    /// `=`
+    Assignment,
+    /// `||`, `&&`, `==>` and `<==>`.
+    Logic(LogicOp),
+    /// `==`, `!=`, `<`, `>`, `<=` and `>=`.
+    Cmp(CmpOp),
+    /// `+`, `-`, `*`, `/`, `%`, `|`, `^`, `&`, `<<` and `>>`.
+    Arith(ArithOp),
+    /// `..`, only valid in specs.
+    Range,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogicOp {
+    Or,
+    And,
+    Implies,
+    Iff,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CmpOp {
+    Eq,
+    NotEq,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shl,
+    Shr,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pat {
+    Missing,
+    /// `_`
+    Wild,
+    /// A binding, like `x` in `let x = 1;`.
+    Bind {
+        name: Name,
+    },
+    /// `(a, b)`
+    Tuple {
+        pats: Box<[PatId]>,
+    },
+    /// `S { f: x, g }`. `ellipsis` is set by a trailing `..`.
+    Record {
+        path: Option<Path>,
+        fields: Box<[RecordFieldPat]>,
+        ellipsis: bool,
+    },
+    Path(Path),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordFieldPat {
+    pub name: Name,
+    pub pat: PatId,
+}
+
+impl Expr {
+    /// Calls `f` with every direct child expression.
+    pub fn walk_child_exprs(&self, mut f: impl FnMut(ExprId)) {
+        match self {
+            Expr::Missing
+            | Expr::Path(_)
+            | Expr::Literal(_)
+            | Expr::Break
+            | Expr::Continue
+            | Expr::Move { .. }
+            | Expr::Copy { .. }
+            | Expr::Spec => {}
+            Expr::Block { statements, tail } => {
+                for stmt in statements.iter() {
+                    match stmt {
+                        Statement::Let { initializer, .. } => {
+                            if let Some(expr) = initializer {
+                                f(*expr);
+                            }
+                        }
+                        Statement::Expr(expr) => f(*expr),
+                    }
+                }
+                if let Some(expr) = tail {
+                    f(*expr);
+                }
+            }
+            Expr::If { condition, then_branch, else_branch } => {
+                f(*condition);
+                f(*then_branch);
+                if let Some(expr) = else_branch {
+                    f(*expr);
+                }
+            }
+            Expr::While { condition, body } => {
+                f(*condition);
+                f(*body);
+            }
+            Expr::Loop { body } => f(*body),
+            Expr::Return { expr } => {
+                if let Some(expr) = expr {
+                    f(*expr);
+                }
+            }
+            Expr::Abort { expr }
+            | Expr::Field { expr, .. }
+            | Expr::UnaryOp { expr, .. }
+            | Expr::Ref { expr, .. }
+            | Expr::Cast { expr, .. } => f(*expr),
+            Expr::Call { callee, args } => {
+                f(*callee);
+                args.iter().copied().for_each(f);
+            }
+            Expr::Index { base, index } => {
+                f(*base);
+                f(*index);
+            }
+            Expr::Record { fields, .. } => fields.iter().for_each(|field| f(field.expr)),
+            Expr::BinaryOp { lhs, rhs, .. } => {
+                f(*lhs);
+                f(*rhs);
+            }
+            Expr::Tuple { exprs } => exprs.iter().copied().for_each(f),
+            Expr::Vector { elements, .. } => elements.iter().copied().for_each(f),
+        }
+    }
+}
+
+impl Pat {
+    /// Calls `f` with every direct child pattern.
+    pub fn walk_child_pats(&self, mut f: impl FnMut(PatId)) {
+        match self {
+            Pat::Missing | Pat::Wild | Pat::Bind { .. } | Pat::Path(_) => {}
+            Pat::Tuple { pats } => pats.iter().copied().for_each(f),
+            Pat::Record { fields, .. } => fields.iter().for_each(|field| f(field.pat)),
+        }
+    }
+}
@@ -0,0 +1,317 @@
+//! A simplified AST that only contains items.
+//!
+//! This is the primary IR used throughout `hir_def`. It is the input to the
+//! name resolution algorithm, and the signatures of the items are read from
+//! it.
+//!
+//! One `ItemTree` is created for each Move file. It contains the addresses,
+//! modules and scripts of the file, and in them the items: functions, structs,
+//! constants, `use`s, `friend`s and specs, but not the bodies of functions.
+//!
+//! The `ItemTree` doesn't store any text ranges: items point back into the
+//! syntax tree through `FileAstId`s, which are indices of the items in the
+//! file. That way an edit which doesn't change any signature, like an edit of
+//! a function body or of whitespace, produces an equal `ItemTree`, and salsa
+//! doesn't recompute the queries which depend on it.
+mod lower;
+mod pretty;
+#[cfg(test)]
+mod tests;
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Index,
+    sync::Arc,
+};
+
+use base_db::FileId;
+use syntax::ast;
+
+pub use crate::arena::Idx;
+
+use crate::{
+    ability::Abilities, ast_id_map::FileAstId, db::DefDatabase, name::Name, path::Address,
+    path::Path, type_ref::TypeRef,
+};
+
+/// The item tree of a source file.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ItemTree {
+    top_level: Vec<TopLevelItem>,
+    data: ItemTreeData,
+}
+
+impl ItemTree {
+    pub(crate) fn file_item_tree_query(db: &dyn DefDatabase, file_id: FileId) -> Arc<ItemTree> {
+        let source_file = db.parse(file_id).tree();
+        let ctx = lower::Ctx::new(db, file_id);
+        Arc::new(ctx.lower_source_file(&source_file))
+    }
+
+    /// Addresses, modules and scripts at the top level of the file, in source
+    /// order.
+    pub fn top_level_items(&self) -> &[TopLevelItem] {
+        &self.top_level
+    }
+
+    /// All modules of the file, including the ones in `address` blocks.
+    pub fn modules(&self) -> impl Iterator<Item = Idx<Module>> + '_ {
+        (0..self.data.modules.len()).map(Idx::from_raw)
+    }
+
+    pub fn scripts(&self) -> impl Iterator<Item = Idx<Script>> + '_ {
+        (0..self.data.scripts.len()).map(Idx::from_raw)
+    }
+
+    /// Renders the item tree as Move code, for tests.
+    pub fn pretty_print(&self) -> String {
+        pretty::print_item_tree(self)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ItemTreeData {
+    addresses: Vec<AddressBlock>,
+    modules: Vec<Module>,
+    scripts: Vec<Script>,
+    functions: Vec<Function>,
+    structs: Vec<Struct>,
+    consts: Vec<Const>,
+    uses: Vec<Use>,
+    friends: Vec<Friend>,
+    specs: Vec<Spec>,
+    schemas: Vec<Schema>,
+}
+
+/// An item in the item tree of a specific file.
+pub struct ItemTreeId<N> {
+    pub file_id: FileId,
+    pub value: Idx<N>,
+}
+
+impl<N> ItemTreeId<N> {
+    pub fn new(file_id: FileId, value: Idx<N>) -> ItemTreeId<N> {
+        ItemTreeId { file_id, value }
+    }
+
+    pub fn item_tree(&self, db: &dyn DefDatabase) -> Arc<ItemTree> {
+        db.file_item_tree(self.file_id)
+    }
+}
+
+impl<N> Clone for ItemTreeId<N> {
+    fn clone(&self) -> ItemTreeId<N> {
+        *self
+    }
+}
+impl<N> Copy for ItemTreeId<N> {}
+
+impl<N> PartialEq for ItemTreeId<N> {
+    fn eq(&self, other: &ItemTreeId<N>) -> bool {
+        self.file_id == other.file_id && self.value == other.value
+    }
+}
+impl<N> Eq for ItemTreeId<N> {}
+
+impl<N> Hash for ItemTreeId<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.file_id.hash(state);
+        self.value.hash(state);
+    }
+}
+
+impl<N> fmt::Debug for ItemTreeId<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ItemTreeId")
+            .field("file_id", &self.file_id)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+macro_rules! impl_index {
+    ( $($fld:ident: $t:ty),+ $(,)? ) => {
+        $(
+            impl Index<Idx<$t>> for ItemTree {
+                type Output = $t;
+
+                fn index(&self, index: Idx<$t>) -> &Self::Output {
+                    &self.data.$fld[index.into_raw() as usize]
+                }
+            }
+        )+
+    };
+}
+
+impl_index!(
+    addresses: AddressBlock,
+    modules: Module,
+    scripts: Script,
+    functions: Function,
+    structs: Struct,
+    consts: Const,
+    uses: Use,
+    friends: Friend,
+    specs: Spec,
+    schemas: Schema,
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopLevelItem {
+    Address(Idx<AddressBlock>),
+    Module(Idx<Module>),
+    Script(Idx<Script>),
+}
+
+/// An item of a module or a script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModItem {
+    Function(Idx<Function>),
+    Struct(Idx<Struct>),
+    Const(Idx<Const>),
+    Use(Idx<Use>),
+    Friend(Idx<Friend>),
+    Spec(Idx<Spec>),
+    Schema(Idx<Schema>),
+}
+
+/// `address 0x1 { ... }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressBlock {
+    pub address: Option<Address>,
+    pub modules: Box<[Idx<Module>]>,
+    pub ast_id: FileAstId<ast::AddressDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    pub name: Name,
+    /// The address of the module, either from the `address` block around it,
+    /// or from the module itself, as in `module 0x1::M`.
+    pub address: Option<Address>,
+    pub items: Box<[ModItem]>,
+    pub ast_id: FileAstId<ast::ModuleDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+    pub items: Box<[ModItem]>,
+    pub ast_id: FileAstId<ast::ScriptDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    pub name: Name,
+    pub visibility: Visibility,
+    pub is_native: bool,
+    pub is_entry: bool,
+    /// Whether the function is marked with `#[test]`.
+    pub is_test: bool,
+    pub type_params: Box<[TypeParam]>,
+    pub params: Box<[Param]>,
+    /// The return type, `()` if there is none.
+    pub ret_type: TypeRef,
+    pub acquires: Box<[Path]>,
+    pub has_body: bool,
+    pub ast_id: FileAstId<ast::FunctionDef>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    Private,
+    Public,
+    /// `public(script)`.
+    Script,
+    /// `public(friend)`.
+    Friend,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: Name,
+    pub type_ref: TypeRef,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeParam {
+    pub name: Name,
+    pub is_phantom: bool,
+    pub bounds: Abilities,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Struct {
+    pub name: Name,
+    pub is_native: bool,
+    pub type_params: Box<[TypeParam]>,
+    pub abilities: Abilities,
+    pub fields: Box<[Field]>,
+    pub ast_id: FileAstId<ast::Struct>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: Name,
+    pub type_ref: TypeRef,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Const {
+    pub name: Name,
+    pub type_ref: TypeRef,
+    pub ast_id: FileAstId<ast::ConstDef>,
+}
+
+/// A `use` declaration, flattened into the names it imports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Use {
+    pub imports: Box<[Import]>,
+    pub ast_id: FileAstId<ast::UseDecl>,
+}
+
+/// A single import of a `use`: `use 0x1::M::{Self, f as g}` imports
+/// `0x1::M` and `0x1::M::f` as `g`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Import {
+    pub path: Path,
+    pub alias: Option<Name>,
+    /// The index of the `UseTree` of the import among all the use trees of
+    /// the declaration, in preorder.
+    pub index: u32,
+}
+
+impl Import {
+    /// The name under which the import is visible.
+    pub fn name(&self) -> &Name {
+        self.alias.as_ref().unwrap_or_else(|| self.path.last_segment())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Friend {
+    pub path: Option<Path>,
+    pub ast_id: FileAstId<ast::FriendDecl>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spec {
+    pub target: SpecTarget,
+    pub ast_id: FileAstId<ast::SpecDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpecTarget {
+    /// `spec module { ... }`.
+    Module,
+    /// `spec f { ... }`, `spec fun f { ... }` or `spec struct S { ... }`.
+    Item(Name),
+}
+
+/// `spec schema S<T> { ... }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schema {
+    pub name: Name,
+    pub type_params: Box<[TypeParam]>,
+    pub ast_id: FileAstId<ast::SpecSchema>,
+}
@@ -0,0 +1,271 @@
+//! AST -> `ItemTree` lowering code.
+use std::sync::Arc;
+
+use base_db::FileId;
+use syntax::ast::{self, HasModuleItem, HasName, HasTypeParams, HasVisibility};
+
+use super::*;
+use crate::{
+    ability::Abilities,
+    ast_id_map::AstIdMap,
+    name::{known, AsName},
+    path::{Path, PathKind},
+};
+
+pub(super) struct Ctx {
+    tree: ItemTree,
+    source_ast_id_map: Arc<AstIdMap>,
+}
+
+impl Ctx {
+    pub(super) fn new(db: &dyn DefDatabase, file_id: FileId) -> Ctx {
+        Ctx { tree: ItemTree::default(), source_ast_id_map: db.ast_id_map(file_id) }
+    }
+
+    pub(super) fn lower_source_file(mut self, source_file: &ast::SourceFile) -> ItemTree {
+        // Addresses, modules and scripts can't be interleaved, so lowering them
+        // one kind after another keeps the source order.
+        for address_def in source_file.address_defs() {
+            let idx = self.lower_address(&address_def);
+            self.tree.top_level.push(TopLevelItem::Address(idx));
+        }
+        for module_def in source_file.module_defs() {
+            let address = module_def.address_ident().and_then(|it| Address::from_ast(&it));
+            let idx = self.lower_module(&module_def, address);
+            self.tree.top_level.push(TopLevelItem::Module(idx));
+        }
+        for script_def in source_file.script_defs() {
+            let idx = self.lower_script(&script_def);
+            self.tree.top_level.push(TopLevelItem::Script(idx));
+        }
+        self.tree
+    }
+
+    fn lower_address(&mut self, address_def: &ast::AddressDef) -> Idx<AddressBlock> {
+        let address = address_def.address_ident().and_then(|it| Address::from_ast(&it));
+        let modules = address_def
+            .address_item_list()
+            .into_iter()
+            .flat_map(|it| it.module_defs())
+            .map(|module_def| self.lower_module(&module_def, address.clone()))
+            .collect();
+        let ast_id = self.source_ast_id_map.ast_id(address_def);
+        alloc(&mut self.tree.data.addresses, AddressBlock { address, modules, ast_id })
+    }
+
+    fn lower_module(
+        &mut self,
+        module_def: &ast::ModuleDef,
+        address: Option<Address>,
+    ) -> Idx<Module> {
+        let name = module_def.name().map(|it| it.as_name()).unwrap_or_else(Name::missing);
+        let items = self.lower_items(module_def.item_list());
+        let ast_id = self.source_ast_id_map.ast_id(module_def);
+        alloc(&mut self.tree.data.modules, Module { name, address, items, ast_id })
+    }
+
+    fn lower_script(&mut self, script_def: &ast::ScriptDef) -> Idx<Script> {
+        let items = self.lower_items(script_def.item_list());
+        let ast_id = self.source_ast_id_map.ast_id(script_def);
+        alloc(&mut self.tree.data.scripts, Script { items, ast_id })
+    }
+
+    fn lower_items(&mut self, item_list: Option<ast::ItemList>) -> Box<[ModItem]> {
+        item_list.into_iter().flat_map(|it| it.items()).map(|item| self.lower_item(&item)).collect()
+    }
+
+    fn lower_item(&mut self, item: &ast::Item) -> ModItem {
+        match item {
+            ast::Item::FunctionDef(it) => ModItem::Function(self.lower_function(it)),
+            ast::Item::Struct(it) => ModItem::Struct(self.lower_struct(it)),
+            ast::Item::ConstDef(it) => ModItem::Const(self.lower_const(it)),
+            ast::Item::UseDecl(it) => ModItem::Use(self.lower_use(it)),
+            ast::Item::FriendDecl(it) => ModItem::Friend(self.lower_friend(it)),
+            ast::Item::SpecDef(it) => ModItem::Spec(self.lower_spec(it)),
+            ast::Item::SpecSchema(it) => ModItem::Schema(self.lower_schema(it)),
+        }
+    }
+
+    fn lower_function(&mut self, fun: &ast::FunctionDef) -> Idx<Function> {
+        let visibility = lower_visibility(fun.visibility());
+        let params = fun
+            .param_list()
+            .into_iter()
+            .flat_map(|it| it.params())
+            .map(|param| Param {
+                name: name_of(&param),
+                type_ref: TypeRef::from_ast_opt(param.ty()),
+            })
+            .collect();
+        let ret_type = match fun.ret_type() {
+            Some(ret_type) => TypeRef::from_ast_opt(ret_type.ty()),
+            None => TypeRef::unit(),
+        };
+        let acquires = fun
+            .acquires_list()
+            .into_iter()
+            .flat_map(|it| it.paths())
+            .filter_map(Path::from_ast)
+            .collect();
+        let res = Function {
+            name: name_of(fun),
+            visibility,
+            is_native: fun.native_token().is_some(),
+            is_entry: fun.entry_token().is_some(),
+            is_test: fun.is_test(),
+            type_params: lower_type_params(fun.generic_param_list()),
+            params,
+            ret_type,
+            acquires,
+            has_body: fun.body().is_some(),
+            ast_id: self.source_ast_id_map.ast_id(fun),
+        };
+        alloc(&mut self.tree.data.functions, res)
+    }
+
+    fn lower_struct(&mut self, strukt: &ast::Struct) -> Idx<Struct> {
+        let abilities =
+            strukt.ability_list().map(|it| Abilities::from_ast(it.abilities())).unwrap_or_default();
+        let fields = strukt
+            .record_field_list()
+            .into_iter()
+            .flat_map(|it| it.record_fields())
+            .map(|field| Field {
+                name: name_of(&field),
+                type_ref: TypeRef::from_ast_opt(field.ty()),
+            })
+            .collect();
+        let res = Struct {
+            name: name_of(strukt),
+            is_native: strukt.native_token().is_some(),
+            type_params: lower_type_params(strukt.generic_param_list()),
+            abilities,
+            fields,
+            ast_id: self.source_ast_id_map.ast_id(strukt),
+        };
+        alloc(&mut self.tree.data.structs, res)
+    }
+
+    fn lower_const(&mut self, konst: &ast::ConstDef) -> Idx<Const> {
+        let res = Const {
+            name: name_of(konst),
+            type_ref: TypeRef::from_ast_opt(konst.ty()),
+            ast_id: self.source_ast_id_map.ast_id(konst),
+        };
+        alloc(&mut self.tree.data.consts, res)
+    }
+
+    fn lower_use(&mut self, use_decl: &ast::UseDecl) -> Idx<Use> {
+        let mut imports = Vec::new();
+        if let Some(tree) = use_decl.use_tree() {
+            let mut index = 0;
+            lower_use_tree(None, &tree, &mut index, &mut imports);
+        }
+        let res = Use { imports: imports.into(), ast_id: self.source_ast_id_map.ast_id(use_decl) };
+        alloc(&mut self.tree.data.uses, res)
+    }
+
+    fn lower_friend(&mut self, friend: &ast::FriendDecl) -> Idx<Friend> {
+        let res = Friend {
+            path: friend.path().and_then(Path::from_ast),
+            ast_id: self.source_ast_id_map.ast_id(friend),
+        };
+        alloc(&mut self.tree.data.friends, res)
+    }
+
+    fn lower_spec(&mut self, spec: &ast::SpecDef) -> Idx<Spec> {
+        let target = if spec.module_token().is_some() {
+            SpecTarget::Module
+        } else {
+            SpecTarget::Item(spec.name_ref().map(|it| it.as_name()).unwrap_or_else(Name::missing))
+        };
+        let res = Spec { target, ast_id: self.source_ast_id_map.ast_id(spec) };
+        alloc(&mut self.tree.data.specs, res)
+    }
+
+    fn lower_schema(&mut self, schema: &ast::SpecSchema) -> Idx<Schema> {
+        let res = Schema {
+            name: name_of(schema),
+            type_params: lower_type_params(schema.generic_param_list()),
+            ast_id: self.source_ast_id_map.ast_id(schema),
+        };
+        alloc(&mut self.tree.data.schemas, res)
+    }
+}
+
+/// Flattens the use tree into imports. `prefix` is the path of the enclosing
+/// tree, for the trees in a `{...}` list.
+fn lower_use_tree(
+    prefix: Option<&Path>,
+    tree: &ast::UseTree,
+    index: &mut u32,
+    imports: &mut Vec<Import>,
+) {
+    let tree_index = *index;
+    *index += 1;
+    let path = tree.path().and_then(Path::from_ast);
+    let path = match (prefix, path) {
+        (None, path) => path,
+        (Some(prefix), Some(path)) => Some(concat_paths(prefix, &path)),
+        (Some(_), None) => None,
+    };
+    match tree.use_tree_list() {
+        Some(list) => {
+            for subtree in list.use_trees() {
+                lower_use_tree(path.as_ref(), &subtree, index, imports);
+            }
+        }
+        None => {
+            let path = match path {
+                Some(it) => it,
+                None => return,
+            };
+            let alias = tree.rename().and_then(|it| it.name()).map(|it| it.as_name());
+            imports.push(Import { path, alias, index: tree_index });
+        }
+    }
+}
+
+/// Joins `0x1::M` and `f` into `0x1::M::f`. `Self` refers to the prefix
+/// itself, so `0x1::M` and `Self` give `0x1::M`.
+fn concat_paths(prefix: &Path, path: &Path) -> Path {
+    let mut segments = prefix.segments.to_vec();
+    let is_self = path.kind == PathKind::Plain && path.as_ident() == Some(&known::self_());
+    if !is_self {
+        segments.extend(path.segments.iter().cloned());
+    }
+    Path { kind: prefix.kind.clone(), segments: segments.into(), type_args: None }
+}
+
+fn lower_visibility(visibility: Option<ast::Visibility>) -> Visibility {
+    match visibility {
+        None => Visibility::Private,
+        Some(it) if it.script_token().is_some() => Visibility::Script,
+        Some(it) if it.friend_token().is_some() => Visibility::Friend,
+        Some(_) => Visibility::Public,
+    }
+}
+
+fn lower_type_params(list: Option<ast::GenericParamList>) -> Box<[TypeParam]> {
+    list.into_iter()
+        .flat_map(|it| it.type_params())
+        .map(|param| TypeParam {
+            name: name_of(&param),
+            is_phantom: param.phantom_token().is_some(),
+            bounds: param
+                .ability_bound_list()
+                .map(|it| Abilities::from_ast(it.abilities()))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn name_of(node: &impl HasName) -> Name {
+    node.name().map(|it| it.as_name()).unwrap_or_else(Name::missing)
+}
+
+fn alloc<N>(arena: &mut Vec<N>, item: N) -> Idx<N> {
+    let idx = Idx::from_raw(arena.len());
+    arena.push(item);
+    idx
+}
@@ -0,0 +1,201 @@
+//! `ItemTree` debug printer.
+use stdx::format_to;
+
+use super::*;
+
+pub(super) fn print_item_tree(tree: &ItemTree) -> String {
+    let mut p = Printer { tree, buf: String::new(), indent_level: 0 };
+    for item in tree.top_level_items() {
+        p.print_top_level_item(*item);
+    }
+    p.buf
+}
+
+struct Printer<'a> {
+    tree: &'a ItemTree,
+    buf: String,
+    indent_level: usize,
+}
+
+impl<'a> Printer<'a> {
+    fn line(&mut self, text: &str) {
+        for _ in 0..self.indent_level {
+            self.buf.push_str("    ");
+        }
+        self.buf.push_str(text);
+        self.buf.push('\n');
+    }
+
+    fn block(&mut self, header: &str, f: impl FnOnce(&mut Self)) {
+        self.line(&format!("{} {{", header));
+        let body_start = self.buf.len();
+        self.indent_level += 1;
+        f(self);
+        self.indent_level -= 1;
+        if self.buf.len() == body_start {
+            // `module M {}` for an empty module.
+            self.buf.truncate(body_start - 1);
+            self.buf.push_str("}\n");
+        } else {
+            self.line("}");
+        }
+    }
+
+    fn print_top_level_item(&mut self, item: TopLevelItem) {
+        match item {
+            TopLevelItem::Address(idx) => {
+                let address = &self.tree[idx];
+                let header = format!("address {}", opt_address(&address.address));
+                self.block(&header, |p| {
+                    for module in address.modules.iter() {
+                        p.print_module(*module, false);
+                    }
+                });
+            }
+            TopLevelItem::Module(idx) => self.print_module(idx, true),
+            TopLevelItem::Script(idx) => {
+                let script = &self.tree[idx];
+                self.block("script", |p| p.print_items(&script.items));
+            }
+        }
+    }
+
+    fn print_module(&mut self, idx: Idx<Module>, with_address: bool) {
+        let module = &self.tree[idx];
+        let header = match (&module.address, with_address) {
+            (Some(address), true) => format!("module {}::{}", address, module.name),
+            _ => format!("module {}", module.name),
+        };
+        self.block(&header, |p| p.print_items(&module.items));
+    }
+
+    fn print_items(&mut self, items: &[ModItem]) {
+        for item in items {
+            let text = self.item_text(*item);
+            self.line(&text);
+        }
+    }
+
+    fn item_text(&self, item: ModItem) -> String {
+        let mut buf = String::new();
+        match item {
+            ModItem::Function(idx) => {
+                let fun = &self.tree[idx];
+                match fun.visibility {
+                    Visibility::Private => {}
+                    Visibility::Public => buf.push_str("public "),
+                    Visibility::Script => buf.push_str("public(script) "),
+                    Visibility::Friend => buf.push_str("public(friend) "),
+                }
+                if fun.is_native {
+                    buf.push_str("native ");
+                }
+                if fun.is_entry {
+                    buf.push_str("entry ");
+                }
+                format_to!(buf, "fun {}{}(", fun.name, type_params(&fun.type_params));
+                let params: Vec<String> = fun
+                    .params
+                    .iter()
+                    .map(|param| format!("{}: {}", param.name, param.type_ref))
+                    .collect();
+                format_to!(buf, "{}): {}", params.join(", "), fun.ret_type);
+                if !fun.acquires.is_empty() {
+                    let acquires: Vec<String> =
+                        fun.acquires.iter().map(|it| it.to_string()).collect();
+                    format_to!(buf, " acquires {}", acquires.join(", "));
+                }
+                buf.push_str(if fun.has_body { " { ... }" } else { ";" });
+                if fun.is_test {
+                    buf.insert_str(0, "#[test] ");
+                }
+            }
+            ModItem::Struct(idx) => {
+                let strukt = &self.tree[idx];
+                if strukt.is_native {
+                    buf.push_str("native ");
+                }
+                format_to!(buf, "struct {}{}", strukt.name, type_params(&strukt.type_params));
+                if !strukt.abilities.is_empty() {
+                    format_to!(buf, " has {}", strukt.abilities);
+                }
+                let fields: Vec<String> = strukt
+                    .fields
+                    .iter()
+                    .map(|field| format!("{}: {}", field.name, field.type_ref))
+                    .collect();
+                if fields.is_empty() {
+                    buf.push_str(" {}");
+                } else {
+                    format_to!(buf, " {{ {} }}", fields.join(", "));
+                }
+            }
+            ModItem::Const(idx) => {
+                let konst = &self.tree[idx];
+                format_to!(buf, "const {}: {};", konst.name, konst.type_ref);
+            }
+            ModItem::Use(idx) => {
+                let imports: Vec<String> = self.tree[idx]
+                    .imports
+                    .iter()
+                    .map(|import| match &import.alias {
+                        Some(alias) => format!("{} as {}", import.path, alias),
+                        None => import.path.to_string(),
+                    })
+                    .collect();
+                if imports.is_empty() {
+                    buf.push_str("use {error};");
+                } else {
+                    format_to!(buf, "use {};", imports.join(", "));
+                }
+            }
+            ModItem::Friend(idx) => match &self.tree[idx].path {
+                Some(path) => format_to!(buf, "friend {};", path),
+                None => buf.push_str("friend {error};"),
+            },
+            ModItem::Spec(idx) => match &self.tree[idx].target {
+                SpecTarget::Module => buf.push_str("spec module { ... }"),
+                SpecTarget::Item(name) => format_to!(buf, "spec {} {{ ... }}", name),
+            },
+            ModItem::Schema(idx) => {
+                let schema = &self.tree[idx];
+                format_to!(
+                    buf,
+                    "spec schema {}{} {{ ... }}",
+                    schema.name,
+                    type_params(&schema.type_params)
+                );
+            }
+        }
+        buf
+    }
+}
+
+fn type_params(params: &[TypeParam]) -> String {
+    if params.is_empty() {
+        return String::new();
+    }
+    let mut buf = String::from("<");
+    for (idx, param) in params.iter().enumerate() {
+        if idx > 0 {
+            buf.push_str(", ");
+        }
+        if param.is_phantom {
+            buf.push_str("phantom ");
+        }
+        buf.push_str(param.name.as_str());
+        let bounds: Vec<&str> = param.bounds.iter().map(|it| it.name()).collect();
+        if !bounds.is_empty() {
+            format_to!(buf, ": {}", bounds.join(" + "));
+        }
+    }
+    buf.push('>');
+    buf
+}
+
+fn opt_address(address: &Option<Address>) -> String {
+    match address {
+        Some(it) => it.to_string(),
+        None => "{error}".to_string(),
+    }
+}
@@ -0,0 +1,105 @@
+use base_db::fixture::WithFixture;
+use expect_test::{expect, Expect};
+
+use crate::{db::DefDatabase, test_db::TestDB};
+
+fn check(ra_fixture: &str, expect: Expect) {
+    let (db, file_id) = TestDB::with_single_file(ra_fixture);
+    let item_tree = db.file_item_tree(file_id);
+    expect.assert_eq(&item_tree.pretty_print());
+}
+
+#[test]
+fn smoke() {
+    check(
+        r#"
+address 0x1 {
+module Coin {
+    struct Coin<phantom T> has store { value: u64 }
+    const ERR: u64 = 1;
+    public fun value<T>(coin: &Coin<T>): u64 { coin.value }
+}
+}
+module 0x2::Wallet {
+    use 0x1::Coin::{Self, Coin as C};
+    friend 0x2::Bank;
+
+    struct Balance has key { coins: vector<0x1::Coin::Coin<u8>> }
+
+    #[test]
+    fun test_balance() {}
+
+    public(friend) native fun deposit<T: store + drop>(addr: address, amount: u64, flag: (u8, bool)): &mut u64 acquires Balance;
+
+    spec deposit { aborts_if false; }
+    spec module {}
+    spec schema Ok<T> {}
+}
+script {
+    use 0x2::Wallet as W;
+    fun main(account: signer) { W::deposit(); }
+}
+"#,
+        expect![[r##"
+            address 0x1 {
+                module Coin {
+                    struct Coin<phantom T> has store { value: u64 }
+                    const ERR: u64;
+                    public fun value<T>(coin: &Coin<T>): u64 { ... }
+                }
+            }
+            module 0x2::Wallet {
+                use 0x1::Coin, 0x1::Coin::Coin as C;
+                friend 0x2::Bank;
+                struct Balance has key { coins: vector<>, Coin: {error}, Coin: {error}, u8: {error} }
+                #[test] fun test_balance(): () { ... }
+                public(friend) native fun deposit<T: drop + store>(addr: address, amount: u64, flag: (u8, bool)): &mut u64 acquires Balance;
+                spec deposit { ... }
+                spec module { ... }
+                spec schema Ok<T> { ... }
+            }
+            script {
+                use 0x2::Wallet as W;
+                fun main(account: signer): () { ... }
+            }
+        "##]],
+    );
+}
+
+#[test]
+fn normalizes_addresses() {
+    check(
+        r#"
+module 0x00A::M {}
+address Std { module N {} }
+"#,
+        expect![[r#"
+            address Std {
+                module N {}
+            }
+            module 0xa::M {}
+        "#]],
+    );
+}
+
+#[test]
+fn broken_items() {
+    check(
+        r#"
+module 0x1::M {
+    fun (x: ) {}
+    struct S has copy, unknown { f }
+    use 0x1::;
+    fun g(
+}
+"#,
+        expect![[r#"
+            module 0x1::M {
+                fun [missing name](): ();
+                struct S has copy { f: {error} }
+                use {error};
+                fun g(): ();
+            }
+        "#]],
+    );
+}
@@ -0,0 +1,295 @@
+//! `hir_def` crate contains everything between the syntax tree and type
+//! inference.
+//!
+//! It lowers every file into an `ItemTree`, which has the addresses, modules,
+//! scripts and item signatures of the file but no text ranges. The `DefMap`
+//! then collects the modules of every source root, so that a module can be
+//! found by its address and name.
+//!
+//! Items are identified by interned IDs like `FunctionId`, which point into
+//! the item trees and stay the same as long as the set of items of a file
+//! doesn't change.
+//!
+//! Function bodies are lowered separately into a `Body` of expressions and
+//! patterns. The `Resolver` finds what a path in a body or a signature refers
+//! to, from the locals, the type parameters, the items and imports of the
+//! enclosing module or script, and the builtins.
+pub mod db;
+
+pub mod ability;
+pub mod arena;
+pub mod ast_id_map;
+pub mod body;
+pub mod builtin;
+pub mod diagnostics;
+pub mod expr;
+pub mod item_tree;
+pub mod name;
+pub mod nameres;
+pub mod path;
+pub mod resolver;
+pub mod source_analyzer;
+pub mod type_ref;
+
+mod src;
+
+#[cfg(test)]
+mod test_db;
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use base_db::{impl_intern_key, salsa, FileId};
+use syntax::AstNode;
+
+use crate::{
+    ast_id_map::FileAstId,
+    db::DefDatabase,
+    item_tree::{Const, Function, ItemTreeId, ModItem, Module, Script, Struct, TypeParam},
+};
+
+pub use crate::src::HasSource;
+
+/// A value together with the file it comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InFile<T> {
+    pub file_id: FileId,
+    pub value: T,
+}
+
+impl<T> InFile<T> {
+    pub fn new(file_id: FileId, value: T) -> InFile<T> {
+        InFile { file_id, value }
+    }
+
+    pub fn with_value<U>(&self, value: U) -> InFile<U> {
+        InFile::new(self.file_id, value)
+    }
+
+    pub fn map<F: FnOnce(T) -> U, U>(self, f: F) -> InFile<U> {
+        InFile::new(self.file_id, f(self.value))
+    }
+
+    pub fn as_ref(&self) -> InFile<&T> {
+        self.with_value(&self.value)
+    }
+}
+
+/// `AstId` points to an AST node in any file.
+pub type AstId<N> = InFile<FileAstId<N>>;
+
+impl<N: AstNode> AstId<N> {
+    pub fn to_node(&self, db: &dyn DefDatabase) -> N {
+        let root = db.parse(self.file_id).syntax_node();
+        db.ast_id_map(self.file_id).get(self.value).to_node(&root)
+    }
+}
+
+pub trait Intern {
+    type ID;
+    fn intern(self, db: &dyn DefDatabase) -> Self::ID;
+}
+
+pub trait Lookup {
+    type Data;
+    fn lookup(&self, db: &dyn DefDatabase) -> Self::Data;
+}
+
+macro_rules! impl_intern {
+    ($id:ident, $loc:ident, $intern:ident, $lookup:ident) => {
+        impl_intern_key!($id);
+
+        impl Intern for $loc {
+            type ID = $id;
+            fn intern(self, db: &dyn db::DefDatabase) -> $id {
+                db.$intern(self)
+            }
+        }
+
+        impl Lookup for $id {
+            type Data = $loc;
+            fn lookup(&self, db: &dyn db::DefDatabase) -> $loc {
+                db.$lookup(*self)
+            }
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModuleId(salsa::InternId);
+pub type ModuleLoc = ItemTreeId<Module>;
+impl_intern!(ModuleId, ModuleLoc, intern_module, lookup_intern_module);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScriptId(salsa::InternId);
+pub type ScriptLoc = ItemTreeId<Script>;
+impl_intern!(ScriptId, ScriptLoc, intern_script, lookup_intern_script);
+
+/// A module or a script: the places where items are defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContainerId {
+    Module(ModuleId),
+    Script(ScriptId),
+}
+
+impl ContainerId {
+    pub fn file_id(self, db: &dyn DefDatabase) -> FileId {
+        match self {
+            ContainerId::Module(it) => it.lookup(db).file_id,
+            ContainerId::Script(it) => it.lookup(db).file_id,
+        }
+    }
+
+    /// The functions of the module or script, in declaration order.
+    pub fn functions(self, db: &dyn DefDatabase) -> Vec<FunctionId> {
+        let (file_id, items) = match self {
+            ContainerId::Module(it) => {
+                let loc = it.lookup(db);
+                (loc.file_id, loc.item_tree(db)[loc.value].items.clone())
+            }
+            ContainerId::Script(it) => {
+                let loc = it.lookup(db);
+                (loc.file_id, loc.item_tree(db)[loc.value].items.clone())
+            }
+        };
+        items
+            .iter()
+            .filter_map(|item| match *item {
+                ModItem::Function(idx) => {
+                    let loc = FunctionLoc { container: self, id: ItemTreeId::new(file_id, idx) };
+                    Some(loc.intern(db))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl From<ModuleId> for ContainerId {
+    fn from(id: ModuleId) -> ContainerId {
+        ContainerId::Module(id)
+    }
+}
+
+impl From<ScriptId> for ContainerId {
+    fn from(id: ScriptId) -> ContainerId {
+        ContainerId::Script(id)
+    }
+}
+
+/// The location of an item of a module or a script.
+pub struct ItemLoc<N> {
+    pub container: ContainerId,
+    pub id: ItemTreeId<N>,
+}
+
+impl<N> Clone for ItemLoc<N> {
+    fn clone(&self) -> ItemLoc<N> {
+        *self
+    }
+}
+impl<N> Copy for ItemLoc<N> {}
+
+impl<N> PartialEq for ItemLoc<N> {
+    fn eq(&self, other: &ItemLoc<N>) -> bool {
+        self.container == other.container && self.id == other.id
+    }
+}
+impl<N> Eq for ItemLoc<N> {}
+
+impl<N> Hash for ItemLoc<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.container.hash(state);
+        self.id.hash(state);
+    }
+}
+
+impl<N> fmt::Debug for ItemLoc<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ItemLoc").field("container", &self.container).field("id", &self.id).finish()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FunctionId(salsa::InternId);
+pub type FunctionLoc = ItemLoc<Function>;
+impl_intern!(FunctionId, FunctionLoc, intern_function, lookup_intern_function);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StructId(salsa::InternId);
+pub type StructLoc = ItemLoc<Struct>;
+impl_intern!(StructId, StructLoc, intern_struct, lookup_intern_struct);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ConstId(salsa::InternId);
+pub type ConstLoc = ItemLoc<Const>;
+impl_intern!(ConstId, ConstLoc, intern_const, lookup_intern_const);
+
+/// Items which have a name in the scope of a module or a script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModuleDefId {
+    FunctionId(FunctionId),
+    StructId(StructId),
+    ConstId(ConstId),
+}
+
+impl From<FunctionId> for ModuleDefId {
+    fn from(id: FunctionId) -> ModuleDefId {
+        ModuleDefId::FunctionId(id)
+    }
+}
+
+impl From<StructId> for ModuleDefId {
+    fn from(id: StructId) -> ModuleDefId {
+        ModuleDefId::StructId(id)
+    }
+}
+
+impl From<ConstId> for ModuleDefId {
+    fn from(id: ConstId) -> ModuleDefId {
+        ModuleDefId::ConstId(id)
+    }
+}
+
+/// Items which can have type parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GenericDefId {
+    FunctionId(FunctionId),
+    StructId(StructId),
+}
+
+impl GenericDefId {
+    pub fn type_params(self, db: &dyn DefDatabase) -> Box<[TypeParam]> {
+        match self {
+            GenericDefId::FunctionId(it) => {
+                let loc = it.lookup(db);
+                loc.id.item_tree(db)[loc.id.value].type_params.clone()
+            }
+            GenericDefId::StructId(it) => {
+                let loc = it.lookup(db);
+                loc.id.item_tree(db)[loc.id.value].type_params.clone()
+            }
+        }
+    }
+}
+
+impl From<FunctionId> for GenericDefId {
+    fn from(id: FunctionId) -> GenericDefId {
+        GenericDefId::FunctionId(id)
+    }
+}
+
+impl From<StructId> for GenericDefId {
+    fn from(id: StructId) -> GenericDefId {
+        GenericDefId::StructId(id)
+    }
+}
+
+/// The `idx`th type parameter of a function or a struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeParamId {
+    pub parent: GenericDefId,
+    pub idx: u32,
+}
@@ -0,0 +1,73 @@
+//! Names of items, fields, locals and modules, detached from the syntax tree.
+use std::fmt;
+
+use syntax::{ast, SmolStr};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Name(SmolStr);
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Name {
+    pub fn new(text: &str) -> Name {
+        Name(SmolStr::new(text))
+    }
+
+    /// A fake name for things which are missing in the source code, like the
+    /// name of `fun (x: u8) {}`.
+    pub fn missing() -> Name {
+        Name(SmolStr::new_inline("[missing name]"))
+    }
+
+    pub fn is_missing(&self) -> bool {
+        self.0 == "[missing name]"
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+pub trait AsName {
+    fn as_name(&self) -> Name;
+}
+
+impl AsName for ast::Name {
+    fn as_name(&self) -> Name {
+        match self.ident_token() {
+            Some(ident) => Name::new(ident.text()),
+            None => Name::missing(),
+        }
+    }
+}
+
+impl AsName for ast::NameRef {
+    fn as_name(&self) -> Name {
+        match self.ident_token().or_else(|| self.address_token()) {
+            Some(token) => Name::new(token.text()),
+            None => Name::missing(),
+        }
+    }
+}
+
+impl AsName for ast::NamedAddress {
+    fn as_name(&self) -> Name {
+        match self.ident_token() {
+            Some(ident) => Name::new(ident.text()),
+            None => Name::missing(),
+        }
+    }
+}
+
+/// Names which have a meaning of their own in paths.
+pub mod known {
+    use super::Name;
+
+    pub fn self_() -> Name {
+        Name::new("Self")
+    }
+}
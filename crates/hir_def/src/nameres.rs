@@ -0,0 +1,400 @@
+//! The `DefMap` collects every module and script of the workspace, from the
+//! item trees of the Move files of all source roots.
+//!
+//! Move has no module tree: a module is known by its address and its name,
+//! wherever it is defined, so there is a single `DefMap` for the whole
+//! workspace. It maps `(address, module name)` to the module, and has the
+//! items of each module and script by name. A named address with a value in
+//! the `[addresses]` table of `Dove.toml` is the same address as its value, so
+//! `Std::M` and `0x1::M` are the same module when `Std = "0x1"`.
+//!
+//! Once all modules are known, the `use` declarations of every module and
+//! script are resolved into its scope. Imports which don't resolve are kept,
+//! so that they can be reported.
+//!
+//! The `DefMap` is computed from the item trees only, so edits which keep the
+//! item trees the same, like edits of function bodies, don't invalidate it.
+#[cfg(test)]
+mod tests;
+
+use std::{ops::Index, sync::Arc};
+
+use base_db::{FileId, SourceRootId};
+use rustc_hash::{FxHashMap, FxHashSet};
+use stdx::format_to;
+use syntax::ast;
+
+use crate::{
+    ast_id_map::FileAstId,
+    db::DefDatabase,
+    item_tree::{ItemTree, ItemTreeId, ModItem},
+    name::Name,
+    path::{Address, Path, PathKind},
+    ConstLoc, ContainerId, FunctionLoc, InFile, Intern, Lookup, ModuleDefId, ModuleId, ScriptId,
+    StructLoc,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DefMap {
+    modules: Vec<ModuleData>,
+    scripts: Vec<ScriptData>,
+    by_address: FxHashMap<(Address, Name), ModuleId>,
+    named_addresses: FxHashMap<Name, Address>,
+    module_index: FxHashMap<ModuleId, usize>,
+    unresolved_imports: Vec<UnresolvedImport>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ModuleData {
+    pub id: ModuleId,
+    pub file_id: FileId,
+    /// `None` if the module is written without an address.
+    pub address: Option<Address>,
+    pub name: Name,
+    pub scope: ItemScope,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScriptData {
+    pub id: ScriptId,
+    pub file_id: FileId,
+    pub scope: ItemScope,
+}
+
+/// An import which doesn't resolve: the `index`th use tree of a `use`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedImport {
+    pub container: ContainerId,
+    pub use_decl: InFile<FileAstId<ast::UseDecl>>,
+    pub index: u32,
+}
+
+/// The names visible in a module or a script: the items defined in it, and
+/// the modules and items imported by its `use`s.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ItemScope {
+    items: FxHashMap<Name, ModuleDefId>,
+    imported_modules: FxHashMap<Name, ModuleId>,
+    imported_items: FxHashMap<Name, ModuleDefId>,
+}
+
+impl ItemScope {
+    /// Finds an item defined in the scope.
+    pub fn get(&self, name: &Name) -> Option<ModuleDefId> {
+        self.items.get(name).copied()
+    }
+
+    /// The items defined in the scope, in an unspecified order.
+    pub fn items(&self) -> impl Iterator<Item = (&Name, ModuleDefId)> + '_ {
+        self.items.iter().map(|(name, def)| (name, *def))
+    }
+
+    /// Finds a module imported as `name`, like `V` in `use 0x1::Vector as V`.
+    pub fn imported_module(&self, name: &Name) -> Option<ModuleId> {
+        self.imported_modules.get(name).copied()
+    }
+
+    /// Finds an item imported as `name`, like `empty` in
+    /// `use 0x1::Vector::empty`.
+    pub fn imported_item(&self, name: &Name) -> Option<ModuleDefId> {
+        self.imported_items.get(name).copied()
+    }
+
+    /// The imported modules, in an unspecified order.
+    pub fn imported_modules(&self) -> impl Iterator<Item = (&Name, ModuleId)> + '_ {
+        self.imported_modules.iter().map(|(name, module)| (name, *module))
+    }
+
+    /// The imported items, in an unspecified order.
+    pub fn imported_items(&self) -> impl Iterator<Item = (&Name, ModuleDefId)> + '_ {
+        self.imported_items.iter().map(|(name, def)| (name, *def))
+    }
+
+    fn insert(&mut self, name: Name, def: ModuleDefId) {
+        if name.is_missing() {
+            return;
+        }
+        // The first definition wins, the duplicates are errors.
+        self.items.entry(name).or_insert(def);
+    }
+}
+
+impl DefMap {
+    pub(crate) fn def_map_query(db: &dyn DefDatabase) -> Arc<DefMap> {
+        let mut def_map = DefMap {
+            modules: Vec::new(),
+            scripts: Vec::new(),
+            by_address: FxHashMap::default(),
+            named_addresses: named_addresses(db),
+            module_index: FxHashMap::default(),
+            unresolved_imports: Vec::new(),
+        };
+        // Workspace modules come first, so that they win over library modules
+        // with the same address and name.
+        let local_roots = sorted_roots(&db.local_roots());
+        let library_roots = sorted_roots(&db.library_roots());
+        for root_id in local_roots.into_iter().chain(library_roots) {
+            let source_root = db.source_root(root_id);
+            let mut files: Vec<FileId> = source_root
+                .iter()
+                .filter(|file_id| {
+                    let path = source_root.path_for_file(file_id);
+                    let extension = path.and_then(|it| it.name_and_extension()?.1);
+                    extension == Some("move")
+                })
+                .collect();
+            files.sort();
+            for file_id in files {
+                def_map.collect_file(db, file_id);
+            }
+        }
+        def_map.resolve_imports(db);
+        Arc::new(def_map)
+    }
+
+    /// Finds the module `address::name`.
+    pub fn module(&self, address: &Address, name: &Name) -> Option<ModuleId> {
+        self.by_address.get(&(self.canonical_address(address), name.clone())).copied()
+    }
+
+    /// The value of a named address, if the workspace gives it one, or the
+    /// address itself.
+    pub fn canonical_address(&self, address: &Address) -> Address {
+        match address {
+            Address::Named(name) => match self.named_addresses.get(name) {
+                Some(value) => value.clone(),
+                None => address.clone(),
+            },
+            Address::Value(_) => address.clone(),
+        }
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = &ModuleData> + '_ {
+        self.modules.iter()
+    }
+
+    pub fn scripts(&self) -> impl Iterator<Item = &ScriptData> + '_ {
+        self.scripts.iter()
+    }
+
+    pub fn script(&self, id: ScriptId) -> Option<&ScriptData> {
+        self.scripts.iter().find(|it| it.id == id)
+    }
+
+    /// The scope of a module or a script.
+    pub fn scope(&self, container: ContainerId) -> Option<&ItemScope> {
+        match container {
+            ContainerId::Module(id) => {
+                self.module_index.get(&id).map(|&idx| &self.modules[idx].scope)
+            }
+            ContainerId::Script(id) => self.script(id).map(|it| &it.scope),
+        }
+    }
+
+    pub fn unresolved_imports(&self) -> &[UnresolvedImport] {
+        &self.unresolved_imports
+    }
+
+    /// Resolves the path of an import, which names either a module, like
+    /// `0x1::M` and `Std::M`, or an item of a module, like `0x1::M::f`.
+    pub fn resolve_import_path(&self, path: &Path) -> Option<ImportResolution> {
+        let (address, names) = match (&path.kind, &*path.segments) {
+            (PathKind::Address(address), names) => (address.clone(), names),
+            (PathKind::Plain, [address, names @ ..]) => (Address::Named(address.clone()), names),
+            (PathKind::Plain, []) => return None,
+        };
+        match names {
+            [module] => self.module(&address, module).map(ImportResolution::Module),
+            [module, item] => {
+                let module = self.module(&address, module)?;
+                self[module].scope.get(item).map(ImportResolution::Item)
+            }
+            _ => None,
+        }
+    }
+
+    /// Renders the modules and their items, for tests.
+    pub fn dump(&self) -> String {
+        fn kind(def: ModuleDefId) -> &'static str {
+            match def {
+                ModuleDefId::FunctionId(_) => "fun",
+                ModuleDefId::StructId(_) => "struct",
+                ModuleDefId::ConstId(_) => "const",
+            }
+        }
+
+        fn dump_scope(buf: &mut String, scope: &ItemScope) {
+            let mut items: Vec<_> = scope
+                .items()
+                .map(|(name, def)| format!("{}: {}", name, kind(def)))
+                .chain(
+                    scope
+                        .imported_items()
+                        .map(|(name, def)| format!("{}: use {}", name, kind(def))),
+                )
+                .chain(scope.imported_modules().map(|(name, _)| format!("{}: use module", name)))
+                .collect();
+            items.sort();
+            for item in items {
+                format_to!(buf, "  {}\n", item);
+            }
+        }
+
+        let mut buf = String::new();
+        for module in self.modules() {
+            let address = match &module.address {
+                Some(it) => it.to_string(),
+                None => "{unknown}".to_string(),
+            };
+            format_to!(buf, "module {}::{} (file {})\n", address, module.name, module.file_id.0);
+            dump_scope(&mut buf, &module.scope);
+        }
+        for script in self.scripts() {
+            format_to!(buf, "script (file {})\n", script.file_id.0);
+            dump_scope(&mut buf, &script.scope);
+        }
+        buf
+    }
+
+    fn collect_file(&mut self, db: &dyn DefDatabase, file_id: FileId) {
+        let item_tree = db.file_item_tree(file_id);
+        for idx in item_tree.modules() {
+            let module = &item_tree[idx];
+            let id = ItemTreeId::new(file_id, idx).intern(db);
+            let scope = collect_scope(db, &item_tree, file_id, id.into(), &module.items);
+            if let Some(address) = &module.address {
+                if !module.name.is_missing() {
+                    let address = self.canonical_address(address);
+                    self.by_address.entry((address, module.name.clone())).or_insert(id);
+                }
+            }
+            self.module_index.insert(id, self.modules.len());
+            self.modules.push(ModuleData {
+                id,
+                file_id,
+                address: module.address.clone(),
+                name: module.name.clone(),
+                scope,
+            });
+        }
+        for idx in item_tree.scripts() {
+            let id = ItemTreeId::new(file_id, idx).intern(db);
+            let scope = collect_scope(db, &item_tree, file_id, id.into(), &item_tree[idx].items);
+            self.scripts.push(ScriptData { id, file_id, scope });
+        }
+    }
+}
+
+impl DefMap {
+    fn resolve_imports(&mut self, db: &dyn DefDatabase) {
+        let containers: Vec<ContainerId> = self
+            .modules
+            .iter()
+            .map(|it| ContainerId::from(it.id))
+            .chain(self.scripts.iter().map(|it| ContainerId::from(it.id)))
+            .collect();
+        for container in containers {
+            let (file_id, items) = match container {
+                ContainerId::Module(id) => {
+                    let loc = id.lookup(db);
+                    (loc.file_id, loc.item_tree(db)[loc.value].items.clone())
+                }
+                ContainerId::Script(id) => {
+                    let loc = id.lookup(db);
+                    (loc.file_id, loc.item_tree(db)[loc.value].items.clone())
+                }
+            };
+            let item_tree = db.file_item_tree(file_id);
+            let mut imported_modules = FxHashMap::default();
+            let mut imported_items = FxHashMap::default();
+            for item in items.iter() {
+                let use_idx = match item {
+                    ModItem::Use(it) => *it,
+                    _ => continue,
+                };
+                let use_decl = &item_tree[use_idx];
+                for import in use_decl.imports.iter() {
+                    match self.resolve_import_path(&import.path) {
+                        Some(ImportResolution::Module(module)) => {
+                            imported_modules.entry(import.name().clone()).or_insert(module);
+                        }
+                        Some(ImportResolution::Item(def)) => {
+                            imported_items.entry(import.name().clone()).or_insert(def);
+                        }
+                        None => self.unresolved_imports.push(UnresolvedImport {
+                            container,
+                            use_decl: InFile::new(file_id, use_decl.ast_id),
+                            index: import.index,
+                        }),
+                    }
+                }
+            }
+            let scope = match container {
+                ContainerId::Module(id) => &mut self.modules[self.module_index[&id]].scope,
+                ContainerId::Script(id) => match self.scripts.iter_mut().find(|it| it.id == id) {
+                    Some(it) => &mut it.scope,
+                    None => continue,
+                },
+            };
+            scope.imported_modules = imported_modules;
+            scope.imported_items = imported_items;
+        }
+    }
+}
+
+/// What the path of an import refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportResolution {
+    Module(ModuleId),
+    Item(ModuleDefId),
+}
+
+impl Index<ModuleId> for DefMap {
+    type Output = ModuleData;
+    fn index(&self, id: ModuleId) -> &ModuleData {
+        &self.modules[self.module_index[&id]]
+    }
+}
+
+fn collect_scope(
+    db: &dyn DefDatabase,
+    item_tree: &ItemTree,
+    file_id: FileId,
+    container: ContainerId,
+    items: &[ModItem],
+) -> ItemScope {
+    let mut scope = ItemScope::default();
+    for item in items {
+        match *item {
+            ModItem::Function(idx) => {
+                let id = FunctionLoc { container, id: ItemTreeId::new(file_id, idx) }.intern(db);
+                scope.insert(item_tree[idx].name.clone(), id.into());
+            }
+            ModItem::Struct(idx) => {
+                let id = StructLoc { container, id: ItemTreeId::new(file_id, idx) }.intern(db);
+                scope.insert(item_tree[idx].name.clone(), id.into());
+            }
+            ModItem::Const(idx) => {
+                let id = ConstLoc { container, id: ItemTreeId::new(file_id, idx) }.intern(db);
+                scope.insert(item_tree[idx].name.clone(), id.into());
+            }
+            ModItem::Use(_) | ModItem::Friend(_) | ModItem::Spec(_) | ModItem::Schema(_) => {}
+        }
+    }
+    scope
+}
+
+fn sorted_roots(roots: &FxHashSet<SourceRootId>) -> Vec<SourceRootId> {
+    let mut roots: Vec<SourceRootId> = roots.iter().copied().collect();
+    roots.sort();
+    roots
+}
+
+/// The named addresses of the workspace whose values are valid address
+/// literals.
+fn named_addresses(db: &dyn DefDatabase) -> FxHashMap<Name, Address> {
+    db.named_addresses()
+        .iter()
+        .filter_map(|(name, value)| Some((Name::new(name), Address::from_literal(value)?)))
+        .collect()
+}
@@ -0,0 +1,182 @@
+mod incremental;
+
+use base_db::fixture::WithFixture;
+use expect_test::{expect, Expect};
+
+use crate::{db::DefDatabase, name::Name, path::Address, test_db::TestDB};
+
+fn check(ra_fixture: &str, expect: Expect) {
+    let db = TestDB::with_files(ra_fixture);
+    let def_map = db.def_map();
+    expect.assert_eq(&def_map.dump());
+}
+
+#[test]
+fn modules_across_files() {
+    check(
+        r#"
+//- /sources/Coin.move
+address 0x1 {
+module Coin {
+    struct Coin {}
+    const ERR: u64 = 1;
+    fun value() {}
+    use 0x1::Signer;
+}
+module Signer {}
+}
+//- /sources/Wallet.move
+module 0x2::Wallet {
+    fun f() {}
+}
+//- /scripts/main.move
+script {
+    fun main() {}
+}
+"#,
+        expect![[r#"
+            module 0x1::Coin (file 0)
+              Coin: struct
+              ERR: const
+              Signer: use module
+              value: fun
+            module 0x1::Signer (file 0)
+            module 0x2::Wallet (file 1)
+              f: fun
+            script (file 2)
+              main: fun
+        "#]],
+    );
+}
+
+#[test]
+fn library_roots() {
+    check(
+        r#"
+//- /sources/M.move
+module 0x1::M {}
+//- /stdlib/Vector.move new_source_root:library
+module 0x1::Vector {
+    native public fun empty<T>(): vector<T>;
+}
+//- /stdlib/Signer.move
+module 0x1::Signer {}
+"#,
+        expect![[r#"
+            module 0x1::M (file 0)
+            module 0x1::Vector (file 1)
+              empty: fun
+            module 0x1::Signer (file 2)
+        "#]],
+    );
+}
+
+#[test]
+fn skips_files_which_are_not_move() {
+    check(
+        r#"
+//- /sources/M.move
+module 0x1::M {}
+//- /Dove.toml
+module 0x1::N {}
+"#,
+        expect![[r#"
+            module 0x1::M (file 0)
+        "#]],
+    );
+}
+
+#[test]
+fn lookup_by_address_and_name() {
+    let db = TestDB::with_files(
+        r#"
+//- /sources/A.move
+module 0x0001::M {}
+module Std::M {}
+//- /sources/B.move
+module 0x1::M {}
+"#,
+    );
+    let def_map = db.def_map();
+    let name = Name::new("M");
+
+    let module = def_map.module(&Address::from_literal("0x1").unwrap(), &name).unwrap();
+    assert_eq!(def_map[module].file_id.0, 0, "the first definition of a module wins");
+
+    let named = def_map.module(&Address::Named(Name::new("Std")), &name).unwrap();
+    assert_ne!(module, named);
+
+    assert!(def_map.module(&Address::from_literal("0x2").unwrap(), &name).is_none());
+}
+
+#[test]
+fn bech32_and_ss58_addresses() {
+    check(
+        r#"
+//- /sources/A.move
+module 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY::A {
+    fun f() {}
+}
+module wallet1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5j53nz7::B {}
+//- /sources/M.move
+module 0x1::M {
+    use 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY::A;
+    use 0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d::A as HexA;
+    use 0x0102030405060708090a0b0c0d0e0f1011121314::B;
+}
+"#,
+        expect![[r#"
+            module 0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d::A (file 0)
+              f: fun
+            module 0x102030405060708090a0b0c0d0e0f1011121314::B (file 0)
+            module 0x1::M (file 1)
+              A: use module
+              B: use module
+              HexA: use module
+        "#]],
+    );
+}
+
+#[test]
+fn named_addresses_have_their_values() {
+    let db = TestDB::with_files(
+        r#"
+//- /sources/Signer.move addresses:Std=0x1,Other=0x2,Bad=Std
+module Std::Signer {}
+//- /sources/Vector.move
+module 0x1::Vector {}
+//- /sources/M.move
+module Bad::M {}
+"#,
+    );
+    let def_map = db.def_map();
+    let std = Address::Named(Name::new("Std"));
+    let one = Address::from_literal("0x1").unwrap();
+
+    let signer = def_map.module(&one, &Name::new("Signer")).unwrap();
+    assert_eq!(def_map.module(&std, &Name::new("Signer")), Some(signer));
+    let vector = def_map.module(&std, &Name::new("Vector")).unwrap();
+    assert_eq!(def_map.module(&one, &Name::new("Vector")), Some(vector));
+
+    assert!(def_map.module(&Address::Named(Name::new("Other")), &Name::new("Vector")).is_none());
+    // Values which aren't address literals are ignored.
+    assert_eq!(def_map.canonical_address(&Address::Named(Name::new("Bad"))).to_string(), "Bad");
+    assert!(def_map.module(&Address::Named(Name::new("Bad")), &Name::new("M")).is_some());
+}
+
+#[test]
+fn duplicate_items() {
+    check(
+        r#"
+module 0x1::M {
+    fun f() {}
+    const f: u8 = 0;
+    fun () {}
+}
+"#,
+        expect![[r#"
+            module 0x1::M (file 0)
+              f: fun
+        "#]],
+    );
+}
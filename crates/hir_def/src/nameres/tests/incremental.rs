@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use base_db::{fixture::WithFixture, Change, SourceDatabase};
+use syntax::{ast, AstNode, Parse, SourceFile};
+
+use crate::{db::DefDatabase, test_db::TestDB};
+
+fn check_def_map_is_not_recomputed(ra_fixture_initial: &str, ra_fixture_change: &str) {
+    let (mut db, files) = TestDB::with_many_files(ra_fixture_initial);
+    {
+        let events = db.log_executed(|| {
+            db.def_map();
+        });
+        assert!(format!("{:?}", events).contains("def_map"), "{:#?}", events)
+    }
+    db.set_text(files[0], ra_fixture_change);
+
+    {
+        let events = db.log_executed(|| {
+            db.def_map();
+        });
+        assert!(format!("{:?}", events).contains("file_item_tree"), "{:#?}", events);
+        assert!(!format!("{:?}", events).contains("def_map"), "{:#?}", events)
+    }
+}
+
+#[test]
+fn whitespace_edit_does_not_recompute_def_map() {
+    check_def_map_is_not_recomputed(
+        r"
+        //- /sources/M.move
+        module 0x1::M {
+            fun f() {}
+        }
+        //- /sources/N.move
+        module 0x1::N {}
+        ",
+        "
+        module 0x1::M
+        {
+
+            fun   f() {}
+        }
+        ",
+    );
+}
+
+#[test]
+fn body_edit_does_not_recompute_def_map() {
+    check_def_map_is_not_recomputed(
+        r"
+        //- /sources/M.move
+        module 0x1::M {
+            fun f(): u64 { 1 }
+        }
+        ",
+        "
+        module 0x1::M {
+            fun f(): u64 { let x = 92; x + 1 }
+        }
+        ",
+    );
+}
+
+#[test]
+fn new_item_recomputes_def_map() {
+    let (mut db, files) = TestDB::with_many_files(
+        r"
+        //- /sources/M.move
+        module 0x1::M {}
+        ",
+    );
+    db.def_map();
+    db.set_text(files[0], "module 0x1::M { fun f() {} }");
+    let events = db.log_executed(|| {
+        db.def_map();
+    });
+    assert!(format!("{:?}", events).contains("def_map"), "{:#?}", events);
+    let def_map = db.def_map();
+    let module = def_map.modules().next().unwrap();
+    assert!(module.scope.get(&crate::name::Name::new("f")).is_some());
+}
+
+#[test]
+fn edit_reparses_only_the_edited_block() {
+    let (mut db, files) = TestDB::with_many_files(
+        r"
+        //- /sources/M.move
+        module 0x1::M {
+            fun f(): u64 { 1 }
+            fun g() { /* é */ }
+        }
+        ",
+    );
+    let file_id = files[0];
+    let before = db.parse(file_id);
+
+    let text = db.file_text(file_id).replace("{ 1 }", "{ let x = 92; x + 1 }");
+    let mut change = Change::new();
+    change.edit_file(file_id, Arc::new(text.clone()));
+    change.apply(&mut db);
+
+    let after = db.parse(file_id);
+    let full = SourceFile::parse_with_dialect(&text, db.dialect());
+    assert_eq!(after.debug_dump(), full.debug_dump());
+
+    // The function which wasn't edited is the same green node.
+    let g = |parse: &Parse<SourceFile>| {
+        parse.syntax_node().descendants().filter_map(ast::FunctionDef::cast).nth(1).unwrap()
+    };
+    assert!(std::ptr::eq(&*g(&before).syntax().green(), &*g(&after).syntax().green()));
+    assert!(!std::ptr::eq(&*g(&full).syntax().green(), &*g(&after).syntax().green()));
+}
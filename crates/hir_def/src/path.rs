@@ -0,0 +1,171 @@
+//! Paths and addresses, lowered from the syntax tree.
+//!
+//! A path is kept as it is written: whether `Std::M::f` starts with a named
+//! address or with a module alias is only known after name resolution.
+use std::fmt;
+
+use stdx::format_to;
+use syntax::{ast, Dialect, SmolStr};
+
+use crate::name::{AsName, Name};
+use crate::type_ref::TypeRef;
+
+/// An account address, like `0x1` or `Std`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Address {
+    /// A numerical address, as lowercase hex digits without the `0x` prefix
+    /// and leading zeros, so that `0x1` and `0x0001` are the same address.
+    /// Bech32 and ss58 addresses are kept as the hex digits of their account,
+    /// so that every form of an address is the same address.
+    Value(SmolStr),
+    Named(Name),
+}
+
+impl Address {
+    pub fn from_ast(address: &ast::AddressIdent) -> Option<Address> {
+        if let Some(named) = address.named_address() {
+            return Some(Address::from_name(named.as_name()));
+        }
+        let token = address.diem_address_token()?;
+        Address::from_literal(token.text())
+    }
+
+    /// A named address, unless the name is a bech32 address literal, which
+    /// lexes as an identifier.
+    pub fn from_name(name: Name) -> Address {
+        let text = name.to_string();
+        if Dialect::DFinance.is_address_literal_name(&text) {
+            if let Some(address) = Address::from_literal(&text) {
+                return address;
+            }
+        }
+        Address::Named(name)
+    }
+
+    /// Parses a numerical address literal like `0x1`, or a bech32 or ss58
+    /// address.
+    pub fn from_literal(text: &str) -> Option<Address> {
+        let bytes = Dialect::decode_address(text)?;
+        let mut digits = String::new();
+        for byte in bytes {
+            format_to!(digits, "{:02x}", byte);
+        }
+        let digits = digits.trim_start_matches('0');
+        let digits = if digits.is_empty() { "0" } else { digits };
+        Some(Address::Value(SmolStr::new(digits)))
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Address::Value(digits) => write!(f, "0x{}", digits),
+            Address::Named(name) => fmt::Display::fmt(name, f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Path {
+    pub kind: PathKind,
+    pub segments: Box<[Name]>,
+    /// Type arguments of the last segment, like `<u64>` in
+    /// `Vector::empty<u64>`.
+    pub type_args: Option<Box<[TypeRef]>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathKind {
+    /// `f`, `M::f`, `Self::f` or `Std::M::f`.
+    Plain,
+    /// `0x1::M::f`.
+    Address(Address),
+}
+
+impl Path {
+    /// Lowers the path, or returns `None` if it is too broken to be useful.
+    pub fn from_ast(path: ast::Path) -> Option<Path> {
+        let mut segments = Vec::new();
+        let mut kind = PathKind::Plain;
+        let mut type_args = None;
+        let mut current = Some(path);
+        let mut is_last = true;
+        while let Some(path) = current {
+            let segment = path.segment()?;
+            if is_last {
+                type_args = segment
+                    .type_arg_list()
+                    .map(|list| list.types().map(TypeRef::from_ast).collect());
+                is_last = false;
+            }
+            match (segment.name_ref(), segment.address_ident()) {
+                (Some(name_ref), _) => segments.push(name_ref.as_name()),
+                (None, Some(address)) => {
+                    // An address can only start a path.
+                    if path.qualifier().is_some() {
+                        return None;
+                    }
+                    kind = PathKind::Address(Address::from_ast(&address)?);
+                }
+                (None, None) => return None,
+            }
+            current = path.qualifier();
+        }
+        segments.reverse();
+        if segments.is_empty() {
+            return None;
+        }
+        Some(Path { kind, segments: segments.into(), type_args })
+    }
+
+    pub fn from_name(name: Name) -> Path {
+        Path { kind: PathKind::Plain, segments: Box::new([name]), type_args: None }
+    }
+
+    pub fn as_ident(&self) -> Option<&Name> {
+        match (&self.kind, &*self.segments) {
+            (PathKind::Plain, [name]) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The path without its last segment, like `0x1::M` for `0x1::M::f`, or
+    /// `None` for a single name.
+    pub fn qualifier(&self) -> Option<Path> {
+        match &*self.segments {
+            [] | [_] => None,
+            [qualifier @ .., _] => {
+                Some(Path { kind: self.kind.clone(), segments: qualifier.into(), type_args: None })
+            }
+        }
+    }
+
+    pub fn last_segment(&self) -> &Name {
+        self.segments.last().unwrap()
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let PathKind::Address(address) = &self.kind {
+            write!(f, "{}::", address)?;
+        }
+        for (idx, segment) in self.segments.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("::")?;
+            }
+            write!(f, "{}", segment)?;
+        }
+        if let Some(type_args) = &self.type_args {
+            f.write_str("<")?;
+            for (idx, ty) in type_args.iter().enumerate() {
+                if idx > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{}", ty)?;
+            }
+            f.write_str(">")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+//! Name resolution façade.
+//!
+//! A `Resolver` is a stack of scopes: the module or script, then the type
+//! parameters of the item, then the scopes of the locals of a body. Names are
+//! looked up from the innermost scope outwards, and the builtins are tried
+//! last, so that items can shadow them.
+//!
+//! Types and values live in different namespaces: `S` can be a struct and a
+//! function at the same time, and the position of a path decides which one it
+//! refers to. The leading segments of a path always name a module.
+#[cfg(test)]
+mod tests;
+
+use std::sync::Arc;
+
+use crate::{
+    body::scope::{ExprScopes, ScopeId},
+    builtin::{BuiltinFunction, BuiltinType},
+    db::DefDatabase,
+    expr::{ExprId, PatId},
+    item_tree::TypeParam,
+    name::{known, Name},
+    nameres::{DefMap, ItemScope},
+    path::{Address, Path, PathKind},
+    ConstId, ContainerId, FunctionId, GenericDefId, Lookup, ModuleDefId, ModuleId, ScriptId,
+    StructId, TypeParamId,
+};
+
+#[derive(Debug, Clone)]
+pub struct Resolver {
+    /// The outermost scope comes first.
+    scopes: Vec<Scope>,
+}
+
+#[derive(Debug, Clone)]
+enum Scope {
+    /// The items and imports of a module or a script.
+    Container { container: ContainerId, def_map: Arc<DefMap> },
+    /// The type parameters of a function or a struct.
+    Generics { def: GenericDefId, params: Box<[TypeParam]> },
+    /// The locals visible at some point of a function body.
+    Expr { owner: FunctionId, expr_scopes: Arc<ExprScopes>, scope_id: ScopeId },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypeNs {
+    StructId(StructId),
+    TypeParam(TypeParamId),
+    BuiltinType(BuiltinType),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueNs {
+    Local(PatId),
+    FunctionId(FunctionId),
+    ConstId(ConstId),
+    BuiltinFunction(BuiltinFunction),
+}
+
+impl Resolver {
+    /// Resolves a path which names a module: `0x1::M`, `Std::M`, a module
+    /// imported with `use`, or `Self`.
+    pub fn resolve_module_path(&self, path: &Path) -> Option<ModuleId> {
+        let (container, def_map) = self.container_scope()?;
+        match (&path.kind, &*path.segments) {
+            (PathKind::Address(address), [name]) => def_map.module(address, name),
+            (PathKind::Plain, [name]) if *name == known::self_() => match container {
+                ContainerId::Module(it) => Some(it),
+                ContainerId::Script(_) => None,
+            },
+            (PathKind::Plain, [name]) => def_map.scope(container)?.imported_module(name),
+            (PathKind::Plain, [address, name]) => {
+                def_map.module(&Address::Named(address.clone()), name)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn resolve_path_in_type_ns(&self, path: &Path) -> Option<TypeNs> {
+        let name = match path.as_ident() {
+            Some(name) => name,
+            None => {
+                return match self.resolve_qualified_item(path)? {
+                    ModuleDefId::StructId(it) => Some(TypeNs::StructId(it)),
+                    ModuleDefId::FunctionId(_) | ModuleDefId::ConstId(_) => None,
+                }
+            }
+        };
+        for scope in self.scopes.iter().rev() {
+            match scope {
+                Scope::Generics { def, params } => {
+                    if let Some(idx) = params.iter().position(|it| it.name == *name) {
+                        let id = TypeParamId { parent: *def, idx: idx as u32 };
+                        return Some(TypeNs::TypeParam(id));
+                    }
+                }
+                Scope::Container { container, def_map } => {
+                    let scope = match def_map.scope(*container) {
+                        Some(it) => it,
+                        None => continue,
+                    };
+                    let def = scope.get(name).filter(|it| matches!(it, ModuleDefId::StructId(_)));
+                    let def = def.or_else(|| {
+                        scope
+                            .imported_item(name)
+                            .filter(|it| matches!(it, ModuleDefId::StructId(_)))
+                    });
+                    if let Some(ModuleDefId::StructId(it)) = def {
+                        return Some(TypeNs::StructId(it));
+                    }
+                }
+                Scope::Expr { .. } => {}
+            }
+        }
+        BuiltinType::by_name(name).map(TypeNs::BuiltinType)
+    }
+
+    pub fn resolve_path_in_value_ns(&self, path: &Path) -> Option<ValueNs> {
+        let name = match path.as_ident() {
+            Some(name) => name,
+            None => return value_ns(self.resolve_qualified_item(path)?),
+        };
+        for scope in self.scopes.iter().rev() {
+            match scope {
+                Scope::Expr { expr_scopes, scope_id, .. } => {
+                    if let Some(entry) = expr_scopes.resolve_name_in_scope(*scope_id, name) {
+                        return Some(ValueNs::Local(entry.pat()));
+                    }
+                }
+                Scope::Container { container, def_map } => {
+                    let scope = match def_map.scope(*container) {
+                        Some(it) => it,
+                        None => continue,
+                    };
+                    let def = scope.get(name).and_then(value_ns);
+                    if let Some(def) = def.or_else(|| scope.imported_item(name).and_then(value_ns))
+                    {
+                        return Some(def);
+                    }
+                }
+                Scope::Generics { .. } => {}
+            }
+        }
+        BuiltinFunction::by_name(name).map(ValueNs::BuiltinFunction)
+    }
+
+    /// Finds the local `name`, as in `move name` or `copy name`.
+    pub fn resolve_local(&self, name: &Name) -> Option<PatId> {
+        self.scopes.iter().rev().find_map(|scope| match scope {
+            Scope::Expr { expr_scopes, scope_id, .. } => {
+                expr_scopes.resolve_name_in_scope(*scope_id, name).map(|it| it.pat())
+            }
+            _ => None,
+        })
+    }
+
+    /// The module or script the resolver is in.
+    pub fn container(&self) -> Option<ContainerId> {
+        self.container_scope().map(|(container, _)| container)
+    }
+
+    /// The function whose body the resolver is in.
+    pub fn body_owner(&self) -> Option<FunctionId> {
+        self.scopes.iter().rev().find_map(|scope| match scope {
+            Scope::Expr { owner, .. } => Some(*owner),
+            _ => None,
+        })
+    }
+
+    /// The scope of the module or script the resolver is in.
+    pub fn item_scope(&self) -> Option<&ItemScope> {
+        let (container, def_map) = self.container_scope()?;
+        def_map.scope(container)
+    }
+
+    /// Resolves `M::f`, `0x1::M::f` or `Std::M::f` to the item `f` of the
+    /// module. Only the items defined in the module are visible this way, not
+    /// its imports.
+    fn resolve_qualified_item(&self, path: &Path) -> Option<ModuleDefId> {
+        let (_, def_map) = self.container_scope()?;
+        let module = self.resolve_module_path(&path.qualifier()?)?;
+        def_map[module].scope.get(path.last_segment())
+    }
+
+    fn container_scope(&self) -> Option<(ContainerId, &DefMap)> {
+        self.scopes.iter().find_map(|scope| match scope {
+            Scope::Container { container, def_map } => Some((*container, &**def_map)),
+            _ => None,
+        })
+    }
+
+    fn push_scope(mut self, scope: Scope) -> Resolver {
+        self.scopes.push(scope);
+        self
+    }
+
+    fn push_generics(self, db: &dyn DefDatabase, def: GenericDefId) -> Resolver {
+        let params = def.type_params(db);
+        self.push_scope(Scope::Generics { def, params })
+    }
+
+    fn push_expr_scope(
+        self,
+        owner: FunctionId,
+        expr_scopes: Arc<ExprScopes>,
+        scope_id: ScopeId,
+    ) -> Resolver {
+        self.push_scope(Scope::Expr { owner, expr_scopes, scope_id })
+    }
+}
+
+fn value_ns(def: ModuleDefId) -> Option<ValueNs> {
+    match def {
+        ModuleDefId::FunctionId(it) => Some(ValueNs::FunctionId(it)),
+        ModuleDefId::ConstId(it) => Some(ValueNs::ConstId(it)),
+        ModuleDefId::StructId(_) => None,
+    }
+}
+
+/// The resolver at `expr` in the body of `owner`, which sees the locals in
+/// scope there.
+pub fn resolver_for_expr(db: &dyn DefDatabase, owner: FunctionId, expr: ExprId) -> Resolver {
+    let scopes = db.expr_scopes(owner);
+    let scope = scopes.scope_for(expr);
+    resolver_for_scope(db, owner, scope)
+}
+
+pub fn resolver_for_scope(
+    db: &dyn DefDatabase,
+    owner: FunctionId,
+    scope_id: Option<ScopeId>,
+) -> Resolver {
+    let r = owner.resolver(db);
+    match scope_id {
+        // The scope knows its parents, so it is enough to push the innermost
+        // one.
+        Some(scope_id) => r.push_expr_scope(owner, db.expr_scopes(owner), scope_id),
+        None => r,
+    }
+}
+
+pub trait HasResolver: Copy {
+    /// Builds a resolver for the names visible in the signature of the item,
+    /// or in the item itself for modules and scripts.
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver;
+}
+
+impl HasResolver for ContainerId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        Resolver { scopes: vec![Scope::Container { container: self, def_map: db.def_map() }] }
+    }
+}
+
+impl HasResolver for ModuleId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        ContainerId::from(self).resolver(db)
+    }
+}
+
+impl HasResolver for ScriptId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        ContainerId::from(self).resolver(db)
+    }
+}
+
+impl HasResolver for FunctionId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        self.lookup(db).container.resolver(db).push_generics(db, self.into())
+    }
+}
+
+impl HasResolver for StructId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        self.lookup(db).container.resolver(db).push_generics(db, self.into())
+    }
+}
+
+impl HasResolver for ConstId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        self.lookup(db).container.resolver(db)
+    }
+}
+
+impl HasResolver for GenericDefId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        match self {
+            GenericDefId::FunctionId(it) => it.resolver(db),
+            GenericDefId::StructId(it) => it.resolver(db),
+        }
+    }
+}
@@ -0,0 +1,541 @@
+use base_db::{fixture::WithFixture, SourceDatabase};
+use expect_test::{expect, Expect};
+use syntax::ast::{self, AstNode};
+
+use crate::{
+    body::PatPtr,
+    db::DefDatabase,
+    expr::Pat,
+    source_analyzer::{PathResolution, SourceAnalyzer},
+    test_db::TestDB,
+    ContainerId, InFile, Lookup,
+};
+
+/// Resolves the path, or the name of `move x` and `copy x`, which ends at the
+/// cursor.
+fn check(ra_fixture: &str, expect: Expect) {
+    let (db, position) = TestDB::with_position(ra_fixture);
+    let file = db.parse(position.file_id).tree();
+    let token = file.syntax().token_at_offset(position.offset).left_biased().unwrap();
+    let node = token.parent().unwrap();
+    let sa = SourceAnalyzer::new_for_node(&db, InFile::new(position.file_id, &node)).unwrap();
+    let res = match node.ancestors().find_map(ast::Path::cast) {
+        Some(path) => sa.resolve_path(&db, &path),
+        None => sa.resolve_local(&ast::NameRef::cast(node).unwrap()),
+    };
+    expect.assert_eq(&describe(&db, res));
+}
+
+fn describe(db: &TestDB, res: Option<PathResolution>) -> String {
+    let res = match res {
+        Some(it) => it,
+        None => return "unresolved".to_string(),
+    };
+    match res {
+        PathResolution::Module(id) => {
+            format!("module {}", container_name(db, id.into()))
+        }
+        PathResolution::Function(id) => {
+            let loc = id.lookup(db);
+            let name = &loc.id.item_tree(db)[loc.id.value].name;
+            format!("fun {} in {}", name, container_name(db, loc.container))
+        }
+        PathResolution::Struct(id) => {
+            let loc = id.lookup(db);
+            let name = &loc.id.item_tree(db)[loc.id.value].name;
+            format!("struct {} in {}", name, container_name(db, loc.container))
+        }
+        PathResolution::Const(id) => {
+            let loc = id.lookup(db);
+            let name = &loc.id.item_tree(db)[loc.id.value].name;
+            format!("const {} in {}", name, container_name(db, loc.container))
+        }
+        PathResolution::Local(owner, pat) => {
+            let (body, source_map) = db.body_with_source_map(owner);
+            let name = match &body[pat] {
+                Pat::Bind { name } => name.clone(),
+                _ => unreachable!(),
+            };
+            let kind = match source_map.pat_syntax(pat) {
+                Some(PatPtr::Param(_)) => "param",
+                _ => "local",
+            };
+            let range = match source_map.pat_syntax(pat) {
+                Some(PatPtr::Param(ptr)) => ptr.text_range(),
+                Some(PatPtr::Pat(ptr)) => ptr.text_range(),
+                None => unreachable!(),
+            };
+            format!("{} {} at {:?}", kind, name, range)
+        }
+        PathResolution::TypeParam(id) => {
+            let name = &id.parent.type_params(db)[id.idx as usize].name;
+            format!("type param {}", name)
+        }
+        PathResolution::BuiltinType(it) => format!("builtin type {}", it),
+        PathResolution::BuiltinFunction(it) => format!("builtin fun {}", it),
+    }
+}
+
+fn container_name(db: &TestDB, container: ContainerId) -> String {
+    match container {
+        ContainerId::Module(id) => {
+            let def_map = db.def_map();
+            let module = &def_map[id];
+            let address = match &module.address {
+                Some(it) => it.to_string(),
+                None => "{unknown}".to_string(),
+            };
+            format!("{}::{}", address, module.name)
+        }
+        ContainerId::Script(_) => "script".to_string(),
+    }
+}
+
+const VECTOR: &str = r#"
+//- /sources/Vector.move
+module 0x1::Vector {
+    native public fun empty<E>(): vector<E>;
+    native public fun push_back<E>(v: &mut vector<E>, e: E);
+}
+"#;
+
+fn check_with_vector(ra_fixture: &str, expect: Expect) {
+    check(&format!("{}{}", VECTOR, ra_fixture), expect);
+}
+
+#[test]
+fn use_with_alias() {
+    check_with_vector(
+        r#"
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector as V;
+    fun f() { V::empty$0<u64>(); }
+}
+"#,
+        expect![["fun empty in 0x1::Vector"]],
+    );
+    check_with_vector(
+        r#"
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector as V;
+    fun f() { V$0::empty<u64>(); }
+}
+"#,
+        expect![["module 0x1::Vector"]],
+    );
+}
+
+#[test]
+fn use_of_items_and_self() {
+    check_with_vector(
+        r#"
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector::{Self, empty as new};
+    fun f() { new$0<u64>(); }
+}
+"#,
+        expect![["fun empty in 0x1::Vector"]],
+    );
+    check_with_vector(
+        r#"
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector::{Self, empty as new};
+    fun f() { Vector$0::push_back(); }
+}
+"#,
+        expect![["module 0x1::Vector"]],
+    );
+    check_with_vector(
+        r#"
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector::{Self, empty$0 as new};
+}
+"#,
+        expect![["fun empty in 0x1::Vector"]],
+    );
+    check_with_vector(
+        r#"
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector::{Self$0, empty as new};
+}
+"#,
+        expect![["module 0x1::Vector"]],
+    );
+}
+
+#[test]
+fn self_path() {
+    check(
+        r#"
+module 0x1::M {
+    struct S {}
+    fun f() {}
+    fun g() { Self::f$0(); }
+}
+"#,
+        expect![["fun f in 0x1::M"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    struct S {}
+    fun g(s: Self::S$0) {}
+}
+"#,
+        expect![["struct S in 0x1::M"]],
+    );
+}
+
+#[test]
+fn fully_qualified_and_named_address_paths() {
+    check_with_vector(
+        r#"
+//- /sources/M.move
+module 0x1::M {
+    fun f() { 0x1::Vector::empty$0<u64>(); }
+}
+"#,
+        expect![["fun empty in 0x1::Vector"]],
+    );
+    check_with_vector(
+        r#"
+//- /sources/M.move
+module 0x1::M {
+    fun f() { 0x01::Vector$0::empty<u64>(); }
+}
+"#,
+        expect![["module 0x1::Vector"]],
+    );
+    check(
+        r#"
+//- /sources/Signer.move
+module Std::Signer {
+    native public fun address_of(s: &signer): address;
+}
+//- /sources/M.move
+module 0x1::M {
+    fun f(s: &signer) { Std::Signer::address_of$0(s); }
+}
+"#,
+        expect![["fun address_of in Std::Signer"]],
+    );
+    // Without a value for `Std`, `Std` and `0x1` are different addresses.
+    check(
+        r#"
+//- /sources/Signer.move
+module 0x1::Signer {
+    native public fun address_of(s: &signer): address;
+}
+//- /sources/M.move
+module 0x1::M {
+    fun f(s: &signer) { Std::Signer::address_of$0(s); }
+}
+"#,
+        expect![["unresolved"]],
+    );
+    check(
+        r#"
+//- /sources/Signer.move addresses:Std=0x1
+module 0x1::Signer {
+    native public fun address_of(s: &signer): address;
+}
+//- /sources/M.move
+module 0x1::M {
+    use Std::Signer;
+    fun f(s: &signer) { Signer::address_of$0(s); }
+}
+"#,
+        expect![["fun address_of in 0x1::Signer"]],
+    );
+    check(
+        r#"
+//- /sources/Signer.move addresses:Std=0x1
+module Std::Signer {
+    native public fun address_of(s: &signer): address;
+}
+//- /sources/M.move
+module 0x1::M {
+    fun f(s: &signer) { 0x1::Signer::address_of$0(s); }
+}
+"#,
+        expect![["fun address_of in Std::Signer"]],
+    );
+}
+
+#[test]
+fn qualified_paths_see_only_items_of_the_module() {
+    check_with_vector(
+        r#"
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector::empty;
+}
+//- /sources/N.move
+module 0x1::N {
+    fun f() { 0x1::M::empty$0(); }
+}
+"#,
+        expect![["unresolved"]],
+    );
+}
+
+#[test]
+fn namespaces() {
+    check(
+        r#"
+module 0x1::M {
+    struct S { f: u64 }
+    fun g() { S$0 { f: 1 }; }
+}
+"#,
+        expect![["struct S in 0x1::M"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    struct R has key { f: u64 }
+    fun g(): u64 acquires R$0 { 0 }
+}
+"#,
+        expect![["struct R in 0x1::M"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    struct R has key { f: u64 }
+    fun g(r: R) { let R$0 { f } = r; }
+}
+"#,
+        expect![["struct R in 0x1::M"]],
+    );
+}
+
+#[test]
+fn builtins() {
+    check(
+        r#"
+module 0x1::M {
+    struct R has key {}
+    fun f(): bool { borrow_global$0<R>(@0x1); }
+}
+"#,
+        expect![["builtin fun borrow_global"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    struct R has key {}
+    fun f(s: &signer) { move_to$0(s, R {}); }
+}
+"#,
+        expect![["builtin fun move_to"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    struct R has key {}
+    fun f(): bool { exists$0<R>(@0x1) }
+}
+"#,
+        expect![["builtin fun exists"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    fun f(x: vector$0<u8>) {}
+}
+"#,
+        expect![["builtin type vector"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    fun f(x: vector<u8$0>) {}
+}
+"#,
+        expect![["builtin type u8"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    fun f(): bool { let x = (1 as u128$0); true }
+}
+"#,
+        expect![["builtin type u128"]],
+    );
+}
+
+#[test]
+fn items_shadow_builtins() {
+    check(
+        r#"
+module 0x1::M {
+    fun exists() {}
+    fun f() { exists$0(); }
+}
+"#,
+        expect![["fun exists in 0x1::M"]],
+    );
+}
+
+#[test]
+fn type_params() {
+    check(
+        r#"
+module 0x1::M {
+    fun f<T: drop>(x: T$0) {}
+}
+"#,
+        expect![["type param T"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    struct Box<T> { value: T$0 }
+}
+"#,
+        expect![["type param T"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    fun f<T>() { let x: vector<T$0>; }
+}
+"#,
+        expect![["type param T"]],
+    );
+}
+
+#[test]
+fn locals() {
+    check(
+        r#"
+module 0x1::M {
+    fun f(x: u64) { x$0; }
+}
+"#,
+        expect![["param x at 26..32"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    fun f(x: u64) { let x = x$0; }
+}
+"#,
+        expect![["param x at 26..32"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    fun f(x: u64) { let x = 1; x$0; }
+}
+"#,
+        expect![["local x at 40..41"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    fun f() { { let x = 1; }; x$0; }
+}
+"#,
+        expect![["unresolved"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    struct S { f: u64 }
+    fun f(s: S) { let S { f } = s; move f$0; }
+}
+"#,
+        expect![["local f at 66..67"]],
+    );
+    check(
+        r#"
+module 0x1::M {
+    fun f() { let (a, b) = (1, 2); if (true) { copy b$0; } }
+}
+"#,
+        expect![["local b at 38..39"]],
+    );
+}
+
+#[test]
+fn locals_shadow_items() {
+    check(
+        r#"
+module 0x1::M {
+    const X: u64 = 1;
+    fun f() { let X = 2; X$0; }
+}
+"#,
+        expect![["local X at 56..57"]],
+    );
+}
+
+#[test]
+fn script_imports() {
+    check(
+        r#"
+//- /sources/Coin.move
+module 0x1::Coin {
+    public fun mint() {}
+}
+//- /scripts/main.move
+script {
+    use 0x1::Coin;
+    fun main() { Coin::mint$0(); }
+}
+"#,
+        expect![["fun mint in 0x1::Coin"]],
+    );
+    check(
+        r#"
+//- /sources/Coin.move
+module 0x1::Coin {
+    public fun mint() {}
+}
+//- /scripts/main.move
+script {
+    use 0x1::Coin::mint;
+    const AMOUNT: u64 = 1;
+    fun main() { mint$0(); AMOUNT; }
+}
+"#,
+        expect![["fun mint in 0x1::Coin"]],
+    );
+    check(
+        r#"
+script {
+    const AMOUNT: u64 = 1;
+    fun main() { AMOUNT$0; }
+}
+"#,
+        expect![["const AMOUNT in script"]],
+    );
+}
+
+#[test]
+fn unresolved() {
+    check(
+        r#"
+module 0x1::M {
+    fun f() { Foo$0::f(); }
+}
+"#,
+        expect![["unresolved"]],
+    );
+    check(
+        r#"
+script {
+    fun main() { Self$0::main(); }
+}
+"#,
+        expect![["unresolved"]],
+    );
+}
@@ -0,0 +1,256 @@
+//! Resolution of syntax nodes, for the IDE.
+//!
+//! `SourceAnalyzer` finds the module or script and the item around a syntax
+//! node, and builds the resolver which sees the names in scope at the node. A
+//! path is then resolved in the namespace implied by where it is written: the
+//! qualifier of a path names a module, a path in a type or in a struct
+//! literal names a type, and a path in an expression names a value.
+use std::sync::Arc;
+
+use base_db::FileId;
+use syntax::{
+    ast::{self, AstNode},
+    syntax_node::SyntaxNode,
+    SyntaxKind,
+};
+
+use crate::{
+    body::BodySourceMap,
+    builtin::{BuiltinFunction, BuiltinType},
+    db::DefDatabase,
+    expr::PatId,
+    item_tree::{ItemTreeId, ModItem},
+    name::{known, AsName},
+    nameres::ImportResolution,
+    path::{Path, PathKind},
+    resolver::{resolver_for_expr, HasResolver, Resolver, TypeNs, ValueNs},
+    ConstId, ConstLoc, ContainerId, FunctionId, FunctionLoc, InFile, Intern, Lookup, ModuleDefId,
+    ModuleId, StructId, StructLoc, TypeParamId,
+};
+
+/// What a path or a name refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathResolution {
+    Module(ModuleId),
+    Function(FunctionId),
+    Struct(StructId),
+    Const(ConstId),
+    /// A parameter or a `let` binding of a function.
+    Local(FunctionId, PatId),
+    TypeParam(TypeParamId),
+    BuiltinType(BuiltinType),
+    BuiltinFunction(BuiltinFunction),
+}
+
+impl From<ModuleDefId> for PathResolution {
+    fn from(def: ModuleDefId) -> PathResolution {
+        match def {
+            ModuleDefId::FunctionId(it) => PathResolution::Function(it),
+            ModuleDefId::StructId(it) => PathResolution::Struct(it),
+            ModuleDefId::ConstId(it) => PathResolution::Const(it),
+        }
+    }
+}
+
+/// The namespace a path is resolved in, decided by its position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathNs {
+    /// The qualifier of another path, or the path of a `friend`.
+    Module,
+    Type,
+    Value,
+    /// The path of a `use`, which names either a module or an item.
+    Import,
+}
+
+impl PathNs {
+    pub fn of(path: &ast::Path) -> Option<PathNs> {
+        let parent = path.syntax().parent()?;
+        let ns = match parent.kind() {
+            SyntaxKind::PATH | SyntaxKind::FRIEND_DECL => PathNs::Module,
+            SyntaxKind::PATH_TYPE
+            | SyntaxKind::RECORD_EXPR
+            | SyntaxKind::RECORD_PAT
+            | SyntaxKind::ACQUIRES_LIST => PathNs::Type,
+            SyntaxKind::PATH_EXPR | SyntaxKind::PATH_PAT => PathNs::Value,
+            SyntaxKind::USE_TREE => PathNs::Import,
+            _ => return None,
+        };
+        Some(ns)
+    }
+}
+
+pub struct SourceAnalyzer {
+    pub file_id: FileId,
+    resolver: Resolver,
+    body: Option<(FunctionId, Arc<BodySourceMap>)>,
+}
+
+impl SourceAnalyzer {
+    /// Builds the analyzer for the position of `node`. Returns `None` if the
+    /// node isn't inside a module or a script.
+    pub fn new_for_node(db: &dyn DefDatabase, node: InFile<&SyntaxNode>) -> Option<SourceAnalyzer> {
+        let file_id = node.file_id;
+        let container = container_of(db, file_id, node.value)?;
+        let item = node.value.ancestors().find_map(|it| {
+            if let Some(fun) = ast::FunctionDef::cast(it.clone()) {
+                return Some(ast::Item::FunctionDef(fun));
+            }
+            if let Some(strukt) = ast::Struct::cast(it.clone()) {
+                return Some(ast::Item::Struct(strukt));
+            }
+            ast::ConstDef::cast(it).map(ast::Item::ConstDef)
+        });
+        let def = item.and_then(|item| item_of(db, file_id, container, &item));
+        let (resolver, body) = match def {
+            Some(ModuleDefId::FunctionId(fun)) => {
+                let (_, source_map) = db.body_with_source_map(fun);
+                let expr = node
+                    .value
+                    .ancestors()
+                    .filter_map(ast::Expr::cast)
+                    .find_map(|it| source_map.node_expr(&it));
+                let resolver = match expr {
+                    Some(expr) => resolver_for_expr(db, fun, expr),
+                    None => fun.resolver(db),
+                };
+                (resolver, Some((fun, source_map)))
+            }
+            Some(ModuleDefId::StructId(it)) => (it.resolver(db), None),
+            Some(ModuleDefId::ConstId(it)) => (it.resolver(db), None),
+            None => (container.resolver(db), None),
+        };
+        Some(SourceAnalyzer { file_id, resolver, body })
+    }
+
+    pub fn resolver(&self) -> &Resolver {
+        &self.resolver
+    }
+
+    /// The function whose body the node is in.
+    pub fn body_owner(&self) -> Option<FunctionId> {
+        self.body.as_ref().map(|(owner, _)| *owner)
+    }
+
+    pub fn body_source_map(&self) -> Option<&BodySourceMap> {
+        self.body.as_ref().map(|(_, source_map)| &**source_map)
+    }
+
+    pub fn resolve_path(&self, db: &dyn DefDatabase, path: &ast::Path) -> Option<PathResolution> {
+        let ns = PathNs::of(path)?;
+        if ns == PathNs::Import {
+            return self.resolve_import(db, path);
+        }
+        let hir_path = Path::from_ast(path.clone())?;
+        match ns {
+            PathNs::Module => {
+                self.resolver.resolve_module_path(&hir_path).map(PathResolution::Module)
+            }
+            PathNs::Type => {
+                let res = match self.resolver.resolve_path_in_type_ns(&hir_path)? {
+                    TypeNs::StructId(it) => PathResolution::Struct(it),
+                    TypeNs::TypeParam(it) => PathResolution::TypeParam(it),
+                    TypeNs::BuiltinType(it) => PathResolution::BuiltinType(it),
+                };
+                Some(res)
+            }
+            PathNs::Value => {
+                let res = match self.resolver.resolve_path_in_value_ns(&hir_path)? {
+                    ValueNs::Local(pat) => PathResolution::Local(self.body.as_ref()?.0, pat),
+                    ValueNs::FunctionId(it) => PathResolution::Function(it),
+                    ValueNs::ConstId(it) => PathResolution::Const(it),
+                    ValueNs::BuiltinFunction(it) => PathResolution::BuiltinFunction(it),
+                };
+                Some(res)
+            }
+            PathNs::Import => None,
+        }
+    }
+
+    /// Resolves the name of `move x` or `copy x` to the local `x`.
+    pub fn resolve_local(&self, name_ref: &ast::NameRef) -> Option<PathResolution> {
+        let (owner, _) = self.body.as_ref()?;
+        let pat = self.resolver.resolve_local(&name_ref.as_name())?;
+        Some(PathResolution::Local(*owner, pat))
+    }
+
+    /// Resolves the path of a `use` tree, together with the paths of the
+    /// enclosing trees: `f` in `use 0x1::M::{f}` is `0x1::M::f`.
+    fn resolve_import(&self, db: &dyn DefDatabase, path: &ast::Path) -> Option<PathResolution> {
+        let tree = ast::UseTree::cast(path.syntax().parent()?)?;
+        let mut full_path = Path::from_ast(path.clone())?;
+        for outer in tree.syntax().ancestors().skip(1).filter_map(ast::UseTree::cast) {
+            let prefix = match outer.path().and_then(Path::from_ast) {
+                Some(it) => it,
+                None => continue,
+            };
+            let is_self =
+                full_path.kind == PathKind::Plain && full_path.as_ident() == Some(&known::self_());
+            let mut segments = prefix.segments.to_vec();
+            if !is_self {
+                segments.extend(full_path.segments.iter().cloned());
+            }
+            full_path = Path { kind: prefix.kind, segments: segments.into(), type_args: None };
+        }
+        let res = match db.def_map().resolve_import_path(&full_path)? {
+            ImportResolution::Module(it) => PathResolution::Module(it),
+            ImportResolution::Item(it) => it.into(),
+        };
+        Some(res)
+    }
+}
+
+/// Finds the module or script which contains `node`.
+fn container_of(db: &dyn DefDatabase, file_id: FileId, node: &SyntaxNode) -> Option<ContainerId> {
+    let ast_id_map = db.ast_id_map(file_id);
+    let item_tree = db.file_item_tree(file_id);
+    for ancestor in node.ancestors() {
+        if let Some(module_def) = ast::ModuleDef::cast(ancestor.clone()) {
+            let ast_id = ast_id_map.ast_id(&module_def);
+            let idx = item_tree.modules().find(|&idx| item_tree[idx].ast_id == ast_id)?;
+            return Some(ItemTreeId::new(file_id, idx).intern(db).into());
+        }
+        if let Some(script_def) = ast::ScriptDef::cast(ancestor) {
+            let ast_id = ast_id_map.ast_id(&script_def);
+            let idx = item_tree.scripts().find(|&idx| item_tree[idx].ast_id == ast_id)?;
+            return Some(ItemTreeId::new(file_id, idx).intern(db).into());
+        }
+    }
+    None
+}
+
+/// Finds the function, struct or constant of the container for `item`.
+fn item_of(
+    db: &dyn DefDatabase,
+    file_id: FileId,
+    container: ContainerId,
+    item: &ast::Item,
+) -> Option<ModuleDefId> {
+    let ast_id_map = db.ast_id_map(file_id);
+    let item_tree = db.file_item_tree(file_id);
+    let items = match container {
+        ContainerId::Module(it) => item_tree[it.lookup(db).value].items.clone(),
+        ContainerId::Script(it) => item_tree[it.lookup(db).value].items.clone(),
+    };
+    items.iter().find_map(|mod_item| {
+        let def: ModuleDefId = match (*mod_item, item) {
+            (ModItem::Function(idx), ast::Item::FunctionDef(it))
+                if item_tree[idx].ast_id == ast_id_map.ast_id(it) =>
+            {
+                FunctionLoc { container, id: ItemTreeId::new(file_id, idx) }.intern(db).into()
+            }
+            (ModItem::Struct(idx), ast::Item::Struct(it))
+                if item_tree[idx].ast_id == ast_id_map.ast_id(it) =>
+            {
+                StructLoc { container, id: ItemTreeId::new(file_id, idx) }.intern(db).into()
+            }
+            (ModItem::Const(idx), ast::Item::ConstDef(it))
+                if item_tree[idx].ast_id == ast_id_map.ast_id(it) =>
+            {
+                ConstLoc { container, id: ItemTreeId::new(file_id, idx) }.intern(db).into()
+            }
+            _ => return None,
+        };
+        Some(def)
+    })
+}
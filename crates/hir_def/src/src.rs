@@ -0,0 +1,37 @@
+//! Utilities for mapping between hir IDs and the surface syntax.
+use syntax::ast;
+
+use crate::{
+    db::DefDatabase, item_tree::ItemTreeId, ConstId, FunctionId, InFile, Lookup, ModuleId,
+    ScriptId, StructId,
+};
+
+pub trait HasSource {
+    type Value;
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Value>;
+}
+
+macro_rules! impl_has_source {
+    ($($id:ident => $ast:ty, |$loc:ident| $tree_id:expr;)+) => {
+        $(
+            impl HasSource for $id {
+                type Value = $ast;
+                fn source(&self, db: &dyn DefDatabase) -> InFile<$ast> {
+                    let $loc = self.lookup(db);
+                    let tree_id: ItemTreeId<_> = $tree_id;
+                    let ast_id = tree_id.item_tree(db)[tree_id.value].ast_id;
+                    let node = InFile::new(tree_id.file_id, ast_id).to_node(db);
+                    InFile::new(tree_id.file_id, node)
+                }
+            }
+        )+
+    };
+}
+
+impl_has_source! {
+    ModuleId => ast::ModuleDef, |loc| loc;
+    ScriptId => ast::ScriptDef, |loc| loc;
+    FunctionId => ast::FunctionDef, |loc| loc.id;
+    StructId => ast::Struct, |loc| loc.id;
+    ConstId => ast::ConstDef, |loc| loc.id;
+}
@@ -0,0 +1,72 @@
+//! Database used for testing `hir_def`.
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use base_db::{salsa, salsa::Durability, SourceDatabase};
+
+#[salsa::database(
+    base_db::SourceDatabaseStorage,
+    crate::db::InternDatabaseStorage,
+    crate::db::DefDatabaseStorage
+)]
+pub(crate) struct TestDB {
+    storage: salsa::Storage<TestDB>,
+    events: Mutex<Option<Vec<salsa::Event>>>,
+}
+
+impl Default for TestDB {
+    fn default() -> TestDB {
+        let mut db = TestDB { storage: Default::default(), events: Default::default() };
+        db.set_local_roots_with_durability(Default::default(), Durability::HIGH);
+        db.set_library_roots_with_durability(Default::default(), Durability::HIGH);
+        db.set_dialect_with_durability(Default::default(), Durability::HIGH);
+        db.set_named_addresses_with_durability(Default::default(), Durability::HIGH);
+        db
+    }
+}
+
+impl salsa::Database for TestDB {
+    fn salsa_event(&self, event: salsa::Event) {
+        let mut events = self.events.lock().unwrap();
+        if let Some(events) = &mut *events {
+            events.push(event);
+        }
+    }
+}
+
+impl fmt::Debug for TestDB {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TestDB").finish()
+    }
+}
+
+impl TestDB {
+    pub(crate) fn set_text(&mut self, file_id: base_db::FileId, text: &str) {
+        self.set_file_reparse_base(file_id, None);
+        self.set_file_text(file_id, Arc::new(text.to_string()));
+    }
+
+    pub(crate) fn log(&self, f: impl FnOnce()) -> Vec<salsa::Event> {
+        *self.events.lock().unwrap() = Some(Vec::new());
+        f();
+        self.events.lock().unwrap().take().unwrap()
+    }
+
+    /// Names of the queries which were executed, rather than reused, by `f`.
+    pub(crate) fn log_executed(&self, f: impl FnOnce()) -> Vec<String> {
+        let events = self.log(f);
+        events
+            .into_iter()
+            .filter_map(|e| match e.kind {
+                // This pretty horrible, but `Debug` is the only way to inspect
+                // QueryDescriptor at the moment.
+                salsa::EventKind::WillExecute { database_key } => {
+                    Some(format!("{:?}", database_key.debug(self)))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,70 @@
+//! Types as they are written in the source code, before name resolution.
+use std::fmt;
+
+use syntax::ast;
+
+use crate::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TypeRef {
+    /// `u64`, `vector<u8>`, `S<T>` or `0x1::M::S`.
+    Path(Path),
+    /// `&T` or `&mut T`.
+    Ref(Mutability, Box<TypeRef>),
+    /// `(u64, bool)`; the unit type is the empty tuple.
+    Tuple(Box<[TypeRef]>),
+    /// A type which failed to parse.
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mutability {
+    Shared,
+    Mut,
+}
+
+impl TypeRef {
+    pub fn from_ast(node: ast::Type) -> TypeRef {
+        match node {
+            ast::Type::PathType(it) => {
+                it.path().and_then(Path::from_ast).map(TypeRef::Path).unwrap_or(TypeRef::Error)
+            }
+            ast::Type::RefType(it) => {
+                let mutability =
+                    if it.amp_mut_token().is_some() { Mutability::Mut } else { Mutability::Shared };
+                TypeRef::Ref(mutability, Box::new(TypeRef::from_ast_opt(it.ty())))
+            }
+            ast::Type::TupleType(it) => TypeRef::Tuple(it.types().map(TypeRef::from_ast).collect()),
+            ast::Type::ParenType(it) => TypeRef::from_ast_opt(it.ty()),
+        }
+    }
+
+    pub fn from_ast_opt(node: Option<ast::Type>) -> TypeRef {
+        node.map(TypeRef::from_ast).unwrap_or(TypeRef::Error)
+    }
+
+    pub fn unit() -> TypeRef {
+        TypeRef::Tuple(Box::new([]))
+    }
+}
+
+impl fmt::Display for TypeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeRef::Path(path) => write!(f, "{}", path),
+            TypeRef::Ref(Mutability::Shared, inner) => write!(f, "&{}", inner),
+            TypeRef::Ref(Mutability::Mut, inner) => write!(f, "&mut {}", inner),
+            TypeRef::Tuple(types) => {
+                f.write_str("(")?;
+                for (idx, ty) in types.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", ty)?;
+                }
+                f.write_str(")")
+            }
+            TypeRef::Error => f.write_str("{error}"),
+        }
+    }
+}
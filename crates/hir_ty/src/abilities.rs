@@ -0,0 +1,385 @@
+//! Checking of abilities: a value which is dropped must have `drop`, a value
+//! which is copied must have `copy`, and the type arguments of calls and
+//! structs must have the abilities their type parameters require.
+//!
+//! There is no borrow checker to tell where a value is moved, so unused
+//! values are only reported when they certainly are dropped: a local which is
+//! never moved out of, or a value which is discarded by an expression
+//! statement or a `_` pattern.
+use std::sync::Arc;
+
+use hir_def::{
+    ability::{Abilities, Ability},
+    body::Body,
+    builtin::BuiltinFunction,
+    expr::{BinaryOp, Expr, ExprId, Pat, PatId, Statement, UnaryOp},
+    resolver::{resolver_for_expr, ValueNs},
+    FunctionId, GenericDefId, Lookup, StructId, TypeParamId,
+};
+use rustc_hash::FxHashSet;
+
+use crate::{db::HirDatabase, Callee, ExprOrPatId, InferenceResult, Ty};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbilityDiagnostic {
+    /// A value of a type without `drop` which is discarded, overwritten, or
+    /// kept in a local which is never moved out of.
+    CannotDrop { node: ExprOrPatId, ty: Ty },
+    /// `copy x`, `*r` or `s.f` for a value of a type without `copy`.
+    CannotCopy { expr: ExprId, ty: Ty },
+    /// The `idx`th type argument of a call, or of a struct literal or
+    /// pattern, doesn't have an ability its type parameter requires.
+    UnsatisfiedAbility { node: ExprOrPatId, idx: usize, ty: Ty, ability: Ability },
+}
+
+/// The declaration which has to get an ability for a type to have it: the
+/// struct, or the type parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbilityOwner {
+    Struct(StructId),
+    TypeParam(TypeParamId),
+}
+
+impl Ty {
+    /// The abilities of the type. Types which aren't known have all of them,
+    /// so that they aren't reported.
+    pub fn abilities(&self, db: &dyn HirDatabase) -> Abilities {
+        let primitive: Abilities =
+            [Ability::Copy, Ability::Drop, Ability::Store].iter().copied().collect();
+        match self {
+            Ty::Unknown | Ty::Never | Ty::Infer(_) => Abilities::ALL,
+            Ty::Bool | Ty::Integer(_) | Ty::Address => primitive,
+            Ty::Signer => [Ability::Drop].iter().copied().collect(),
+            Ty::Vector(elem) => elem.abilities(db).intersection(primitive),
+            Ty::Ref(..) => [Ability::Copy, Ability::Drop].iter().copied().collect(),
+            Ty::Tuple(tys) => tys
+                .iter()
+                .fold([Ability::Copy, Ability::Drop].iter().copied().collect(), |acc, ty| {
+                    acc.intersection(ty.abilities(db))
+                }),
+            Ty::TypeParam(id) => type_param_bounds(db, *id),
+            Ty::Struct(id, substs) => {
+                let (abilities, params) = struct_abilities(db, *id);
+                abilities
+                    .iter()
+                    .filter(|ability| {
+                        let required = required_by_field(*ability);
+                        params.iter().zip(substs.iter()).all(|(is_phantom, ty)| {
+                            *is_phantom || ty.abilities(db).contains(required)
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// The declaration which lacks an ability for the type not to have
+    /// `ability`, and the ability it lacks, if one can be added to it. For
+    /// `Pool<T>` without `key`, this is either `key` for `Pool`, or `store`
+    /// for `T` if `Pool` has `key` already.
+    pub fn ability_owner(
+        &self,
+        db: &dyn HirDatabase,
+        ability: Ability,
+    ) -> Option<(AbilityOwner, Ability)> {
+        match self {
+            Ty::TypeParam(id) => Some((AbilityOwner::TypeParam(*id), ability)),
+            Ty::Vector(elem) if ability != Ability::Key => elem.ability_owner(db, ability),
+            Ty::Tuple(tys) => tys
+                .iter()
+                .find(|ty| !ty.abilities(db).contains(ability))?
+                .ability_owner(db, ability),
+            Ty::Struct(id, substs) => {
+                let (abilities, params) = struct_abilities(db, *id);
+                if !abilities.contains(ability) {
+                    return Some((AbilityOwner::Struct(*id), ability));
+                }
+                let required = required_by_field(ability);
+                let (_, ty) = params.iter().zip(substs.iter()).find(|(is_phantom, ty)| {
+                    !**is_phantom && !ty.abilities(db).contains(required)
+                })?;
+                ty.ability_owner(db, required)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The ability the non-phantom type arguments of a struct need for it to
+/// have `ability`.
+fn required_by_field(ability: Ability) -> Ability {
+    match ability {
+        Ability::Key => Ability::Store,
+        it => it,
+    }
+}
+
+/// The declared abilities of a struct, and whether each of its type
+/// parameters is a phantom one.
+fn struct_abilities(db: &dyn HirDatabase, id: StructId) -> (Abilities, Vec<bool>) {
+    let loc = id.lookup(db.upcast());
+    let item_tree = loc.id.item_tree(db.upcast());
+    let strukt = &item_tree[loc.id.value];
+    (strukt.abilities, strukt.type_params.iter().map(|it| it.is_phantom).collect())
+}
+
+fn type_param_bounds(db: &dyn HirDatabase, id: TypeParamId) -> Abilities {
+    let params = id.parent.type_params(db.upcast());
+    params.get(id.idx as usize).map_or(Abilities::ALL, |it| it.bounds)
+}
+
+/// Checks the abilities of the values of a function body.
+pub fn check_abilities(db: &dyn HirDatabase, owner: FunctionId) -> Vec<AbilityDiagnostic> {
+    let body = db.body(owner);
+    // The parameters of native functions are never moved.
+    if matches!(body[body.body_expr], Expr::Missing) {
+        return Vec::new();
+    }
+    let mut checker = AbilityChecker {
+        db,
+        owner,
+        infer: db.infer(owner),
+        body: Arc::clone(&body),
+        moved: FxHashSet::default(),
+        diagnostics: Vec::new(),
+    };
+    for &param in body.params.iter() {
+        checker.check_pat(param);
+    }
+    checker.check_expr(body.body_expr, Usage::Value);
+    checker.check_unmoved_locals();
+    checker.diagnostics
+}
+
+/// How the value of an expression is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Usage {
+    /// Moved or copied somewhere else.
+    Value,
+    /// Dropped right away, by an expression statement.
+    Discard,
+    /// Borrowed, by `&e` or `e.f`.
+    Borrow,
+    /// Overwritten, by `e = value`.
+    Assign,
+}
+
+struct AbilityChecker<'a> {
+    db: &'a dyn HirDatabase,
+    owner: FunctionId,
+    body: Arc<Body>,
+    infer: Arc<InferenceResult>,
+    /// The locals which are moved out of somewhere.
+    moved: FxHashSet<PatId>,
+    diagnostics: Vec<AbilityDiagnostic>,
+}
+
+impl AbilityChecker<'_> {
+    fn check_expr(&mut self, expr: ExprId, usage: Usage) {
+        let body = Arc::clone(&self.body);
+        // Blocks and `if`s pass their value on, so the check is left to the
+        // expressions which produce it.
+        let mut passes_value_on = false;
+        match &body[expr] {
+            Expr::Path(path) => {
+                let resolver = resolver_for_expr(self.db.upcast(), self.owner, expr);
+                if let Some(ValueNs::Local(pat)) = resolver.resolve_path_in_value_ns(path) {
+                    if matches!(usage, Usage::Value | Usage::Discard) {
+                        self.moved.insert(pat);
+                    }
+                }
+            }
+            Expr::Move { name } => {
+                let resolver = resolver_for_expr(self.db.upcast(), self.owner, expr);
+                if let Some(pat) = resolver.resolve_local(name) {
+                    self.moved.insert(pat);
+                }
+            }
+            Expr::Copy { .. } => self.require_copy(expr),
+            Expr::Field { expr: receiver, .. } => {
+                self.check_expr(*receiver, Usage::Borrow);
+                self.check_place(expr, usage);
+            }
+            Expr::UnaryOp { expr: inner, op: UnaryOp::Deref } => {
+                self.check_expr(*inner, Usage::Value);
+                self.check_place(expr, usage);
+            }
+            Expr::Ref { expr: inner, .. } => self.check_expr(*inner, Usage::Borrow),
+            Expr::BinaryOp { lhs, rhs, op: Some(BinaryOp::Assignment) } => {
+                self.check_expr(*lhs, Usage::Assign);
+                self.check_expr(*rhs, Usage::Value);
+            }
+            Expr::Block { statements, tail } => {
+                for stmt in statements.iter() {
+                    match stmt {
+                        Statement::Let { pat, initializer, .. } => {
+                            if let Some(initializer) = initializer {
+                                self.check_expr(*initializer, Usage::Value);
+                            }
+                            self.check_pat(*pat);
+                        }
+                        Statement::Expr(expr) => self.check_expr(*expr, Usage::Discard),
+                    }
+                }
+                if let Some(tail) = tail {
+                    self.check_expr(*tail, usage.passed_on());
+                }
+                passes_value_on = true;
+            }
+            Expr::If { condition, then_branch, else_branch } => {
+                self.check_expr(*condition, Usage::Value);
+                self.check_expr(*then_branch, usage.passed_on());
+                if let Some(else_branch) = else_branch {
+                    self.check_expr(*else_branch, usage.passed_on());
+                }
+                passes_value_on = true;
+            }
+            Expr::While { condition, body } => {
+                self.check_expr(*condition, Usage::Value);
+                self.check_expr(*body, Usage::Discard);
+            }
+            Expr::Loop { body } => self.check_expr(*body, Usage::Discard),
+            Expr::Call { callee, args } => {
+                self.check_expr(*callee, Usage::Borrow);
+                for arg in args.iter() {
+                    self.check_expr(*arg, Usage::Value);
+                }
+                self.check_call(expr);
+            }
+            Expr::Record { fields, .. } => {
+                for field in fields.iter() {
+                    self.check_expr(field.expr, Usage::Value);
+                }
+                self.check_struct_instantiation(expr.into(), &self.infer[expr].clone());
+            }
+            it => it.walk_child_exprs(|child| self.check_expr(child, Usage::Value)),
+        }
+        if usage == Usage::Discard && !passes_value_on && !self.is_reported(expr.into()) {
+            self.require_drop(expr.into(), &self.infer[expr].clone());
+        }
+    }
+
+    /// Checks a field access or a dereference: reading it copies the value,
+    /// and assigning to it drops the old one.
+    fn check_place(&mut self, expr: ExprId, usage: Usage) {
+        match usage {
+            Usage::Value | Usage::Discard => self.require_copy(expr),
+            Usage::Assign => self.require_drop(expr.into(), &self.infer[expr].clone()),
+            Usage::Borrow => {}
+        }
+    }
+
+    fn check_pat(&mut self, pat: PatId) {
+        let body = Arc::clone(&self.body);
+        match &body[pat] {
+            Pat::Wild => self.require_drop(pat.into(), &self.infer[pat].clone()),
+            Pat::Record { .. } | Pat::Path(_) => {
+                let ty = strip_ref(&self.infer[pat]).clone();
+                self.check_struct_instantiation(pat.into(), &ty);
+            }
+            Pat::Missing | Pat::Bind { .. } | Pat::Tuple { .. } => {}
+        }
+        body[pat].walk_child_pats(|child| self.check_pat(child));
+    }
+
+    /// Reports the bindings of types without `drop` which are never moved
+    /// out of, and so are dropped at the end of their scope.
+    fn check_unmoved_locals(&mut self) {
+        let body = Arc::clone(&self.body);
+        for (pat, it) in body.pats.iter() {
+            if matches!(it, Pat::Bind { .. }) && !self.moved.contains(&pat) {
+                self.require_drop(pat.into(), &self.infer[pat].clone());
+            }
+        }
+    }
+
+    fn check_call(&mut self, call: ExprId) {
+        let resolution = match self.infer.call_resolution(call) {
+            Some(it) => it.clone(),
+            None => return,
+        };
+        let bounds: Vec<Abilities> = match resolution.callee {
+            Callee::Function(id) => GenericDefId::from(id)
+                .type_params(self.db.upcast())
+                .iter()
+                .map(|it| it.bounds)
+                .collect(),
+            Callee::Builtin(it) => match it {
+                BuiltinFunction::BorrowGlobal
+                | BuiltinFunction::BorrowGlobalMut
+                | BuiltinFunction::MoveTo
+                | BuiltinFunction::MoveFrom
+                | BuiltinFunction::Exists => vec![[Ability::Key].iter().copied().collect()],
+                BuiltinFunction::Freeze | BuiltinFunction::Assert => Vec::new(),
+            },
+        };
+        self.check_type_args(call.into(), &bounds, &resolution.substs);
+    }
+
+    fn check_struct_instantiation(&mut self, node: ExprOrPatId, ty: &Ty) {
+        if let Ty::Struct(id, substs) = ty {
+            let params = GenericDefId::from(*id).type_params(self.db.upcast());
+            let bounds: Vec<_> = params.iter().map(|it| it.bounds).collect();
+            self.check_type_args(node, &bounds, substs);
+        }
+    }
+
+    fn check_type_args(&mut self, node: ExprOrPatId, bounds: &[Abilities], substs: &[Ty]) {
+        for (idx, (bounds, ty)) in bounds.iter().zip(substs.iter()).enumerate() {
+            let abilities = ty.abilities(self.db);
+            for ability in bounds.iter().filter(|it| !abilities.contains(*it)) {
+                self.diagnostics.push(AbilityDiagnostic::UnsatisfiedAbility {
+                    node,
+                    idx,
+                    ty: ty.clone(),
+                    ability,
+                });
+            }
+        }
+    }
+
+    fn require_copy(&mut self, expr: ExprId) {
+        let ty = &self.infer[expr];
+        if !ty.abilities(self.db).contains(Ability::Copy) {
+            let ty = ty.clone();
+            self.diagnostics.push(AbilityDiagnostic::CannotCopy { expr, ty });
+        }
+    }
+
+    fn require_drop(&mut self, node: ExprOrPatId, ty: &Ty) {
+        if !ty.abilities(self.db).contains(Ability::Drop) {
+            self.diagnostics.push(AbilityDiagnostic::CannotDrop { node, ty: ty.clone() });
+        }
+    }
+
+    /// Whether the last diagnostic is about `node`, which would make another
+    /// one redundant.
+    fn is_reported(&self, node: ExprOrPatId) -> bool {
+        let last = match self.diagnostics.last() {
+            Some(it) => it,
+            None => return false,
+        };
+        match last {
+            AbilityDiagnostic::CannotDrop { node: it, .. }
+            | AbilityDiagnostic::UnsatisfiedAbility { node: it, .. } => *it == node,
+            AbilityDiagnostic::CannotCopy { expr, .. } => ExprOrPatId::from(*expr) == node,
+        }
+    }
+}
+
+impl Usage {
+    /// The usage of the tail of a block or of the branches of an `if`.
+    fn passed_on(self) -> Usage {
+        match self {
+            Usage::Discard => Usage::Discard,
+            Usage::Value | Usage::Borrow | Usage::Assign => Usage::Value,
+        }
+    }
+}
+
+fn strip_ref(ty: &Ty) -> &Ty {
+    match ty {
+        Ty::Ref(_, inner) => inner,
+        ty => ty,
+    }
+}
@@ -0,0 +1,179 @@
+//! The structs acquired by the functions of a module.
+//!
+//! A function acquires a struct of its module when it calls `borrow_global`,
+//! `borrow_global_mut` or `move_from` with it, or when it calls a function of
+//! the same module which acquires it. A native function has no body, so it
+//! acquires the structs of its `acquires` list. Calls can be recursive, so the
+//! structs of all functions of a module are computed together: the direct
+//! ones first, which are then propagated from callees to callers along the
+//! call graph until nothing changes.
+use std::sync::Arc;
+
+use hir_def::{
+    builtin::BuiltinFunction,
+    expr::ExprId,
+    resolver::{HasResolver, TypeNs},
+    ContainerId, FunctionId, Lookup, ModuleId, StructId,
+};
+use rustc_hash::FxHashMap;
+
+use crate::{db::HirDatabase, CallResolution, Callee, Ty};
+
+/// The structs each function of a module acquires, sorted.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ModuleAcquires {
+    acquires: FxHashMap<FunctionId, Vec<StructId>>,
+}
+
+impl ModuleAcquires {
+    pub fn get(&self, function: FunctionId) -> &[StructId] {
+        self.acquires.get(&function).map_or(&[], |it| it.as_slice())
+    }
+}
+
+pub(crate) fn module_acquires_query(db: &dyn HirDatabase, module: ModuleId) -> Arc<ModuleAcquires> {
+    let functions = ContainerId::from(module).functions(db.upcast());
+    let mut acquires: FxHashMap<FunctionId, Vec<StructId>> = FxHashMap::default();
+    let mut callees: FxHashMap<FunctionId, Vec<FunctionId>> = FxHashMap::default();
+    for &function in functions.iter() {
+        let declared = declared_by_native(db, function, module);
+        if !declared.is_empty() {
+            acquires.insert(function, declared);
+        }
+        let infer = db.infer(function);
+        for (_, resolution) in infer.call_resolutions() {
+            match resolution.callee {
+                Callee::Function(callee) if is_in_module(db, callee, module) => {
+                    callees.entry(function).or_default().push(callee);
+                }
+                Callee::Function(_) => {}
+                Callee::Builtin(_) => {
+                    if let Some(strukt) = acquired_by_builtin(db, resolution, module) {
+                        acquires.entry(function).or_default().push(strukt);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &function in functions.iter() {
+            let from_callees: Vec<StructId> = callees
+                .get(&function)
+                .into_iter()
+                .flatten()
+                .flat_map(|callee| acquires.get(callee).cloned().unwrap_or_default())
+                .collect();
+            let own = acquires.entry(function).or_default();
+            for strukt in from_callees {
+                if !own.contains(&strukt) {
+                    own.push(strukt);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    for structs in acquires.values_mut() {
+        structs.sort();
+        structs.dedup();
+    }
+    acquires.retain(|_, structs| !structs.is_empty());
+    Arc::new(ModuleAcquires { acquires })
+}
+
+/// The structs the function acquires. Only the functions of modules can
+/// acquire structs.
+pub fn acquired_structs(db: &dyn HirDatabase, function: FunctionId) -> Vec<StructId> {
+    match function.lookup(db.upcast()).container {
+        ContainerId::Module(module) => db.module_acquires(module).get(function).to_vec(),
+        ContainerId::Script(_) => Vec::new(),
+    }
+}
+
+/// The calls of the body of the function which acquire structs, and the
+/// structs each one acquires, in source order.
+pub fn acquiring_calls(db: &dyn HirDatabase, function: FunctionId) -> Vec<(ExprId, Vec<StructId>)> {
+    let module = match function.lookup(db.upcast()).container {
+        ContainerId::Module(it) => it,
+        ContainerId::Script(_) => return Vec::new(),
+    };
+    let module_acquires = db.module_acquires(module);
+    let mut res: Vec<_> = db
+        .infer(function)
+        .call_resolutions()
+        .filter_map(|(call, resolution)| {
+            let structs = match resolution.callee {
+                Callee::Function(callee) if is_in_module(db, callee, module) => {
+                    module_acquires.get(callee).to_vec()
+                }
+                Callee::Function(_) => Vec::new(),
+                Callee::Builtin(_) => {
+                    acquired_by_builtin(db, resolution, module).into_iter().collect()
+                }
+            };
+            if structs.is_empty() {
+                None
+            } else {
+                Some((call, structs))
+            }
+        })
+        .collect();
+    res.sort_by_key(|(call, _)| *call);
+    res
+}
+
+/// The structs of `module` in the `acquires` list of a native function.
+fn declared_by_native(
+    db: &dyn HirDatabase,
+    function: FunctionId,
+    module: ModuleId,
+) -> Vec<StructId> {
+    let loc = function.lookup(db.upcast());
+    let item_tree = loc.id.item_tree(db.upcast());
+    let data = &item_tree[loc.id.value];
+    if data.has_body {
+        return Vec::new();
+    }
+    let resolver = function.resolver(db.upcast());
+    data.acquires
+        .iter()
+        .filter_map(|path| match resolver.resolve_path_in_type_ns(path)? {
+            TypeNs::StructId(it)
+                if it.lookup(db.upcast()).container == ContainerId::Module(module) =>
+            {
+                Some(it)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The struct of `module` which `borrow_global<T>`, `borrow_global_mut<T>`
+/// or `move_from<T>` acquires.
+fn acquired_by_builtin(
+    db: &dyn HirDatabase,
+    resolution: &CallResolution,
+    module: ModuleId,
+) -> Option<StructId> {
+    match resolution.callee {
+        Callee::Builtin(
+            BuiltinFunction::BorrowGlobal
+            | BuiltinFunction::BorrowGlobalMut
+            | BuiltinFunction::MoveFrom,
+        ) => {}
+        _ => return None,
+    }
+    match resolution.substs.first()? {
+        Ty::Struct(id, _) if id.lookup(db.upcast()).container == ContainerId::Module(module) => {
+            Some(*id)
+        }
+        _ => None,
+    }
+}
+
+fn is_in_module(db: &dyn HirDatabase, function: FunctionId, module: ModuleId) -> bool {
+    function.lookup(db.upcast()).container == ContainerId::Module(module)
+}
@@ -0,0 +1,31 @@
+//! Defines the database queries of type inference.
+use std::sync::Arc;
+
+use base_db::{salsa, Upcast};
+use hir_def::{db::DefDatabase, name::Name, ConstId, FunctionId, ModuleId, StructId};
+
+use crate::{acquires::ModuleAcquires, infer::InferenceResult, FnSig, Ty};
+
+#[salsa::query_group(HirDatabaseStorage)]
+pub trait HirDatabase: DefDatabase + Upcast<dyn DefDatabase> {
+    /// The types of the parameters and of the result of a function, in terms
+    /// of its type parameters.
+    #[salsa::invoke(crate::lower::fn_sig_query)]
+    fn fn_sig(&self, id: FunctionId) -> Arc<FnSig>;
+
+    /// The types of the fields of a struct, in declaration order, in terms of
+    /// its type parameters.
+    #[salsa::invoke(crate::lower::field_types_query)]
+    fn field_types(&self, id: StructId) -> Arc<[(Name, Ty)]>;
+
+    #[salsa::invoke(crate::lower::const_ty_query)]
+    fn const_ty(&self, id: ConstId) -> Ty;
+
+    #[salsa::invoke(crate::infer::infer_query)]
+    fn infer(&self, id: FunctionId) -> Arc<InferenceResult>;
+
+    /// The structs acquired by each function of a module, directly or
+    /// through the functions of the module it calls.
+    #[salsa::invoke(crate::acquires::module_acquires_query)]
+    fn module_acquires(&self, id: ModuleId) -> Arc<ModuleAcquires>;
+}
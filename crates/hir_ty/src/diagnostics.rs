@@ -0,0 +1,383 @@
+//! Diagnostics of type inference, mapped back to the syntax tree.
+//!
+//! Types are rendered when the diagnostics are collected, so that the IDE
+//! layer doesn't need to know about `Ty`.
+use base_db::FileId;
+use hir_def::{
+    ability::Ability,
+    body::{Body, BodySourceMap, PatPtr},
+    expr::{Expr, Pat},
+    item_tree::ItemTreeId,
+    name::Name,
+    path::Path,
+    resolver::{HasResolver, TypeNs},
+    ContainerId, FunctionId, GenericDefId, HasSource, InFile, Intern, Lookup, StructId,
+};
+use syntax::{
+    ast::{self, AstNode, HasName, HasTypeParams},
+    syntax_node::SyntaxNode,
+    AstPtr, SyntaxNodePtr,
+};
+
+use crate::{
+    abilities::check_abilities, acquired_structs, acquiring_calls, db::HirDatabase,
+    AbilityDiagnostic, AbilityOwner, ExprOrPatId, InferenceDiagnostic, Ty,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TyDiagnostic {
+    TypeMismatch {
+        node: InFile<SyntaxNodePtr>,
+        expected: String,
+        actual: String,
+    },
+    MismatchedArgCount {
+        call: InFile<SyntaxNodePtr>,
+        expected: usize,
+        found: usize,
+    },
+    /// The field access, or the field of a struct literal or pattern.
+    NoSuchField {
+        node: InFile<SyntaxNodePtr>,
+        field: String,
+        ty: String,
+    },
+    MissingFields {
+        node: InFile<SyntaxNodePtr>,
+        fields: Vec<String>,
+    },
+    /// The type of a cast, which isn't an integer type.
+    InvalidCast {
+        type_ref: InFile<SyntaxNodePtr>,
+        ty: String,
+    },
+    /// A local, or the function of a call, whose type arguments nothing
+    /// constrains.
+    CannotInferType {
+        node: InFile<SyntaxNodePtr>,
+    },
+    /// A value of a type without `drop` which is dropped.
+    CannotDrop {
+        node: InFile<SyntaxNodePtr>,
+        ty: String,
+        fix: Option<AbilityFix>,
+    },
+    /// A value of a type without `copy` which is copied.
+    CannotCopy {
+        node: InFile<SyntaxNodePtr>,
+        ty: String,
+        fix: Option<AbilityFix>,
+    },
+    /// A type argument without an ability its type parameter requires. The
+    /// node is the type argument, or the path of the generic item when the
+    /// type arguments are inferred.
+    UnsatisfiedAbility {
+        node: InFile<SyntaxNodePtr>,
+        ty: String,
+        ability: Ability,
+        /// The name of the generic item.
+        required_by: String,
+        fix: Option<AbilityFix>,
+    },
+    /// A call which acquires a struct missing from the `acquires` list of
+    /// the function. The node is the function of the call.
+    MissingAcquires {
+        call: InFile<SyntaxNodePtr>,
+        /// The name of the struct.
+        name: String,
+        function: InFile<AstPtr<ast::FunctionDef>>,
+    },
+    /// An entry of an `acquires` list which the function doesn't acquire,
+    /// or which is listed twice.
+    RedundantAcquires {
+        path: InFile<AstPtr<ast::Path>>,
+        name: String,
+        /// Whether the struct is acquired, but listed before.
+        is_duplicate: bool,
+    },
+}
+
+/// The declaration to which a missing ability can be added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbilityFix {
+    /// The `has` list of a struct.
+    Struct { strukt: InFile<AstPtr<ast::Struct>>, name: String, ability: Ability },
+    /// The bounds of a type parameter.
+    TypeParam { type_param: InFile<AstPtr<ast::TypeParam>>, name: String, ability: Ability },
+}
+
+/// Collects the inference and ability diagnostics of all functions of the
+/// file.
+pub fn file_diagnostics(db: &dyn HirDatabase, file_id: FileId) -> Vec<TyDiagnostic> {
+    let root = db.parse(file_id).syntax_node();
+    let mut acc = Vec::new();
+    for fun in file_functions(db, file_id) {
+        let (body, source_map) = db.body_with_source_map(fun);
+        let infer = db.infer(fun);
+        let ctx = Ctx { db, file_id, root: &root, body: &body, source_map: &source_map };
+        acc.extend(infer.diagnostics().iter().filter_map(|it| ctx.diagnostic(it)));
+        acc.extend(check_abilities(db, fun).iter().filter_map(|it| ctx.ability_diagnostic(it)));
+        ctx.acquires_diagnostics(fun, &mut acc);
+    }
+    acc
+}
+
+fn file_functions(db: &dyn HirDatabase, file_id: FileId) -> Vec<FunctionId> {
+    let item_tree = db.file_item_tree(file_id);
+    let modules = item_tree
+        .modules()
+        .map(|idx| -> ContainerId { ItemTreeId::new(file_id, idx).intern(db.upcast()).into() });
+    let scripts = item_tree
+        .scripts()
+        .map(|idx| -> ContainerId { ItemTreeId::new(file_id, idx).intern(db.upcast()).into() });
+    modules.chain(scripts).flat_map(|it| it.functions(db.upcast())).collect()
+}
+
+struct Ctx<'a> {
+    db: &'a dyn HirDatabase,
+    file_id: FileId,
+    root: &'a SyntaxNode,
+    body: &'a Body,
+    source_map: &'a BodySourceMap,
+}
+
+impl Ctx<'_> {
+    fn diagnostic(&self, diagnostic: &InferenceDiagnostic) -> Option<TyDiagnostic> {
+        let res = match diagnostic {
+            InferenceDiagnostic::TypeMismatch { node, expected, actual } => {
+                TyDiagnostic::TypeMismatch {
+                    node: self.node_ptr(*node)?,
+                    expected: self.display(expected),
+                    actual: self.display(actual),
+                }
+            }
+            InferenceDiagnostic::MismatchedArgCount { call, expected, found } => {
+                let call = self.node_ptr((*call).into())?;
+                TyDiagnostic::MismatchedArgCount { call, expected: *expected, found: *found }
+            }
+            InferenceDiagnostic::NoSuchField { node, field, ty } => TyDiagnostic::NoSuchField {
+                node: self.field_ptr(*node, field)?,
+                field: field.to_string(),
+                ty: self.display(ty),
+            },
+            InferenceDiagnostic::MissingFields { node, fields } => TyDiagnostic::MissingFields {
+                node: self.node_ptr(*node)?,
+                fields: fields.iter().map(|it| it.to_string()).collect(),
+            },
+            InferenceDiagnostic::InvalidCast { expr, ty } => {
+                let cast = self.node(self.node_ptr((*expr).into())?);
+                let type_ref = ast::CastExpr::cast(cast)?.ty()?;
+                TyDiagnostic::InvalidCast {
+                    type_ref: InFile::new(self.file_id, SyntaxNodePtr::new(type_ref.syntax())),
+                    ty: self.display(ty),
+                }
+            }
+            InferenceDiagnostic::CannotInferType { node } => {
+                // Point at the function rather than at the whole call.
+                let node = match node {
+                    ExprOrPatId::ExprId(expr) => match &self.body[*expr] {
+                        Expr::Call { callee, .. } => (*callee).into(),
+                        _ => *node,
+                    },
+                    ExprOrPatId::PatId(_) => *node,
+                };
+                TyDiagnostic::CannotInferType { node: self.node_ptr(node)? }
+            }
+        };
+        Some(res)
+    }
+
+    fn ability_diagnostic(&self, diagnostic: &AbilityDiagnostic) -> Option<TyDiagnostic> {
+        let res = match diagnostic {
+            AbilityDiagnostic::CannotDrop { node, ty } => TyDiagnostic::CannotDrop {
+                node: self.binding_ptr(*node)?,
+                ty: self.display(ty),
+                fix: self.ability_fix(ty, Ability::Drop),
+            },
+            AbilityDiagnostic::CannotCopy { expr, ty } => TyDiagnostic::CannotCopy {
+                node: self.node_ptr((*expr).into())?,
+                ty: self.display(ty),
+                fix: self.ability_fix(ty, Ability::Copy),
+            },
+            AbilityDiagnostic::UnsatisfiedAbility { node, idx, ty, ability } => {
+                let node = self.node(self.node_ptr(*node)?);
+                let path = match_ast_path(&node)?;
+                let segment = path.segment()?;
+                let type_arg = segment.type_arg_list().and_then(|it| it.types().nth(*idx));
+                let target = match &type_arg {
+                    Some(it) => it.syntax().clone(),
+                    None => path.syntax().clone(),
+                };
+                TyDiagnostic::UnsatisfiedAbility {
+                    node: InFile::new(self.file_id, SyntaxNodePtr::new(&target)),
+                    ty: self.display(ty),
+                    ability: *ability,
+                    required_by: segment.name_ref()?.syntax().text().to_string(),
+                    fix: self.ability_fix(ty, *ability),
+                }
+            }
+        };
+        Some(res)
+    }
+
+    fn acquires_diagnostics(&self, fun: FunctionId, acc: &mut Vec<TyDiagnostic>) {
+        let db = self.db.upcast();
+        let loc = fun.lookup(db);
+        // Native functions acquire what their `acquires` lists say, so
+        // there is nothing to check.
+        if !loc.id.item_tree(db)[loc.id.value].has_body {
+            return;
+        }
+        let source = fun.source(db);
+        let resolver = fun.resolver(db);
+        let declared: Vec<(ast::Path, StructId)> = source
+            .value
+            .acquires_list()
+            .into_iter()
+            .flat_map(|it| it.paths())
+            .filter_map(|path| {
+                match resolver.resolve_path_in_type_ns(&Path::from_ast(path.clone())?)? {
+                    TypeNs::StructId(it) => Some((path, it)),
+                    TypeNs::TypeParam(_) | TypeNs::BuiltinType(_) => None,
+                }
+            })
+            .collect();
+
+        let function = source.as_ref().map(AstPtr::new);
+        for (call, structs) in acquiring_calls(self.db, fun) {
+            for strukt in structs {
+                if declared.iter().any(|(_, it)| *it == strukt) {
+                    continue;
+                }
+                let callee = match &self.body[call] {
+                    Expr::Call { callee, .. } => *callee,
+                    _ => call,
+                };
+                if let Some(call) = self.node_ptr(callee.into()) {
+                    let name = struct_name(self.db, strukt);
+                    acc.push(TyDiagnostic::MissingAcquires {
+                        call,
+                        name,
+                        function: function.clone(),
+                    });
+                }
+            }
+        }
+
+        let acquired = acquired_structs(self.db, fun);
+        for (idx, (path, strukt)) in declared.iter().enumerate() {
+            let is_duplicate = declared[..idx].iter().any(|(_, it)| it == strukt);
+            if is_duplicate || !acquired.contains(strukt) {
+                acc.push(TyDiagnostic::RedundantAcquires {
+                    path: InFile::new(self.file_id, AstPtr::new(path)),
+                    name: path.syntax().text().to_string(),
+                    is_duplicate,
+                });
+            }
+        }
+    }
+
+    /// Like `node_ptr`, but only the name of a parameter.
+    fn binding_ptr(&self, node: ExprOrPatId) -> Option<InFile<SyntaxNodePtr>> {
+        let ptr = self.node_ptr(node)?;
+        match ast::Param::cast(self.node(ptr.clone())).and_then(|it| it.name()) {
+            Some(name) => Some(InFile::new(self.file_id, SyntaxNodePtr::new(name.syntax()))),
+            None => Some(ptr),
+        }
+    }
+
+    fn ability_fix(&self, ty: &Ty, ability: Ability) -> Option<AbilityFix> {
+        let db = self.db.upcast();
+        let (owner, ability) = ty.ability_owner(self.db, ability)?;
+        let res = match owner {
+            AbilityOwner::Struct(id) => {
+                let source = id.source(db);
+                let name = source.value.name()?.syntax().text().to_string();
+                AbilityFix::Struct { strukt: source.map(|it| AstPtr::new(&it)), name, ability }
+            }
+            AbilityOwner::TypeParam(id) => {
+                let params = match id.parent {
+                    GenericDefId::FunctionId(it) => it.source(db).map(|it| it.generic_param_list()),
+                    GenericDefId::StructId(it) => it.source(db).map(|it| it.generic_param_list()),
+                };
+                let type_param = params.value?.type_params().nth(id.idx as usize)?;
+                let name = type_param.name()?.syntax().text().to_string();
+                let type_param = InFile::new(params.file_id, AstPtr::new(&type_param));
+                AbilityFix::TypeParam { type_param, name, ability }
+            }
+        };
+        Some(res)
+    }
+
+    fn node_ptr(&self, node: ExprOrPatId) -> Option<InFile<SyntaxNodePtr>> {
+        let ptr = match node {
+            ExprOrPatId::ExprId(expr) => self.source_map.expr_syntax(expr)?.syntax_node_ptr(),
+            ExprOrPatId::PatId(pat) => match self.source_map.pat_syntax(pat)? {
+                PatPtr::Pat(it) => it.syntax_node_ptr(),
+                PatPtr::Param(it) => it.syntax_node_ptr(),
+            },
+        };
+        Some(InFile::new(self.file_id, ptr))
+    }
+
+    /// The field `field` of a struct literal or pattern, or the name of a
+    /// field access.
+    fn field_ptr(&self, node: ExprOrPatId, field: &Name) -> Option<InFile<SyntaxNodePtr>> {
+        let field_node = match node {
+            ExprOrPatId::ExprId(expr) => match &self.body[expr] {
+                Expr::Record { fields, .. } => {
+                    let field = fields.iter().find(|it| it.name == *field)?;
+                    let node = self.node(self.node_ptr(field.expr.into())?);
+                    node.ancestors().find(|it| ast::RecordExprField::can_cast(it.kind()))
+                }
+                Expr::Field { .. } => {
+                    let node = self.node(self.node_ptr(node)?);
+                    ast::DotExpr::cast(node)?.name_ref().map(|it| it.syntax().clone())
+                }
+                _ => None,
+            },
+            ExprOrPatId::PatId(pat) => match &self.body[pat] {
+                Pat::Record { fields, .. } => {
+                    let field = fields.iter().find(|it| it.name == *field)?;
+                    let node = self.node(self.node_ptr(field.pat.into())?);
+                    node.ancestors().find(|it| ast::RecordPatField::can_cast(it.kind()))
+                }
+                _ => None,
+            },
+        };
+        match field_node {
+            Some(it) => Some(InFile::new(self.file_id, SyntaxNodePtr::new(&it))),
+            None => self.node_ptr(node),
+        }
+    }
+
+    fn node(&self, ptr: InFile<SyntaxNodePtr>) -> SyntaxNode {
+        ptr.value.to_node(self.root)
+    }
+
+    fn display(&self, ty: &Ty) -> String {
+        ty.display(self.db).to_string()
+    }
+}
+
+/// The path of a call, a struct literal or a struct pattern.
+fn match_ast_path(node: &SyntaxNode) -> Option<ast::Path> {
+    if let Some(call) = ast::CallExpr::cast(node.clone()) {
+        return match call.expr()? {
+            ast::Expr::PathExpr(it) => it.path(),
+            _ => None,
+        };
+    }
+    if let Some(record) = ast::RecordExpr::cast(node.clone()) {
+        return record.path();
+    }
+    if let Some(record) = ast::RecordPat::cast(node.clone()) {
+        return record.path();
+    }
+    ast::PathPat::cast(node.clone())?.path()
+}
+
+fn struct_name(db: &dyn HirDatabase, id: StructId) -> String {
+    let loc = id.lookup(db.upcast());
+    loc.id.item_tree(db.upcast())[loc.id.value].name.to_string()
+}
@@ -0,0 +1,68 @@
+//! Rendering of types as they are written in Move.
+use std::fmt;
+
+use hir_def::{type_ref::Mutability, Lookup};
+
+use crate::{db::HirDatabase, InferTy, Ty};
+
+pub struct TyDisplay<'a> {
+    ty: &'a Ty,
+    db: &'a dyn HirDatabase,
+}
+
+impl Ty {
+    pub fn display<'a>(&'a self, db: &'a dyn HirDatabase) -> TyDisplay<'a> {
+        TyDisplay { ty: self, db }
+    }
+}
+
+impl fmt::Display for TyDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let db = self.db;
+        match self.ty {
+            Ty::Unknown => f.write_str("{unknown}"),
+            Ty::Bool => f.write_str("bool"),
+            Ty::Integer(it) => f.write_str(it.as_str()),
+            Ty::Address => f.write_str("address"),
+            Ty::Signer => f.write_str("signer"),
+            Ty::Vector(elem) => write!(f, "vector<{}>", elem.display(db)),
+            Ty::Struct(id, substs) => {
+                let loc = id.lookup(db.upcast());
+                write!(f, "{}", loc.id.item_tree(db.upcast())[loc.id.value].name)?;
+                if !substs.is_empty() {
+                    f.write_str("<")?;
+                    write_joined(f, db, substs)?;
+                    f.write_str(">")?;
+                }
+                Ok(())
+            }
+            Ty::Ref(Mutability::Shared, inner) => write!(f, "&{}", inner.display(db)),
+            Ty::Ref(Mutability::Mut, inner) => write!(f, "&mut {}", inner.display(db)),
+            Ty::Tuple(tys) => {
+                f.write_str("(")?;
+                write_joined(f, db, tys)?;
+                f.write_str(")")
+            }
+            Ty::TypeParam(id) => {
+                let params = id.parent.type_params(db.upcast());
+                match params.get(id.idx as usize) {
+                    Some(param) => write!(f, "{}", param.name),
+                    None => f.write_str("{unknown}"),
+                }
+            }
+            Ty::Never => f.write_str("!"),
+            Ty::Infer(InferTy::TyVar(_)) => f.write_str("_"),
+            Ty::Infer(InferTy::IntVar(_)) => f.write_str("{integer}"),
+        }
+    }
+}
+
+fn write_joined(f: &mut fmt::Formatter<'_>, db: &dyn HirDatabase, tys: &[Ty]) -> fmt::Result {
+    for (idx, ty) in tys.iter().enumerate() {
+        if idx > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}", ty.display(db))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,280 @@
+//! Type inference of function bodies.
+//!
+//! The body is walked once, from the first statement to the last, with the
+//! type expected at each position: the parameter types for the arguments of
+//! a call, the declared type for the initializer of a `let`, the return type
+//! for the tail of the body. The type of an expression is then coerced to the
+//! expected one, which either solves type variables or reports a mismatch.
+//! After the walk, all types are resolved: unsolved integer variables fall
+//! back to `u64`, other unsolved variables become `Ty::Unknown` and are
+//! reported where a type annotation is needed.
+mod expr;
+mod pat;
+mod unify;
+
+use std::{ops::Index, sync::Arc};
+
+use hir_def::{
+    arena::ArenaMap,
+    body::Body,
+    builtin::BuiltinFunction,
+    expr::{ExprId, PatId},
+    name::Name,
+    resolver::{HasResolver, Resolver},
+    type_ref::TypeRef,
+    FunctionId,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{db::HirDatabase, lower::TyLoweringContext, Ty};
+
+use self::unify::InferenceTable;
+
+pub(crate) fn infer_query(db: &dyn HirDatabase, id: FunctionId) -> Arc<InferenceResult> {
+    let mut ctx = InferenceContext::new(db, id);
+    ctx.infer_body();
+    Arc::new(ctx.resolve_all())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExprOrPatId {
+    ExprId(ExprId),
+    PatId(PatId),
+}
+
+impl From<ExprId> for ExprOrPatId {
+    fn from(id: ExprId) -> ExprOrPatId {
+        ExprOrPatId::ExprId(id)
+    }
+}
+
+impl From<PatId> for ExprOrPatId {
+    fn from(id: PatId) -> ExprOrPatId {
+        ExprOrPatId::PatId(id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Callee {
+    Function(FunctionId),
+    Builtin(BuiltinFunction),
+}
+
+/// The function called by a call expression, and the types its type
+/// parameters are instantiated with, like `u64` for `Vector::empty<u64>()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallResolution {
+    pub callee: Callee,
+    pub substs: Box<[Ty]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InferenceDiagnostic {
+    /// An expression or a pattern whose type isn't the one its position
+    /// requires.
+    TypeMismatch {
+        node: ExprOrPatId,
+        expected: Ty,
+        actual: Ty,
+    },
+    MismatchedArgCount {
+        call: ExprId,
+        expected: usize,
+        found: usize,
+    },
+    /// `s.f`, `S { f: 1 }` or `let S { f } = s` for a type without the field
+    /// `f`. The node is the field access, or the struct literal or pattern.
+    NoSuchField {
+        node: ExprOrPatId,
+        field: Name,
+        ty: Ty,
+    },
+    /// A struct literal or pattern without some of the fields of the struct.
+    MissingFields {
+        node: ExprOrPatId,
+        fields: Vec<Name>,
+    },
+    /// `(e as T)`, where `T` isn't an integer type.
+    InvalidCast {
+        expr: ExprId,
+        ty: Ty,
+    },
+    /// A local, or the type arguments of a call, a struct literal or a vector,
+    /// which nothing constrains.
+    CannotInferType {
+        node: ExprOrPatId,
+    },
+}
+
+/// The types of the expressions and patterns of a function body.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct InferenceResult {
+    type_of_expr: ArenaMap<ExprId, Ty>,
+    type_of_pat: ArenaMap<PatId, Ty>,
+    call_resolutions: FxHashMap<ExprId, CallResolution>,
+    diagnostics: Vec<InferenceDiagnostic>,
+}
+
+impl InferenceResult {
+    pub fn call_resolution(&self, call: ExprId) -> Option<&CallResolution> {
+        self.call_resolutions.get(&call)
+    }
+
+    /// The resolved calls of the body, in no particular order.
+    pub fn call_resolutions(&self) -> impl Iterator<Item = (ExprId, &CallResolution)> + '_ {
+        self.call_resolutions.iter().map(|(call, resolution)| (*call, resolution))
+    }
+
+    pub fn diagnostics(&self) -> &[InferenceDiagnostic] {
+        &self.diagnostics
+    }
+}
+
+static UNKNOWN: Ty = Ty::Unknown;
+
+impl Index<ExprId> for InferenceResult {
+    type Output = Ty;
+    fn index(&self, expr: ExprId) -> &Ty {
+        self.type_of_expr.get(expr).unwrap_or(&UNKNOWN)
+    }
+}
+
+impl Index<PatId> for InferenceResult {
+    type Output = Ty;
+    fn index(&self, pat: PatId) -> &Ty {
+        self.type_of_pat.get(pat).unwrap_or(&UNKNOWN)
+    }
+}
+
+struct InferenceContext<'a> {
+    db: &'a dyn HirDatabase,
+    owner: FunctionId,
+    body: Arc<Body>,
+    /// The resolver of the signature, for the types written in the body.
+    resolver: Resolver,
+    result: InferenceResult,
+    table: InferenceTable,
+    ret_ty: Ty,
+    /// For each enclosing `loop`, whether it has a `break`.
+    loops: Vec<bool>,
+    /// The type arguments of calls, struct literals and patterns, and vector
+    /// literals, which have to be solved by the end of inference.
+    instantiations: Vec<(ExprOrPatId, Box<[Ty]>)>,
+}
+
+impl<'a> InferenceContext<'a> {
+    fn new(db: &'a dyn HirDatabase, owner: FunctionId) -> InferenceContext<'a> {
+        InferenceContext {
+            db,
+            owner,
+            body: db.body(owner),
+            resolver: owner.resolver(db.upcast()),
+            result: InferenceResult::default(),
+            table: InferenceTable::default(),
+            ret_ty: Ty::Unknown,
+            loops: Vec::new(),
+            instantiations: Vec::new(),
+        }
+    }
+
+    fn infer_body(&mut self) {
+        let sig = self.db.fn_sig(self.owner);
+        let body = Arc::clone(&self.body);
+        for (idx, &pat) in body.params.iter().enumerate() {
+            let ty = sig.params.get(idx).cloned().unwrap_or(Ty::Unknown);
+            self.write_pat_ty(pat, ty);
+        }
+        self.ret_ty = sig.ret.clone();
+        let ret_ty = self.ret_ty.clone();
+        self.infer_expr(body.body_expr, &ret_ty);
+    }
+
+    fn resolve_all(mut self) -> InferenceResult {
+        self.report_unsolved();
+        let mut result = std::mem::take(&mut self.result);
+        let table = &self.table;
+        for ty in result.type_of_expr.values_mut() {
+            *ty = table.resolve_completely(ty.clone());
+        }
+        for ty in result.type_of_pat.values_mut() {
+            *ty = table.resolve_completely(ty.clone());
+        }
+        for resolution in result.call_resolutions.values_mut() {
+            resolution.substs =
+                resolution.substs.iter().map(|ty| table.resolve_completely(ty.clone())).collect();
+        }
+        for diagnostic in result.diagnostics.iter_mut() {
+            match diagnostic {
+                InferenceDiagnostic::TypeMismatch { expected, actual, .. } => {
+                    *expected = table.resolve_for_display(expected.clone());
+                    *actual = table.resolve_for_display(actual.clone());
+                }
+                InferenceDiagnostic::NoSuchField { ty, .. }
+                | InferenceDiagnostic::InvalidCast { ty, .. } => {
+                    *ty = table.resolve_for_display(ty.clone());
+                }
+                InferenceDiagnostic::MismatchedArgCount { .. }
+                | InferenceDiagnostic::MissingFields { .. }
+                | InferenceDiagnostic::CannotInferType { .. } => {}
+            }
+        }
+        result
+    }
+
+    /// Reports the locals and instantiations with unsolved type variables.
+    /// A variable is only reported once, at the first local which has it,
+    /// or else at the first instantiation.
+    fn report_unsolved(&mut self) {
+        let mut reported = FxHashSet::default();
+        let mut locals: Vec<_> =
+            self.result.type_of_pat.iter().map(|(pat, ty)| (pat, ty.clone())).collect();
+        locals.sort_by_key(|(pat, _)| *pat);
+        for (pat, ty) in locals {
+            let vars = self.table.unsolved_vars(&ty);
+            if !vars.is_empty() && vars.iter().any(|it| !reported.contains(it)) {
+                reported.extend(vars);
+                self.push_diagnostic(InferenceDiagnostic::CannotInferType { node: pat.into() });
+            }
+        }
+        for (node, substs) in std::mem::take(&mut self.instantiations) {
+            let vars: Vec<_> = substs.iter().flat_map(|ty| self.table.unsolved_vars(ty)).collect();
+            if !vars.is_empty() && vars.iter().any(|it| !reported.contains(it)) {
+                reported.extend(vars);
+                self.push_diagnostic(InferenceDiagnostic::CannotInferType { node });
+            }
+        }
+    }
+
+    fn write_expr_ty(&mut self, expr: ExprId, ty: Ty) {
+        self.result.type_of_expr.insert(expr, ty);
+    }
+
+    fn write_pat_ty(&mut self, pat: PatId, ty: Ty) {
+        self.result.type_of_pat.insert(pat, ty);
+    }
+
+    fn push_diagnostic(&mut self, diagnostic: InferenceDiagnostic) {
+        self.result.diagnostics.push(diagnostic);
+    }
+
+    fn push_mismatch(&mut self, node: ExprOrPatId, expected: &Ty, actual: &Ty) {
+        let (expected, actual) = (expected.clone(), actual.clone());
+        self.push_diagnostic(InferenceDiagnostic::TypeMismatch { node, expected, actual });
+    }
+
+    fn lower_ty(&self, type_ref: &TypeRef) -> Ty {
+        TyLoweringContext::new(self.db, &self.resolver).lower_ty(type_ref)
+    }
+
+    /// The types the `count` type parameters of a generic item are
+    /// instantiated with: the explicit type arguments if there are any, or
+    /// else fresh type variables.
+    fn instantiate(&mut self, type_args: Option<&[TypeRef]>, count: usize) -> Box<[Ty]> {
+        match type_args {
+            Some(args) => {
+                TyLoweringContext::new(self.db, &self.resolver).lower_type_args(args, count)
+            }
+            None => (0..count).map(|_| self.table.new_ty_var()).collect(),
+        }
+    }
+}
@@ -0,0 +1,428 @@
+//! Inference of the types of expressions.
+use std::sync::Arc;
+
+use hir_def::{
+    builtin::BuiltinFunction,
+    expr::{ArithOp, BinaryOp, CmpOp, Expr, ExprId, Literal, RecordLitField, Statement, UnaryOp},
+    path::Path,
+    resolver::{resolver_for_expr, TypeNs, ValueNs},
+    type_ref::Mutability,
+    GenericDefId, StructId,
+};
+
+use super::{CallResolution, Callee, InferenceContext, InferenceDiagnostic};
+use crate::{IntTy, Ty};
+
+impl InferenceContext<'_> {
+    /// Infers the type of `expr`, and coerces it to `expected`. On a
+    /// mismatch, the expected type is returned, so that the enclosing
+    /// expressions aren't reported as well.
+    pub(super) fn infer_expr(&mut self, expr: ExprId, expected: &Ty) -> Ty {
+        let ty = self.infer_expr_inner(expr, expected);
+        self.write_expr_ty(expr, ty.clone());
+        if !self.table.coerce(&ty, expected) {
+            self.push_mismatch(expr.into(), expected, &ty);
+            return expected.clone();
+        }
+        ty
+    }
+
+    /// Infers the type of `expr` where any type is accepted, without solving
+    /// its type variables with `Ty::Unknown`.
+    fn infer_expr_no_expectation(&mut self, expr: ExprId) -> Ty {
+        let ty = self.infer_expr_inner(expr, &Ty::Unknown);
+        self.write_expr_ty(expr, ty.clone());
+        ty
+    }
+
+    fn infer_expr_inner(&mut self, expr: ExprId, expected: &Ty) -> Ty {
+        let body = Arc::clone(&self.body);
+        match &body[expr] {
+            Expr::Missing | Expr::Spec => Ty::Unknown,
+            Expr::Path(path) => self.infer_path(expr, path),
+            Expr::Literal(literal) => match literal {
+                Literal::Bool(_) => Ty::Bool,
+                Literal::Integer { suffix, .. } => match suffix.and_then(IntTy::from_builtin) {
+                    Some(it) => Ty::Integer(it),
+                    None => self.table.new_int_var(),
+                },
+                Literal::ByteString => Ty::Vector(Box::new(Ty::Integer(IntTy::U8))),
+                Literal::Address(_) => Ty::Address,
+            },
+            Expr::Block { statements, tail } => self.infer_block(statements, *tail, expected),
+            Expr::If { condition, then_branch, else_branch } => {
+                self.infer_expr(*condition, &Ty::Bool);
+                match else_branch {
+                    Some(else_branch) => {
+                        let ty = match expected {
+                            Ty::Unknown => self.table.new_ty_var(),
+                            _ => expected.clone(),
+                        };
+                        let then_ty = self.infer_expr(*then_branch, &ty);
+                        let else_ty = self.infer_expr(*else_branch, &ty);
+                        if then_ty == Ty::Never && else_ty == Ty::Never {
+                            Ty::Never
+                        } else {
+                            ty
+                        }
+                    }
+                    None => {
+                        self.infer_expr(*then_branch, &Ty::unit());
+                        Ty::unit()
+                    }
+                }
+            }
+            Expr::While { condition, body } => {
+                self.infer_expr(*condition, &Ty::Bool);
+                self.loops.push(false);
+                self.infer_expr(*body, &Ty::unit());
+                self.loops.pop();
+                Ty::unit()
+            }
+            Expr::Loop { body } => {
+                self.loops.push(false);
+                self.infer_expr(*body, &Ty::unit());
+                let has_break = self.loops.pop().unwrap_or(false);
+                if has_break {
+                    Ty::unit()
+                } else {
+                    Ty::Never
+                }
+            }
+            Expr::Break => {
+                if let Some(has_break) = self.loops.last_mut() {
+                    *has_break = true;
+                }
+                Ty::Never
+            }
+            Expr::Continue => Ty::Never,
+            Expr::Return { expr: value } => {
+                let ret_ty = self.ret_ty.clone();
+                match value {
+                    Some(value) => {
+                        self.infer_expr(*value, &ret_ty);
+                    }
+                    None => {
+                        if !self.table.coerce(&Ty::unit(), &ret_ty) {
+                            self.push_mismatch(expr.into(), &ret_ty, &Ty::unit());
+                        }
+                    }
+                }
+                Ty::Never
+            }
+            Expr::Abort { expr } => {
+                self.infer_expr(*expr, &Ty::Integer(IntTy::U64));
+                Ty::Never
+            }
+            Expr::Call { callee, args } => self.infer_call(expr, *callee, args),
+            Expr::Field { expr: receiver, name } => {
+                let receiver_ty = self.infer_expr_no_expectation(*receiver);
+                let receiver_ty = self.strip_references(&receiver_ty);
+                match &receiver_ty {
+                    Ty::Struct(id, substs) => {
+                        match self.db.field_types(*id).iter().find(|(field, _)| field == name) {
+                            Some((_, ty)) => ty.subst(substs),
+                            None => {
+                                self.push_diagnostic(InferenceDiagnostic::NoSuchField {
+                                    node: expr.into(),
+                                    field: name.clone(),
+                                    ty: receiver_ty.clone(),
+                                });
+                                Ty::Unknown
+                            }
+                        }
+                    }
+                    Ty::Unknown | Ty::Never | Ty::Infer(_) => Ty::Unknown,
+                    _ => {
+                        self.push_diagnostic(InferenceDiagnostic::NoSuchField {
+                            node: expr.into(),
+                            field: name.clone(),
+                            ty: receiver_ty.clone(),
+                        });
+                        Ty::Unknown
+                    }
+                }
+            }
+            // Indexing is only valid in specs.
+            Expr::Index { base, index } => {
+                self.infer_expr_no_expectation(*base);
+                self.infer_expr_no_expectation(*index);
+                Ty::Unknown
+            }
+            Expr::Record { path, fields } => self.infer_record(expr, path.as_ref(), fields),
+            Expr::UnaryOp { expr, op: UnaryOp::Not } => {
+                self.infer_expr(*expr, &Ty::Bool);
+                Ty::Bool
+            }
+            Expr::UnaryOp { expr, op: UnaryOp::Deref } => {
+                let ty = self.infer_expr_no_expectation(*expr);
+                match self.table.resolve_shallow(&ty) {
+                    Ty::Ref(_, inner) => *inner,
+                    _ => Ty::Unknown,
+                }
+            }
+            Expr::Ref { expr, mutability } => {
+                let expected_inner = match self.table.resolve_shallow(expected) {
+                    Ty::Ref(_, inner) => *inner,
+                    _ => Ty::Unknown,
+                };
+                let inner = self.infer_expr(*expr, &expected_inner);
+                Ty::Ref(*mutability, Box::new(inner))
+            }
+            Expr::Move { name } | Expr::Copy { name } => {
+                let resolver = resolver_for_expr(self.db.upcast(), self.owner, expr);
+                match resolver.resolve_local(name) {
+                    Some(pat) => self.result[pat].clone(),
+                    None => Ty::Unknown,
+                }
+            }
+            Expr::BinaryOp { lhs, rhs, op } => self.infer_binary_op(*lhs, *rhs, *op),
+            Expr::Cast { expr: value, type_ref } => {
+                let int_var = self.table.new_int_var();
+                self.infer_expr(*value, &int_var);
+                let ty = self.lower_ty(type_ref);
+                if !matches!(ty, Ty::Integer(_) | Ty::Unknown) {
+                    self.push_diagnostic(InferenceDiagnostic::InvalidCast { expr, ty: ty.clone() });
+                }
+                ty
+            }
+            Expr::Tuple { exprs } => {
+                let expected = match self.table.resolve_shallow(expected) {
+                    Ty::Tuple(tys) if tys.len() == exprs.len() => tys.into_vec(),
+                    _ => vec![Ty::Unknown; exprs.len()],
+                };
+                let tys =
+                    exprs.iter().zip(expected.iter()).map(|(expr, ty)| self.infer_expr(*expr, ty));
+                Ty::Tuple(tys.collect())
+            }
+            Expr::Vector { type_arg, elements } => {
+                let elem = match type_arg {
+                    Some(type_ref) => self.lower_ty(type_ref),
+                    None => {
+                        let var = self.table.new_ty_var();
+                        self.instantiations.push((expr.into(), Box::new([var.clone()])));
+                        var
+                    }
+                };
+                for element in elements.iter() {
+                    self.infer_expr(*element, &elem);
+                }
+                Ty::Vector(Box::new(elem))
+            }
+        }
+    }
+
+    fn infer_block(&mut self, statements: &[Statement], tail: Option<ExprId>, expected: &Ty) -> Ty {
+        let mut diverges = false;
+        for statement in statements {
+            let ty = match statement {
+                Statement::Let { pat, type_ref, initializer } => {
+                    let decl_ty = match type_ref {
+                        Some(type_ref) => self.lower_ty(type_ref),
+                        None => self.table.new_ty_var(),
+                    };
+                    let ty = initializer.map(|init| self.infer_expr(init, &decl_ty));
+                    self.infer_pat(*pat, &decl_ty);
+                    ty
+                }
+                Statement::Expr(expr) => Some(self.infer_expr_no_expectation(*expr)),
+            };
+            if ty.is_some_and(|ty| self.table.resolve_shallow(&ty) == Ty::Never) {
+                diverges = true;
+            }
+        }
+        match tail {
+            Some(tail) => self.infer_expr(tail, expected),
+            // A block which ends with `return x;` or `abort 0;` has any type.
+            None if diverges => Ty::Never,
+            None => Ty::unit(),
+        }
+    }
+
+    fn infer_path(&mut self, expr: ExprId, path: &Path) -> Ty {
+        let resolver = resolver_for_expr(self.db.upcast(), self.owner, expr);
+        match resolver.resolve_path_in_value_ns(path) {
+            Some(ValueNs::Local(pat)) => self.result[pat].clone(),
+            Some(ValueNs::ConstId(id)) => self.db.const_ty(id),
+            // Functions aren't values; they are only checked when called.
+            Some(ValueNs::FunctionId(_)) | Some(ValueNs::BuiltinFunction(_)) | None => Ty::Unknown,
+        }
+    }
+
+    fn infer_call(&mut self, call: ExprId, callee: ExprId, args: &[ExprId]) -> Ty {
+        let path = match &self.body[callee] {
+            Expr::Path(path) => Some(path.clone()),
+            _ => None,
+        };
+        let resolver = resolver_for_expr(self.db.upcast(), self.owner, callee);
+        let res = path.as_ref().and_then(|it| resolver.resolve_path_in_value_ns(it));
+        let (callee_def, type_params) = match res {
+            Some(ValueNs::FunctionId(id)) => (Callee::Function(id), self.db.fn_sig(id).type_params),
+            Some(ValueNs::BuiltinFunction(it)) => (Callee::Builtin(it), builtin_type_params(it)),
+            _ => {
+                self.infer_expr(callee, &Ty::Unknown);
+                for arg in args {
+                    self.infer_expr(*arg, &Ty::Unknown);
+                }
+                return Ty::Unknown;
+            }
+        };
+        self.write_expr_ty(callee, Ty::Unknown);
+
+        let type_args = path.as_ref().and_then(|it| it.type_args.as_deref());
+        let substs = self.instantiate(type_args, type_params);
+        if type_args.is_none() && !substs.is_empty() {
+            self.instantiations.push((call.into(), substs.clone()));
+        }
+        let (params, ret) = match callee_def {
+            Callee::Function(id) => {
+                let sig = self.db.fn_sig(id);
+                let params = sig.params.iter().map(|it| it.subst(&substs)).collect();
+                (params, sig.ret.subst(&substs))
+            }
+            Callee::Builtin(it) => builtin_sig(it, &substs),
+        };
+        if args.len() != params.len() {
+            self.push_diagnostic(InferenceDiagnostic::MismatchedArgCount {
+                call,
+                expected: params.len(),
+                found: args.len(),
+            });
+        }
+        for (idx, arg) in args.iter().enumerate() {
+            let expected = params.get(idx).cloned().unwrap_or(Ty::Unknown);
+            self.infer_expr(*arg, &expected);
+        }
+        self.result.call_resolutions.insert(call, CallResolution { callee: callee_def, substs });
+        ret
+    }
+
+    fn infer_record(&mut self, expr: ExprId, path: Option<&Path>, fields: &[RecordLitField]) -> Ty {
+        let resolver = resolver_for_expr(self.db.upcast(), self.owner, expr);
+        let strukt = match path.and_then(|it| resolver.resolve_path_in_type_ns(it)) {
+            Some(TypeNs::StructId(it)) => it,
+            _ => {
+                for field in fields {
+                    self.infer_expr(field.expr, &Ty::Unknown);
+                }
+                return Ty::Unknown;
+            }
+        };
+        let type_args = path.and_then(|it| it.type_args.as_deref());
+        let substs = self.instantiate_struct(strukt, type_args);
+        if type_args.is_none() && !substs.is_empty() {
+            self.instantiations.push((expr.into(), substs.clone()));
+        }
+        let ty = Ty::Struct(strukt, substs.clone());
+
+        let field_types = self.db.field_types(strukt);
+        for field in fields {
+            let expected = match field_types.iter().find(|(name, _)| *name == field.name) {
+                Some((_, ty)) => ty.subst(&substs),
+                None => {
+                    self.push_diagnostic(InferenceDiagnostic::NoSuchField {
+                        node: expr.into(),
+                        field: field.name.clone(),
+                        ty: ty.clone(),
+                    });
+                    Ty::Unknown
+                }
+            };
+            self.infer_expr(field.expr, &expected);
+        }
+        let missing: Vec<_> = field_types
+            .iter()
+            .filter(|(name, _)| !fields.iter().any(|field| field.name == *name))
+            .map(|(name, _)| name.clone())
+            .collect();
+        if !missing.is_empty() {
+            self.push_diagnostic(InferenceDiagnostic::MissingFields {
+                node: expr.into(),
+                fields: missing,
+            });
+        }
+        ty
+    }
+
+    fn infer_binary_op(&mut self, lhs: ExprId, rhs: ExprId, op: Option<BinaryOp>) -> Ty {
+        match op {
+            Some(BinaryOp::Assignment) => {
+                let lhs_ty = self.infer_expr_no_expectation(lhs);
+                self.infer_expr(rhs, &lhs_ty);
+                Ty::unit()
+            }
+            Some(BinaryOp::Logic(_)) => {
+                self.infer_expr(lhs, &Ty::Bool);
+                self.infer_expr(rhs, &Ty::Bool);
+                Ty::Bool
+            }
+            Some(BinaryOp::Cmp(CmpOp::Eq)) | Some(BinaryOp::Cmp(CmpOp::NotEq)) => {
+                let lhs_ty = self.infer_expr_no_expectation(lhs);
+                self.infer_expr(rhs, &lhs_ty);
+                Ty::Bool
+            }
+            Some(BinaryOp::Cmp(_)) => {
+                let int_var = self.table.new_int_var();
+                self.infer_expr(lhs, &int_var);
+                self.infer_expr(rhs, &int_var);
+                Ty::Bool
+            }
+            Some(BinaryOp::Arith(op)) => {
+                let int_var = self.table.new_int_var();
+                self.infer_expr(lhs, &int_var);
+                match op {
+                    ArithOp::Shl | ArithOp::Shr => self.infer_expr(rhs, &Ty::Integer(IntTy::U8)),
+                    _ => self.infer_expr(rhs, &int_var),
+                };
+                int_var
+            }
+            Some(BinaryOp::Range) | None => {
+                self.infer_expr_no_expectation(lhs);
+                self.infer_expr_no_expectation(rhs);
+                Ty::Unknown
+            }
+        }
+    }
+
+    pub(super) fn instantiate_struct(
+        &mut self,
+        strukt: StructId,
+        type_args: Option<&[hir_def::type_ref::TypeRef]>,
+    ) -> Box<[Ty]> {
+        let count = GenericDefId::from(strukt).type_params(self.db.upcast()).len();
+        self.instantiate(type_args, count)
+    }
+
+    /// The type behind any number of references.
+    pub(super) fn strip_references(&self, ty: &Ty) -> Ty {
+        let mut ty = self.table.resolve_shallow(ty);
+        while let Ty::Ref(_, inner) = ty {
+            ty = self.table.resolve_shallow(&inner);
+        }
+        ty
+    }
+}
+
+fn builtin_type_params(builtin: BuiltinFunction) -> usize {
+    match builtin {
+        BuiltinFunction::Assert => 0,
+        _ => 1,
+    }
+}
+
+/// The parameter and return types of a builtin function, instantiated with
+/// `substs`. All builtins but `assert` have a single type parameter.
+fn builtin_sig(builtin: BuiltinFunction, substs: &[Ty]) -> (Vec<Ty>, Ty) {
+    let t = || substs.first().cloned().unwrap_or(Ty::Unknown);
+    let shared = |ty: Ty| Ty::Ref(Mutability::Shared, Box::new(ty));
+    let mutable = |ty: Ty| Ty::Ref(Mutability::Mut, Box::new(ty));
+    match builtin {
+        BuiltinFunction::BorrowGlobal => (vec![Ty::Address], shared(t())),
+        BuiltinFunction::BorrowGlobalMut => (vec![Ty::Address], mutable(t())),
+        BuiltinFunction::MoveFrom => (vec![Ty::Address], t()),
+        BuiltinFunction::Exists => (vec![Ty::Address], Ty::Bool),
+        BuiltinFunction::MoveTo => (vec![shared(Ty::Signer), t()], Ty::unit()),
+        BuiltinFunction::Freeze => (vec![mutable(t())], shared(t())),
+        BuiltinFunction::Assert => (vec![Ty::Bool, Ty::Integer(IntTy::U64)], Ty::unit()),
+    }
+}
@@ -0,0 +1,126 @@
+//! Inference of the types of patterns.
+use std::sync::Arc;
+
+use hir_def::{
+    expr::{Pat, PatId, RecordFieldPat},
+    path::Path,
+    resolver::TypeNs,
+    StructId,
+};
+
+use super::{InferenceContext, InferenceDiagnostic};
+use crate::Ty;
+
+impl InferenceContext<'_> {
+    /// Infers the type of `pat` from the type of the value it destructures.
+    pub(super) fn infer_pat(&mut self, pat: PatId, expected: &Ty) -> Ty {
+        let body = Arc::clone(&self.body);
+        let ty = match &body[pat] {
+            Pat::Missing | Pat::Wild | Pat::Bind { .. } => expected.clone(),
+            Pat::Tuple { pats } => {
+                let expected = match self.table.resolve_shallow(expected) {
+                    Ty::Tuple(tys) if tys.len() == pats.len() => tys.into_vec(),
+                    Ty::Infer(_) => pats.iter().map(|_| self.table.new_ty_var()).collect(),
+                    // A mismatch, reported below.
+                    _ => vec![Ty::Unknown; pats.len()],
+                };
+                let tys =
+                    pats.iter().zip(expected.iter()).map(|(pat, ty)| self.infer_pat(*pat, ty));
+                Ty::Tuple(tys.collect())
+            }
+            Pat::Record { path, fields, ellipsis } => match self.resolve_struct(path.as_ref()) {
+                Some(strukt) => {
+                    self.infer_record_pat(pat, strukt, path.as_ref(), fields, *ellipsis, expected)
+                }
+                None => {
+                    for field in fields.iter() {
+                        self.infer_pat(field.pat, &Ty::Unknown);
+                    }
+                    Ty::Unknown
+                }
+            },
+            Pat::Path(path) => match self.resolve_struct(Some(path)) {
+                Some(strukt) => {
+                    let type_args = path.type_args.as_deref();
+                    Ty::Struct(strukt, self.instantiate_struct(strukt, type_args))
+                }
+                None => Ty::Unknown,
+            },
+        };
+        if !self.table.unify(&ty, expected) {
+            self.push_mismatch(pat.into(), expected, &ty);
+        }
+        self.write_pat_ty(pat, ty.clone());
+        ty
+    }
+
+    /// `let S { f } = s`, or `let S { f } = &s` which binds `f` to a
+    /// reference to the field.
+    fn infer_record_pat(
+        &mut self,
+        pat: PatId,
+        strukt: StructId,
+        path: Option<&Path>,
+        fields: &[RecordFieldPat],
+        ellipsis: bool,
+        expected: &Ty,
+    ) -> Ty {
+        let type_args = path.and_then(|it| it.type_args.as_deref());
+        let substs = self.instantiate_struct(strukt, type_args);
+        let struct_ty = Ty::Struct(strukt, substs.clone());
+        let (ty, mutability) = match self.table.resolve_shallow(expected) {
+            Ty::Ref(mutability, inner) => {
+                // Solve the type arguments before the fields are checked.
+                self.table.unify(&struct_ty, &inner);
+                (Ty::Ref(mutability, Box::new(struct_ty.clone())), Some(mutability))
+            }
+            _ => {
+                self.table.unify(&struct_ty, expected);
+                (struct_ty.clone(), None)
+            }
+        };
+
+        let field_types = self.db.field_types(strukt);
+        for field in fields {
+            let expected = match field_types.iter().find(|(name, _)| *name == field.name) {
+                Some((_, ty)) => {
+                    let ty = ty.subst(&substs);
+                    match mutability {
+                        Some(mutability) => Ty::Ref(mutability, Box::new(ty)),
+                        None => ty,
+                    }
+                }
+                None => {
+                    self.push_diagnostic(InferenceDiagnostic::NoSuchField {
+                        node: pat.into(),
+                        field: field.name.clone(),
+                        ty: struct_ty.clone(),
+                    });
+                    Ty::Unknown
+                }
+            };
+            self.infer_pat(field.pat, &expected);
+        }
+        if !ellipsis {
+            let missing: Vec<_> = field_types
+                .iter()
+                .filter(|(name, _)| !fields.iter().any(|field| field.name == *name))
+                .map(|(name, _)| name.clone())
+                .collect();
+            if !missing.is_empty() {
+                self.push_diagnostic(InferenceDiagnostic::MissingFields {
+                    node: pat.into(),
+                    fields: missing,
+                });
+            }
+        }
+        ty
+    }
+
+    fn resolve_struct(&self, path: Option<&Path>) -> Option<StructId> {
+        match self.resolver.resolve_path_in_type_ns(path?)? {
+            TypeNs::StructId(it) => Some(it),
+            TypeNs::TypeParam(_) | TypeNs::BuiltinType(_) => None,
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! The table of type variables, and unification.
+use hir_def::type_ref::Mutability;
+
+use crate::{InferTy, IntTy, Ty};
+
+#[derive(Debug, Default)]
+pub(super) struct InferenceTable {
+    /// The value of each variable, once it is solved. A variable can be
+    /// solved with another variable.
+    values: Vec<Option<Ty>>,
+}
+
+impl InferenceTable {
+    pub(super) fn new_ty_var(&mut self) -> Ty {
+        Ty::Infer(InferTy::TyVar(self.new_var()))
+    }
+
+    pub(super) fn new_int_var(&mut self) -> Ty {
+        Ty::Infer(InferTy::IntVar(self.new_var()))
+    }
+
+    fn new_var(&mut self) -> u32 {
+        self.values.push(None);
+        (self.values.len() - 1) as u32
+    }
+
+    /// Follows the solved variables at the top of `ty`.
+    pub(super) fn resolve_shallow(&self, ty: &Ty) -> Ty {
+        let mut ty = ty.clone();
+        while let Ty::Infer(var) = &ty {
+            match &self.values[var_idx(*var)] {
+                Some(value) => ty = value.clone(),
+                None => break,
+            }
+        }
+        ty
+    }
+
+    /// Replaces all solved variables in `ty`.
+    pub(super) fn resolve(&self, ty: Ty) -> Ty {
+        ty.fold(&mut |ty| match ty {
+            Ty::Infer(_) => {
+                let ty = self.resolve_shallow(&ty);
+                match ty {
+                    Ty::Infer(_) => ty,
+                    ty => self.resolve(ty),
+                }
+            }
+            ty => ty,
+        })
+    }
+
+    /// Resolves `ty` for the result of inference: unsolved integer variables
+    /// fall back to `u64`, and other variables become `Ty::Unknown`.
+    pub(super) fn resolve_completely(&self, ty: Ty) -> Ty {
+        self.resolve(ty).fold(&mut |ty| match ty {
+            Ty::Infer(InferTy::IntVar(_)) => Ty::Integer(IntTy::U64),
+            Ty::Infer(InferTy::TyVar(_)) => Ty::Unknown,
+            ty => ty,
+        })
+    }
+
+    /// Resolves `ty` for a diagnostic, where an unsolved integer variable is
+    /// shown as `{integer}`.
+    pub(super) fn resolve_for_display(&self, ty: Ty) -> Ty {
+        self.resolve(ty).fold(&mut |ty| match ty {
+            Ty::Infer(InferTy::TyVar(_)) => Ty::Unknown,
+            ty => ty,
+        })
+    }
+
+    /// The unsolved type variables in `ty`. Integer variables are never
+    /// reported, as they have a fallback.
+    pub(super) fn unsolved_vars(&self, ty: &Ty) -> Vec<u32> {
+        let mut res = Vec::new();
+        self.resolve(ty.clone()).walk(&mut |ty| {
+            if let Ty::Infer(InferTy::TyVar(var)) = ty {
+                res.push(*var);
+            }
+        });
+        res
+    }
+
+    /// Makes the two types equal, solving the variables in them. Returns
+    /// `false` if they can't be.
+    pub(super) fn unify(&mut self, ty1: &Ty, ty2: &Ty) -> bool {
+        let ty1 = self.resolve_shallow(ty1);
+        let ty2 = self.resolve_shallow(ty2);
+        match (&ty1, &ty2) {
+            // `Never` coerces to every type, but doesn't solve variables: the
+            // other branch of an `if` decides its type.
+            (Ty::Never, _) | (_, Ty::Never) => true,
+
+            (Ty::Infer(InferTy::TyVar(var1)), Ty::Infer(InferTy::TyVar(var2)))
+            | (Ty::Infer(InferTy::IntVar(var1)), Ty::Infer(InferTy::IntVar(var2)))
+                if var1 == var2 =>
+            {
+                true
+            }
+            (Ty::Infer(InferTy::TyVar(var)), other) | (other, Ty::Infer(InferTy::TyVar(var))) => {
+                self.solve(*var, other.clone())
+            }
+            (Ty::Infer(InferTy::IntVar(var)), other) | (other, Ty::Infer(InferTy::IntVar(var))) => {
+                match other {
+                    Ty::Integer(_) | Ty::Infer(InferTy::IntVar(_)) | Ty::Unknown => {
+                        self.solve(*var, other.clone())
+                    }
+                    _ => false,
+                }
+            }
+            (Ty::Unknown, _) | (_, Ty::Unknown) => true,
+
+            (Ty::Bool, Ty::Bool) | (Ty::Address, Ty::Address) | (Ty::Signer, Ty::Signer) => true,
+            (Ty::Integer(int1), Ty::Integer(int2)) => int1 == int2,
+            (Ty::TypeParam(param1), Ty::TypeParam(param2)) => param1 == param2,
+            (Ty::Vector(elem1), Ty::Vector(elem2)) => self.unify(elem1, elem2),
+            (Ty::Ref(mut1, inner1), Ty::Ref(mut2, inner2)) => {
+                mut1 == mut2 && self.unify(inner1, inner2)
+            }
+            (Ty::Struct(id1, substs1), Ty::Struct(id2, substs2)) => {
+                id1 == id2 && self.unify_all(substs1, substs2)
+            }
+            (Ty::Tuple(tys1), Ty::Tuple(tys2)) => self.unify_all(tys1, tys2),
+            _ => false,
+        }
+    }
+
+    /// Unifies the type of an expression with the type expected for it. This
+    /// also accepts a `&mut T` where a `&T` is expected.
+    pub(super) fn coerce(&mut self, actual: &Ty, expected: &Ty) -> bool {
+        let actual = self.resolve_shallow(actual);
+        let expected = self.resolve_shallow(expected);
+        match (&actual, &expected) {
+            (Ty::Ref(Mutability::Mut, inner1), Ty::Ref(Mutability::Shared, inner2)) => {
+                self.unify(inner1, inner2)
+            }
+            _ => self.unify(&actual, &expected),
+        }
+    }
+
+    fn unify_all(&mut self, tys1: &[Ty], tys2: &[Ty]) -> bool {
+        tys1.len() == tys2.len() && tys1.iter().zip(tys2.iter()).all(|(a, b)| self.unify(a, b))
+    }
+
+    fn solve(&mut self, var: u32, ty: Ty) -> bool {
+        // A variable can't be solved with a type which contains it.
+        let mut occurs = false;
+        self.resolve(ty.clone()).walk(&mut |it| {
+            if matches!(it, Ty::Infer(other) if var_idx(*other) == var as usize) {
+                occurs = true;
+            }
+        });
+        if occurs {
+            return false;
+        }
+        self.values[var as usize] = Some(ty);
+        true
+    }
+}
+
+fn var_idx(var: InferTy) -> usize {
+    match var {
+        InferTy::TyVar(it) | InferTy::IntVar(it) => it as usize,
+    }
+}
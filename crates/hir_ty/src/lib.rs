@@ -0,0 +1,189 @@
+//! `hir_ty` crate infers the types of the expressions and locals of function
+//! bodies.
+//!
+//! The types written in signatures are lowered into `Ty`s first: the paths in
+//! them are resolved, and the type parameters of the item stay as
+//! `Ty::TypeParam`. A function body is then checked in a single walk, which
+//! assigns a type to every expression and pattern. Types which aren't known
+//! yet are type variables, solved by unification: `Vector::empty()` gets a
+//! fresh variable for its type argument, which the uses of the vector fill in.
+//! An integer literal without a suffix gets an integer variable, which falls
+//! back to `u64` when nothing constrains it.
+//!
+//! Inference never fails: what can't be typed becomes `Ty::Unknown`, which
+//! unifies with everything, so that a single error isn't reported over and
+//! over again. The errors themselves are collected as diagnostics of the
+//! `InferenceResult`.
+//!
+//! The abilities of values and the `acquires` lists of functions are checked
+//! after inference, using the inferred types and calls.
+pub mod db;
+pub mod diagnostics;
+
+mod abilities;
+mod acquires;
+mod display;
+mod infer;
+mod lower;
+
+#[cfg(test)]
+mod test_db;
+#[cfg(test)]
+mod tests;
+
+use hir_def::{
+    builtin::BuiltinType, source_analyzer::SourceAnalyzer, type_ref::Mutability, InFile, StructId,
+    TypeParamId,
+};
+use syntax::ast::{self, AstNode};
+
+use crate::db::HirDatabase;
+
+pub use crate::{
+    abilities::{check_abilities, AbilityDiagnostic, AbilityOwner},
+    acquires::{acquired_structs, acquiring_calls, ModuleAcquires},
+    display::TyDisplay,
+    infer::{CallResolution, Callee, ExprOrPatId, InferenceDiagnostic, InferenceResult},
+    lower::TyLoweringContext,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Ty {
+    /// A type which couldn't be lowered or inferred. It unifies with every
+    /// type.
+    Unknown,
+    Bool,
+    Integer(IntTy),
+    Address,
+    Signer,
+    Vector(Box<Ty>),
+    /// A struct, with the types of its type parameters.
+    Struct(StructId, Box<[Ty]>),
+    /// `&T` or `&mut T`.
+    Ref(Mutability, Box<Ty>),
+    /// `(u64, bool)`; the unit type is the empty tuple.
+    Tuple(Box<[Ty]>),
+    TypeParam(TypeParamId),
+    /// The type of expressions which never produce a value, like `return`
+    /// and `abort`. It coerces to every type.
+    Never,
+    /// A type which is still being inferred. Only the integer variables of
+    /// integer expressions which failed to type check are left after
+    /// inference, in diagnostics.
+    Infer(InferTy),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntTy {
+    U8,
+    U64,
+    U128,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InferTy {
+    TyVar(u32),
+    /// A variable which can only be an integer type.
+    IntVar(u32),
+}
+
+/// The signature of a function, in terms of its type parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FnSig {
+    pub type_params: usize,
+    pub params: Box<[Ty]>,
+    pub ret: Ty,
+}
+
+impl IntTy {
+    pub fn from_builtin(ty: BuiltinType) -> Option<IntTy> {
+        let res = match ty {
+            BuiltinType::U8 => IntTy::U8,
+            BuiltinType::U64 => IntTy::U64,
+            BuiltinType::U128 => IntTy::U128,
+            _ => return None,
+        };
+        Some(res)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IntTy::U8 => "u8",
+            IntTy::U64 => "u64",
+            IntTy::U128 => "u128",
+        }
+    }
+
+    /// The largest value of the type.
+    pub fn max_value(self) -> u128 {
+        match self {
+            IntTy::U8 => u8::MAX as u128,
+            IntTy::U64 => u64::MAX as u128,
+            IntTy::U128 => u128::MAX,
+        }
+    }
+}
+
+impl Ty {
+    pub fn unit() -> Ty {
+        Ty::Tuple(Box::new([]))
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Ty::Unknown)
+    }
+
+    pub fn is_unit(&self) -> bool {
+        matches!(self, Ty::Tuple(tys) if tys.is_empty())
+    }
+
+    /// Replaces the type parameters by the types in `substs`, by index. This
+    /// instantiates the signature of a generic function or struct.
+    pub fn subst(&self, substs: &[Ty]) -> Ty {
+        self.clone().fold(&mut |ty| match ty {
+            Ty::TypeParam(id) => substs.get(id.idx as usize).cloned().unwrap_or(Ty::Unknown),
+            ty => ty,
+        })
+    }
+
+    /// Rebuilds the type bottom-up, replacing every part of it by the result
+    /// of `f`.
+    pub fn fold(self, f: &mut impl FnMut(Ty) -> Ty) -> Ty {
+        let ty = match self {
+            Ty::Vector(elem) => Ty::Vector(Box::new(elem.fold(f))),
+            Ty::Struct(id, substs) => {
+                Ty::Struct(id, substs.into_vec().into_iter().map(|it| it.fold(f)).collect())
+            }
+            Ty::Ref(mutability, inner) => Ty::Ref(mutability, Box::new(inner.fold(f))),
+            Ty::Tuple(tys) => Ty::Tuple(tys.into_vec().into_iter().map(|it| it.fold(f)).collect()),
+            ty => ty,
+        };
+        f(ty)
+    }
+
+    /// Calls `f` with the type and all the types it is made of.
+    pub fn walk(&self, f: &mut impl FnMut(&Ty)) {
+        f(self);
+        match self {
+            Ty::Vector(elem) | Ty::Ref(_, elem) => elem.walk(f),
+            Ty::Struct(_, tys) | Ty::Tuple(tys) => tys.iter().for_each(|it| it.walk(f)),
+            _ => {}
+        }
+    }
+}
+
+/// The inferred type of an expression of a function body.
+pub fn type_of_expr(db: &dyn HirDatabase, expr: InFile<&ast::Expr>) -> Option<Ty> {
+    let sa = SourceAnalyzer::new_for_node(db.upcast(), expr.map(|it| it.syntax()))?;
+    let owner = sa.body_owner()?;
+    let expr_id = sa.body_source_map()?.node_expr(expr.value)?;
+    Some(db.infer(owner)[expr_id].clone())
+}
+
+/// The inferred type of a pattern, or of a binding of a function body.
+pub fn type_of_pat(db: &dyn HirDatabase, pat: InFile<&ast::Pat>) -> Option<Ty> {
+    let sa = SourceAnalyzer::new_for_node(db.upcast(), pat.map(|it| it.syntax()))?;
+    let owner = sa.body_owner()?;
+    let pat_id = sa.body_source_map()?.node_pat(pat.value)?;
+    Some(db.infer(owner)[pat_id].clone())
+}
@@ -0,0 +1,94 @@
+//! Lowering of the types written in the source code into `Ty`s.
+use std::sync::Arc;
+
+use hir_def::{
+    builtin::BuiltinType,
+    name::Name,
+    path::Path,
+    resolver::{HasResolver, Resolver, TypeNs},
+    type_ref::TypeRef,
+    ConstId, FunctionId, GenericDefId, Lookup, StructId,
+};
+
+use crate::{db::HirDatabase, FnSig, IntTy, Ty};
+
+/// Lowers `TypeRef`s with the names visible to a resolver.
+pub struct TyLoweringContext<'a> {
+    db: &'a dyn HirDatabase,
+    resolver: &'a Resolver,
+}
+
+impl<'a> TyLoweringContext<'a> {
+    pub fn new(db: &'a dyn HirDatabase, resolver: &'a Resolver) -> TyLoweringContext<'a> {
+        TyLoweringContext { db, resolver }
+    }
+
+    pub fn lower_ty(&self, type_ref: &TypeRef) -> Ty {
+        match type_ref {
+            TypeRef::Path(path) => self.lower_path(path),
+            TypeRef::Ref(mutability, inner) => Ty::Ref(*mutability, Box::new(self.lower_ty(inner))),
+            TypeRef::Tuple(tys) => Ty::Tuple(tys.iter().map(|it| self.lower_ty(it)).collect()),
+            TypeRef::Error => Ty::Unknown,
+        }
+    }
+
+    fn lower_path(&self, path: &Path) -> Ty {
+        let args: &[TypeRef] = path.type_args.as_deref().unwrap_or(&[]);
+        match self.resolver.resolve_path_in_type_ns(path) {
+            Some(TypeNs::StructId(id)) => {
+                let count = GenericDefId::from(id).type_params(self.db.upcast()).len();
+                Ty::Struct(id, self.lower_type_args(args, count))
+            }
+            Some(TypeNs::TypeParam(id)) => Ty::TypeParam(id),
+            Some(TypeNs::BuiltinType(BuiltinType::Vector)) => {
+                let elem = args.first().map_or(Ty::Unknown, |it| self.lower_ty(it));
+                Ty::Vector(Box::new(elem))
+            }
+            Some(TypeNs::BuiltinType(BuiltinType::Bool)) => Ty::Bool,
+            Some(TypeNs::BuiltinType(BuiltinType::Address)) => Ty::Address,
+            Some(TypeNs::BuiltinType(BuiltinType::Signer)) => Ty::Signer,
+            Some(TypeNs::BuiltinType(it)) => {
+                IntTy::from_builtin(it).map_or(Ty::Unknown, Ty::Integer)
+            }
+            None => Ty::Unknown,
+        }
+    }
+
+    /// Lowers the type arguments of a path to exactly `count` types, filling
+    /// in the missing ones with `Ty::Unknown`.
+    pub fn lower_type_args(&self, args: &[TypeRef], count: usize) -> Box<[Ty]> {
+        (0..count).map(|idx| args.get(idx).map_or(Ty::Unknown, |it| self.lower_ty(it))).collect()
+    }
+}
+
+pub(crate) fn fn_sig_query(db: &dyn HirDatabase, id: FunctionId) -> Arc<FnSig> {
+    let loc = id.lookup(db.upcast());
+    let item_tree = loc.id.item_tree(db.upcast());
+    let fun = &item_tree[loc.id.value];
+    let resolver = id.resolver(db.upcast());
+    let ctx = TyLoweringContext::new(db, &resolver);
+    Arc::new(FnSig {
+        type_params: fun.type_params.len(),
+        params: fun.params.iter().map(|it| ctx.lower_ty(&it.type_ref)).collect(),
+        ret: ctx.lower_ty(&fun.ret_type),
+    })
+}
+
+pub(crate) fn field_types_query(db: &dyn HirDatabase, id: StructId) -> Arc<[(Name, Ty)]> {
+    let loc = id.lookup(db.upcast());
+    let item_tree = loc.id.item_tree(db.upcast());
+    let resolver = id.resolver(db.upcast());
+    let ctx = TyLoweringContext::new(db, &resolver);
+    item_tree[loc.id.value]
+        .fields
+        .iter()
+        .map(|field| (field.name.clone(), ctx.lower_ty(&field.type_ref)))
+        .collect()
+}
+
+pub(crate) fn const_ty_query(db: &dyn HirDatabase, id: ConstId) -> Ty {
+    let loc = id.lookup(db.upcast());
+    let item_tree = loc.id.item_tree(db.upcast());
+    let resolver = id.resolver(db.upcast());
+    TyLoweringContext::new(db, &resolver).lower_ty(&item_tree[loc.id.value].type_ref)
+}
@@ -0,0 +1,40 @@
+//! Database used for testing `hir_ty`.
+use std::fmt;
+
+use base_db::{salsa, salsa::Durability, SourceDatabase, Upcast};
+use hir_def::db::DefDatabase;
+
+#[salsa::database(
+    base_db::SourceDatabaseStorage,
+    hir_def::db::InternDatabaseStorage,
+    hir_def::db::DefDatabaseStorage,
+    crate::db::HirDatabaseStorage
+)]
+pub(crate) struct TestDB {
+    storage: salsa::Storage<TestDB>,
+}
+
+impl Default for TestDB {
+    fn default() -> TestDB {
+        let mut db = TestDB { storage: Default::default() };
+        db.set_local_roots_with_durability(Default::default(), Durability::HIGH);
+        db.set_library_roots_with_durability(Default::default(), Durability::HIGH);
+        db.set_dialect_with_durability(Default::default(), Durability::HIGH);
+        db.set_named_addresses_with_durability(Default::default(), Durability::HIGH);
+        db
+    }
+}
+
+impl salsa::Database for TestDB {}
+
+impl fmt::Debug for TestDB {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TestDB").finish()
+    }
+}
+
+impl Upcast<dyn DefDatabase> for TestDB {
+    fn upcast(&self) -> &(dyn DefDatabase + 'static) {
+        self
+    }
+}
@@ -0,0 +1,401 @@
+use base_db::{fixture::WithFixture, SourceDatabase};
+use hir_def::InFile;
+use syntax::ast::{self, AstNode};
+use test_utils::extract_annotations;
+
+use crate::{diagnostics::file_diagnostics, test_db::TestDB, type_of_expr, type_of_pat};
+
+/// Checks the `//^^^ type` annotations of the fixture against the inferred
+/// types of the patterns and expressions they point to.
+#[track_caller]
+fn check_types(ra_fixture: &str) {
+    let (db, files) = TestDB::with_many_files(ra_fixture);
+    let mut checked = 0;
+    for file_id in files {
+        let root = db.parse(file_id).syntax_node();
+        for (range, expected) in extract_annotations(&db.file_text(file_id)) {
+            let ty = root
+                .covering_element(range)
+                .ancestors()
+                .take_while(|it| it.text_range() == range)
+                .find_map(|it| {
+                    if let Some(pat) = ast::Pat::cast(it.clone()) {
+                        return type_of_pat(&db, InFile::new(file_id, &pat));
+                    }
+                    let expr = ast::Expr::cast(it)?;
+                    type_of_expr(&db, InFile::new(file_id, &expr))
+                })
+                .unwrap_or_else(|| panic!("no expression or pattern at {:?}", range));
+            assert_eq!(ty.display(&db).to_string(), expected, "at {:?}", range);
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "no annotations");
+}
+
+/// Checks that inference doesn't report anything for the fixture.
+#[track_caller]
+fn check_no_diagnostics(ra_fixture: &str) {
+    let (db, files) = TestDB::with_many_files(ra_fixture);
+    for file_id in files {
+        assert_eq!(file_diagnostics(&db, file_id), Vec::new());
+    }
+}
+
+#[test]
+fn literals_and_locals() {
+    check_types(
+        r#"
+module 0x1::M {
+    fun f(a: u8, flag: bool) {
+        let x = 1;
+          //^ u64
+        let y = 2u128;
+          //^ u128
+        let s = b"hello";
+          //^ vector<u8>
+        let addr = @0x1;
+          //^^^^ address
+        let z = a;
+          //^ u8
+        let t = !flag;
+          //^ bool
+        let v = vector[1, 2];
+          //^ vector<u64>
+        let u = ();
+          //^ ()
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn integer_literals_are_inferred_from_their_uses() {
+    check_types(
+        r#"
+module 0x1::M {
+    fun take(x: u8) {}
+    fun f(a: u128) {
+        let x = 1;
+          //^ u8
+        take(x);
+        let y = 1 + a;
+          //^ u128
+        let z = 255;
+        let w: u8 = z;
+        z;
+      //^ u8
+        let shifted = a << 2;
+          //^^^^^^^ u128
+        let cmp = 1 < 2;
+          //^^^ bool
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn casts() {
+    check_types(
+        r#"
+module 0x1::M {
+    fun f(a: u8): u128 {
+        let x = (a as u64);
+          //^ u64
+        let y = (1 as u8);
+          //^ u8
+        (x as u128)
+      //^^^^^^^^^^^ u128
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn references() {
+    check_types(
+        r#"
+module 0x1::M {
+    struct S has drop { f: u64 }
+    fun f(s: S, r: &mut S) {
+        let a = &s;
+          //^ &S
+        let b = &mut s;
+          //^ &mut S
+        let c = &s.f;
+          //^ &u64
+        let d = *c;
+          //^ u64
+        let e = r.f;
+          //^ u64
+        let g: &S = r;
+        g;
+      //^ &S
+        let h = freeze(r);
+          //^ &S
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn tuples() {
+    check_types(
+        r#"
+module 0x1::M {
+    fun pair(): (u64, bool) { (1, true) }
+    fun f() {
+        let (a, b) = pair();
+           //^ u64
+              //^ bool
+        let t = (1u8, @0x1, (true, false));
+          //^ (u8, address, (bool, bool))
+        let (x, _) = (1u8, 2);
+           //^ u8
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn struct_pack_and_unpack() {
+    check_types(
+        r#"
+module 0x1::M {
+    struct Coin<phantom T> has store { value: u64 }
+    struct Box<T> has drop { item: T }
+    struct USD {}
+    fun f(c: Coin<USD>) {
+        let Coin { value } = c;
+                 //^^^^^ u64
+        let b = Box { item: 1u8 };
+          //^ Box<u8>
+        let Box { item: i } = b;
+                      //^ u8
+        let c2 = Coin<USD> { value: 0 };
+          //^^ Coin<USD>
+        let r = &b;
+        let Box { item: j } = r;
+                      //^ &u8
+        b.item;
+      //^^^^^^ u8
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn generic_calls_with_explicit_type_arguments() {
+    check_types(
+        r#"
+//- /sources/Vector.move
+module 0x1::Vector {
+    native public fun empty<E>(): vector<E>;
+    native public fun push_back<E>(v: &mut vector<E>, e: E);
+    native public fun borrow<E>(v: &vector<E>, i: u64): &E;
+}
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector;
+    fun f() {
+        let v = Vector::empty<u64>();
+          //^ vector<u64>
+        let e = Vector::borrow(&v, 0);
+          //^ &u64
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn generic_calls_inferred_from_uses() {
+    check_types(
+        r#"
+//- /sources/Vector.move
+module 0x1::Vector {
+    native public fun empty<E>(): vector<E>;
+    native public fun push_back<E>(v: &mut vector<E>, e: E);
+}
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector;
+    fun id<T>(x: T): T { x }
+                       //^ T
+    fun f(): vector<u8> {
+        let v = Vector::empty();
+          //^ vector<u8>
+        Vector::push_back(&mut v, 1);
+        let w = Vector::empty();
+          //^ vector<bool>
+        Vector::push_back(&mut w, true);
+        let x = id(@0x1);
+          //^ address
+        let y;
+          //^ u128
+        y = 1u128;
+        v
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn global_storage_builtins() {
+    check_types(
+        r#"
+module 0x1::M {
+    struct R has key { value: u64 }
+    fun f(s: &signer): u64 acquires R {
+        let e = exists<R>(@0x1);
+          //^ bool
+        let r = borrow_global<R>(@0x1);
+          //^ &R
+        let m = borrow_global_mut<R>(@0x1);
+          //^ &mut R
+        let R { value } = move_from(@0x1);
+              //^^^^^ u64
+        move_to(s, R { value: 1 });
+        value
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn control_flow() {
+    check_types(
+        r#"
+module 0x1::M {
+    fun f(c: bool): u64 {
+        let x = if (c) 1 else 2u8;
+          //^ u8
+        let y = if (c) { 1 } else { abort 1 };
+          //^ u64
+        let z = loop { break };
+          //^ ()
+        while (c) { };
+        if (c) return 1;
+        return 2
+    }
+    fun g(): u64 {
+        abort 0
+    }
+    fun h(c: bool): u64 {
+        if (c) { return 1; } else { return 2; };
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn constants_and_type_params() {
+    check_types(
+        r#"
+module 0x1::M {
+    const MAX: u64 = 10;
+    fun f<T: copy>(x: &T): T {
+        let m = MAX;
+          //^ u64
+        let t = *x;
+          //^ T
+        t
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn well_typed_code_has_no_diagnostics() {
+    check_no_diagnostics(
+        r#"
+//- /sources/Vector.move
+module 0x1::Vector {
+    native public fun empty<E>(): vector<E>;
+    native public fun length<E>(v: &vector<E>): u64;
+    native public fun push_back<E>(v: &mut vector<E>, e: E);
+}
+//- /sources/Coin.move
+module 0x1::Coin {
+    use 0x1::Vector;
+    struct Coin<phantom T> has key, store { value: u64 }
+    struct Pool<T> has key { coins: vector<T>, total: u128 }
+
+    public fun zero<T>(): Coin<T> { Coin<T> { value: 0 } }
+
+    public fun value<T>(c: &Coin<T>): u64 { c.value }
+
+    public fun merge<T>(c: &mut Coin<T>, other: Coin<T>) {
+        let Coin { value } = other;
+        c.value = c.value + value;
+    }
+
+    public fun publish<T: store>(s: &signer, coins: vector<T>) {
+        let total = (Vector::length(&coins) as u128);
+        move_to(s, Pool { coins, total });
+    }
+
+    public fun add<T: store>(addr: address, coin: T) acquires Pool {
+        let pool = borrow_global_mut<Pool<T>>(addr);
+        Vector::push_back(&mut pool.coins, coin);
+        pool.total = pool.total + 1;
+        assert(pool.total < 100, 1);
+    }
+
+    fun count(n: u64): u64 {
+        let i = 0;
+        let sum = 0;
+        while (i < n) {
+            if (i % 2 == 0) { i = i + 1; continue };
+            sum = sum + i;
+            i = i + 1;
+        };
+        loop {
+            if (sum > 10) break;
+            sum = sum + 1;
+        };
+        sum
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn broken_code_does_not_panic() {
+    let (db, files) = TestDB::with_many_files(
+        r#"
+module 0x1::M {
+    struct S { f: u64 }
+    fun f(x: u64, ): Unknown {
+        let S { f, g } = x;
+        let y = x.missing.other;
+        let z = (x as );
+        let w = Foo::bar<u8, u64>(1, 2);
+        let (a, b, c) = (1, 2);
+        *x = ;
+        f = 1 +;
+        S { f: 1, f: 2 };
+        loop { break; continue };
+        if () else ;
+        return
+    }
+    fun g<T>(): T { g<T, T>() }
+    native fun h();
+}
+"#,
+    );
+    for file_id in files {
+        file_diagnostics(&db, file_id);
+    }
+}
@@ -1,5 +1,6 @@
 mod apply_change;
 mod line_index;
+pub mod source_change;
 
 pub use line_index::{LineCol, LineColUtf16, LineIndex};
 
@@ -9,9 +10,17 @@ use std::{fmt, mem::ManuallyDrop};
 
 pub use base_db;
 use base_db::salsa::Durability;
-use base_db::SourceDatabase;
+use base_db::{SourceDatabase, Upcast};
+pub use hir_def;
+pub use hir_ty;
 
-#[salsa::database(base_db::SourceDatabaseStorage, LineIndexDatabaseStorage)]
+#[salsa::database(
+    base_db::SourceDatabaseStorage,
+    LineIndexDatabaseStorage,
+    hir_def::db::InternDatabaseStorage,
+    hir_def::db::DefDatabaseStorage,
+    hir_ty::db::HirDatabaseStorage
+)]
 pub struct RootDatabase {
     // We use `ManuallyDrop` here because every codegen unit that contains a
     // `&RootDatabase -> &dyn OtherDatabase` cast will instantiate its drop glue in the vtable,
@@ -36,6 +45,12 @@ impl fmt::Debug for RootDatabase {
 
 impl salsa::Database for RootDatabase {}
 
+impl Upcast<dyn hir_def::db::DefDatabase> for RootDatabase {
+    fn upcast(&self) -> &(dyn hir_def::db::DefDatabase + 'static) {
+        self
+    }
+}
+
 impl Default for RootDatabase {
     fn default() -> RootDatabase {
         RootDatabase::new(None)
@@ -47,6 +62,8 @@ impl RootDatabase {
         let mut db = RootDatabase { storage: ManuallyDrop::new(salsa::Storage::default()) };
         db.set_local_roots_with_durability(Default::default(), Durability::HIGH);
         db.set_library_roots_with_durability(Default::default(), Durability::HIGH);
+        db.set_dialect_with_durability(Default::default(), Durability::HIGH);
+        db.set_named_addresses_with_durability(Default::default(), Durability::HIGH);
         db.update_lru_capacity(lru_capacity);
         db
     }
@@ -0,0 +1,35 @@
+//! Edits of several source files, like the ones made by the fixes of
+//! diagnostics.
+use base_db::FileId;
+use rustc_hash::FxHashMap;
+use text_edit::Indel;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceChange {
+    /// The edits of each file. They refer to offsets in the original text,
+    /// and don't overlap.
+    pub source_file_edits: FxHashMap<FileId, Vec<Indel>>,
+}
+
+impl SourceChange {
+    pub fn from_edit(file_id: FileId, edit: Indel) -> SourceChange {
+        let mut res = SourceChange::default();
+        res.insert_edit(file_id, edit);
+        res
+    }
+
+    pub fn insert_edit(&mut self, file_id: FileId, edit: Indel) {
+        self.source_file_edits.entry(file_id).or_default().push(edit);
+    }
+
+    /// Applies the edits of `file_id` to its text.
+    pub fn apply(&self, file_id: FileId, text: &mut String) {
+        let mut edits: Vec<_> =
+            self.source_file_edits.get(&file_id).into_iter().flatten().collect();
+        // From the end, so that the offsets of the other edits stay valid.
+        edits.sort_by_key(|it| std::cmp::Reverse(it.delete.start()));
+        for edit in edits {
+            edit.apply(text);
+        }
+    }
+}
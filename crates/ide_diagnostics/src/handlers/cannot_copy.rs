@@ -0,0 +1,98 @@
+use ide_db::hir_def::InFile;
+use ide_db::hir_ty::diagnostics::AbilityFix;
+use ide_db::RootDatabase;
+use syntax::SyntaxNodePtr;
+
+use crate::{add_ability_fix, Diagnostic};
+
+// Diagnostic: cannot-copy
+//
+// This diagnostic is triggered if a value of a type without the `copy`
+// ability is copied, by `copy x`, or by reading it through a reference or
+// from a field.
+pub(crate) fn cannot_copy(
+    db: &RootDatabase,
+    node: &InFile<SyntaxNodePtr>,
+    ty: &str,
+    fix: Option<&AbilityFix>,
+) -> Diagnostic {
+    let range = node.value.text_range();
+    Diagnostic::new(
+        "cannot-copy",
+        format!("cannot copy a value of type `{}` without the `copy` ability", ty),
+        range,
+    )
+    .with_fixes(fix.and_then(|fix| add_ability_fix(db, fix, range)).map(|it| vec![it]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix};
+
+    #[test]
+    fn cannot_copy() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct Coin has store, drop { value: u64 }
+    struct Wallet has drop { coin: Coin, count: u64 }
+    fun f(w: Wallet, r: &Coin): Coin {
+        let a = copy w;
+              //^^^^^^ error: cannot copy a value of type `Wallet` without the `copy` ability
+        let b = *r;
+              //^^ error: cannot copy a value of type `Coin` without the `copy` ability
+        let c = w.coin;
+              //^^^^^^ error: cannot copy a value of type `Coin` without the `copy` ability
+        let d = w.count;
+        let e = &w.coin;
+        let f = w.coin.value;
+        let Wallet { coin, count: _ } = w;
+        coin
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_copy_to_struct() {
+        check_fix(
+            r#"
+module 0x1::M {
+    struct Coin has drop { value: u64 }
+    fun f(r: &Coin): Coin {
+        *r$0
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct Coin has drop, copy { value: u64 }
+    fun f(r: &Coin): Coin {
+        *r
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_copy_bound() {
+        check_fix(
+            r#"
+module 0x1::M {
+    fun f<T>(r: &T): T {
+        *r$0
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    fun f<T: copy>(r: &T): T {
+        *r
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use ide_db::hir_def::InFile;
+use ide_db::hir_ty::diagnostics::AbilityFix;
+use ide_db::RootDatabase;
+use syntax::SyntaxNodePtr;
+
+use crate::{add_ability_fix, Diagnostic};
+
+// Diagnostic: cannot-drop
+//
+// This diagnostic is triggered if a value of a type without the `drop`
+// ability is dropped: discarded by an expression statement or a `_` pattern,
+// overwritten by an assignment, or left in a local which is never moved out
+// of.
+pub(crate) fn cannot_drop(
+    db: &RootDatabase,
+    node: &InFile<SyntaxNodePtr>,
+    ty: &str,
+    fix: Option<&AbilityFix>,
+) -> Diagnostic {
+    let range = node.value.text_range();
+    Diagnostic::new(
+        "cannot-drop",
+        format!("cannot drop a value of type `{}` without the `drop` ability", ty),
+        range,
+    )
+    .with_fixes(fix.and_then(|fix| add_ability_fix(db, fix, range)).map(|it| vec![it]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix};
+
+    #[test]
+    fn cannot_drop() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct R { value: u64 }
+    struct D has drop { r: u64 }
+    fun make(): R { R { value: 0 } }
+    fun consume(r: R) { let R { value: _ } = r; }
+    fun f(r: &mut R, unused: R, moved: R, d: D) {
+                   //^^^^^^ error: cannot drop a value of type `R` without the `drop` ability
+        make();
+      //^^^^^^ error: cannot drop a value of type `R` without the `drop` ability
+        let _ = make();
+          //^ error: cannot drop a value of type `R` without the `drop` ability
+        *r = make();
+      //^^ error: cannot drop a value of type `R` without the `drop` ability
+        r.value = 1;
+        let local = make();
+          //^^^^^ error: cannot drop a value of type `R` without the `drop` ability
+        let borrowed = &local;
+        consume(moved);
+        if (true) { make() } else { make() };
+                  //^^^^^^ error: cannot drop a value of type `R` without the `drop` ability
+                                  //^^^^^^ error: cannot drop a value of type `R` without the `drop` ability
+    }
+    native fun g(r: R);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generic_values() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct Box<T> has drop { item: T }
+    fun f<T, U: drop>(t: T, u: U, b: Box<T>, c: Box<U>, v: vector<T>) {}
+                    //^ error: cannot drop a value of type `T` without the `drop` ability
+                                //^ error: cannot drop a value of type `Box<T>` without the `drop` ability
+                                                      //^ error: cannot drop a value of type `vector<T>` without the `drop` ability
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_drop_to_struct() {
+        check_fix(
+            r#"
+module 0x1::M {
+    struct R has store { value: u64 }
+    fun f() {
+        R { value: 0 }$0;
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct R has store, drop { value: u64 }
+    fun f() {
+        R { value: 0 };
+    }
+}
+"#,
+        );
+        check_fix(
+            r#"
+module 0x1::M {
+    struct R<T> { value: T }
+    fun f(r$0: R<u8>) {}
+}
+"#,
+            r#"
+module 0x1::M {
+    struct R<T> has drop { value: T }
+    fun f(r: R<u8>) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_drop_bound() {
+        check_fix(
+            r#"
+module 0x1::M {
+    struct Box<T> has drop { item: T }
+    fun f<T: copy>(b$0: Box<T>) {}
+}
+"#,
+            r#"
+module 0x1::M {
+    struct Box<T> has drop { item: T }
+    fun f<T: copy + drop>(b: Box<T>) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_drop_to_struct_in_other_file() {
+        check_fix(
+            r#"
+//- /sources/Coin.move
+module 0x1::Coin {
+    struct Coin { value: u64 }
+    public fun zero(): Coin { Coin { value: 0 } }
+}
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Coin;
+    fun f() {
+        Coin::zero()$0;
+    }
+}
+"#,
+            r#"
+//- /sources/Coin.move
+module 0x1::Coin {
+    struct Coin has drop { value: u64 }
+    public fun zero(): Coin { Coin { value: 0 } }
+}
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Coin;
+    fun f() {
+        Coin::zero();
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use ide_db::hir_def::InFile;
+use syntax::SyntaxNodePtr;
+
+use crate::Diagnostic;
+
+// Diagnostic: cannot-infer-type
+//
+// This diagnostic is triggered if nothing constrains the type of a local, or
+// the type arguments of a call, a struct literal or a vector.
+pub(crate) fn cannot_infer_type(node: &InFile<SyntaxNodePtr>) -> Diagnostic {
+    Diagnostic::new("cannot-infer-type", "type annotations needed", node.value.text_range())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn cannot_infer_type() {
+        check_diagnostics(
+            r#"
+//- /sources/Vector.move
+module 0x1::Vector {
+    native public fun empty<E>(): vector<E>;
+    native public fun destroy_empty<E>(v: vector<E>);
+}
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector;
+    fun f() {
+        let v = Vector::empty();
+          //^ error: type annotations needed
+        Vector::destroy_empty(Vector::empty());
+      //^^^^^^^^^^^^^^^^^^^^^ error: type annotations needed
+        let w = Vector::empty<u8>();
+        let x = Vector::empty();
+        Vector::destroy_empty<u64>(x);
+    }
+}
+"#,
+        );
+    }
+}
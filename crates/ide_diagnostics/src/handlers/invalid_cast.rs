@@ -0,0 +1,37 @@
+use ide_db::hir_def::InFile;
+use syntax::SyntaxNodePtr;
+
+use crate::Diagnostic;
+
+// Diagnostic: invalid-cast
+//
+// This diagnostic is triggered if the type of a cast isn't an integer type.
+pub(crate) fn invalid_cast(type_ref: &InFile<SyntaxNodePtr>, ty: &str) -> Diagnostic {
+    Diagnostic::new(
+        "invalid-cast",
+        format!("cannot cast to `{}`, only to integer types", ty),
+        type_ref.value.text_range(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn invalid_cast() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    fun f(a: u8) {
+        (a as u128);
+        (a as bool);
+            //^^^^ error: cannot cast to `bool`, only to integer types
+        (true as u64);
+       //^^^^ error: expected {integer}, found bool
+    }
+}
+"#,
+        );
+    }
+}
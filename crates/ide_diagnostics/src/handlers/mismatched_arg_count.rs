@@ -0,0 +1,49 @@
+use ide_db::hir_def::InFile;
+use syntax::SyntaxNodePtr;
+
+use crate::Diagnostic;
+
+// Diagnostic: mismatched-arg-count
+//
+// This diagnostic is triggered if a function is called with the wrong number
+// of arguments.
+pub(crate) fn mismatched_arg_count(
+    call: &InFile<SyntaxNodePtr>,
+    expected: usize,
+    found: usize,
+) -> Diagnostic {
+    let s = if expected == 1 { "" } else { "s" };
+    Diagnostic::new(
+        "mismatched-arg-count",
+        format!("expected {} argument{}, found {}", expected, s, found),
+        call.value.text_range(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn mismatched_arg_count() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    fun zero() {}
+    fun one(a: u64) {}
+    fun two(a: u64, b: u64) {}
+    struct R has key {}
+    fun f() {
+        zero(1);
+      //^^^^^^^ error: expected 0 arguments, found 1
+        one();
+      //^^^^^ error: expected 1 argument, found 0
+        two(1, 2);
+        exists<R>();
+      //^^^^^^^^^^^ error: expected 1 argument, found 0
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use ide_db::base_db::SourceDatabase;
+use ide_db::hir_def::InFile;
+use ide_db::source_change::SourceChange;
+use ide_db::RootDatabase;
+use syntax::{
+    ast::{self, AstNode},
+    AstPtr, SyntaxNodePtr,
+};
+use text_edit::Indel;
+
+use crate::{Diagnostic, Fix};
+
+// Diagnostic: missing-acquires
+//
+// This diagnostic is triggered if a function calls `borrow_global`,
+// `borrow_global_mut` or `move_from` with a struct of its module, or calls a
+// function of its module which acquires one, without listing the struct in
+// its `acquires` list.
+pub(crate) fn missing_acquires(
+    db: &RootDatabase,
+    call: &InFile<SyntaxNodePtr>,
+    name: &str,
+    function: &InFile<AstPtr<ast::FunctionDef>>,
+) -> Diagnostic {
+    let range = call.value.text_range();
+    Diagnostic::new(
+        "missing-acquires",
+        format!("`{}` is acquired here, but is missing from the `acquires` list", name),
+        range,
+    )
+    .with_fixes(fix(db, name, function).map(|source_change| {
+        let label = format!("Add `{}` to the `acquires` list", name);
+        vec![Fix { label, source_change, target: range }]
+    }))
+}
+
+fn fix(
+    db: &RootDatabase,
+    name: &str,
+    function: &InFile<AstPtr<ast::FunctionDef>>,
+) -> Option<SourceChange> {
+    let root = db.parse(function.file_id).syntax_node();
+    let function_node = function.value.to_node(&root);
+    let edit = match function_node.acquires_list() {
+        Some(list) => match list.paths().last() {
+            Some(last) => Indel::insert(last.syntax().text_range().end(), format!(", {}", name)),
+            None => Indel::insert(list.syntax().text_range().end(), format!(" {}", name)),
+        },
+        None => {
+            let anchor = match function_node.ret_type() {
+                Some(it) => it.syntax().text_range(),
+                None => function_node.param_list()?.syntax().text_range(),
+            };
+            Indel::insert(anchor.end(), format!(" acquires {}", name))
+        }
+    };
+    Some(SourceChange::from_edit(function.file_id, edit))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix};
+
+    #[test]
+    fn missing_acquires() {
+        check_diagnostics(
+            r#"
+//- /sources/Other.move
+module 0x1::Other {
+    struct O has key { value: u64 }
+    public fun value(): u64 acquires O { borrow_global<O>(@0x1).value }
+}
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Other;
+    struct R has key, drop { value: u64 }
+    struct S has key, drop { value: u64 }
+    fun direct(): u64 {
+        let r = borrow_global<R>(@0x1);
+              //^^^^^^^^^^^^^^^^ error: `R` is acquired here, but is missing from the `acquires` list
+        let s = borrow_global_mut<S>(@0x1);
+              //^^^^^^^^^^^^^^^^^^^^ error: `S` is acquired here, but is missing from the `acquires` list
+        r.value + s.value
+    }
+    fun listed(): R acquires R {
+        move_from<R>(@0x1)
+    }
+    fun transitive(): R {
+        listed()
+      //^^^^^^ error: `R` is acquired here, but is missing from the `acquires` list
+    }
+    fun other_module(): u64 {
+        exists<R>(@0x1);
+        Other::value()
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn native_functions() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    native fun h() acquires R;
+    fun f() {
+        h()
+      //^ error: `R` is acquired here, but is missing from the `acquires` list
+    }
+    fun g() acquires R {
+        h()
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn recursive_calls() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    fun a(n: u64): u64 acquires R {
+        if (n == 0) return borrow_global<R>(@0x1).value;
+        b(n - 1)
+    }
+    fun b(n: u64): u64 {
+        a(n)
+      //^ error: `R` is acquired here, but is missing from the `acquires` list
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_to_acquires_list() {
+        check_fix(
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    struct S has key, drop { value: u64 }
+    fun f(): u64 acquires R {
+        borrow_global<R>(@0x1).value + borrow_global<S>$0(@0x1).value
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    struct S has key, drop { value: u64 }
+    fun f(): u64 acquires R, S {
+        borrow_global<R>(@0x1).value + borrow_global<S>(@0x1).value
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_acquires_list() {
+        check_fix(
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    fun f(): u64 {
+        borrow_global<R>$0(@0x1).value
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    fun f(): u64 acquires R {
+        borrow_global<R>(@0x1).value
+    }
+}
+"#,
+        );
+        check_fix(
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    fun g(addr: address) {
+        move_from<R>$0(addr);
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    fun g(addr: address) acquires R {
+        move_from<R>(addr);
+    }
+}
+"#,
+        );
+    }
+}
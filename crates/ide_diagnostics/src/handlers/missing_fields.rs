@@ -0,0 +1,39 @@
+use ide_db::hir_def::InFile;
+use syntax::SyntaxNodePtr;
+
+use crate::Diagnostic;
+
+// Diagnostic: missing-fields
+//
+// This diagnostic is triggered if a struct literal or pattern doesn't list
+// all the fields of the struct.
+pub(crate) fn missing_fields(node: &InFile<SyntaxNodePtr>, fields: &[String]) -> Diagnostic {
+    let fields = fields.iter().map(|it| format!("`{}`", it)).collect::<Vec<_>>().join(", ");
+    Diagnostic::new(
+        "missing-fields",
+        format!("missing structure fields: {}", fields),
+        node.value.text_range(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn missing_fields() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct S has drop { a: u64, b: bool, c: address }
+    fun f(s: S) {
+        S { a: 1 };
+      //^^^^^^^^^^ error: missing structure fields: `b`, `c`
+        let S { a, b } = s;
+          //^^^^^^^^^^ error: missing structure fields: `c`
+    }
+}
+"#,
+        );
+    }
+}
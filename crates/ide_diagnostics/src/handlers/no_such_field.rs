@@ -0,0 +1,43 @@
+use ide_db::hir_def::InFile;
+use syntax::SyntaxNodePtr;
+
+use crate::Diagnostic;
+
+// Diagnostic: no-such-field
+//
+// This diagnostic is triggered if a field is accessed, initialized or
+// destructured on a type which doesn't have it.
+pub(crate) fn no_such_field(node: &InFile<SyntaxNodePtr>, field: &str, ty: &str) -> Diagnostic {
+    Diagnostic::new(
+        "no-such-field",
+        format!("no field `{}` on type `{}`", field, ty),
+        node.value.text_range(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn no_such_field() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct S has drop { f: u64 }
+    fun f(s: S, r: &S) {
+        s.g;
+        //^ error: no field `g` on type `S`
+        r.f;
+        r.g;
+        //^ error: no field `g` on type `S`
+        S { f: 1, g: 2 };
+                //^^^^ error: no field `g` on type `S`
+        let S { f, g } = s;
+                 //^ error: no field `g` on type `S`
+    }
+}
+"#,
+        );
+    }
+}
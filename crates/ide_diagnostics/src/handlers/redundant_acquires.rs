@@ -0,0 +1,157 @@
+use ide_db::base_db::SourceDatabase;
+use ide_db::hir_def::InFile;
+use ide_db::source_change::SourceChange;
+use ide_db::RootDatabase;
+use syntax::{
+    ast::{self, AstNode},
+    AstPtr, SyntaxKind, TextRange,
+};
+use text_edit::Indel;
+
+use crate::{Diagnostic, Fix};
+
+// Diagnostic: redundant-acquires
+//
+// This diagnostic is triggered if the `acquires` list of a function has a
+// struct which the function doesn't acquire, or has a struct twice.
+pub(crate) fn redundant_acquires(
+    db: &RootDatabase,
+    path: &InFile<AstPtr<ast::Path>>,
+    name: &str,
+    is_duplicate: bool,
+) -> Diagnostic {
+    let range = path.value.text_range();
+    let message = if is_duplicate {
+        format!("`{}` is listed more than once in the `acquires` list", name)
+    } else {
+        format!("`{}` is not acquired by the function", name)
+    };
+    Diagnostic::new("redundant-acquires", message, range).with_fixes(fix(db, path).map(
+        |source_change| {
+            let label = format!("Remove `{}` from the `acquires` list", name);
+            vec![Fix { label, source_change, target: range }]
+        },
+    ))
+}
+
+fn fix(db: &RootDatabase, path: &InFile<AstPtr<ast::Path>>) -> Option<SourceChange> {
+    let root = db.parse(path.file_id).syntax_node();
+    let path_node = path.value.to_node(&root);
+    let list = ast::AcquiresList::cast(path_node.syntax().parent()?)?;
+    let paths: Vec<_> = list.paths().collect();
+    let idx = paths.iter().position(|it| *it == path_node)?;
+    let range = if paths.len() == 1 {
+        // Remove the whole list, with the whitespace before it.
+        let list_range = list.syntax().text_range();
+        match list.syntax().prev_sibling_or_token() {
+            Some(it) if it.kind() == SyntaxKind::WHITESPACE => {
+                TextRange::new(it.text_range().start(), list_range.end())
+            }
+            _ => list_range,
+        }
+    } else if idx + 1 < paths.len() {
+        let next = paths[idx + 1].syntax().text_range();
+        TextRange::new(path_node.syntax().text_range().start(), next.start())
+    } else {
+        let prev = paths[idx - 1].syntax().text_range();
+        TextRange::new(prev.end(), path_node.syntax().text_range().end())
+    };
+    Some(SourceChange::from_edit(path.file_id, Indel::delete(range)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix};
+
+    #[test]
+    fn redundant_acquires() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    struct S has key, drop { value: u64 }
+    fun f(): u64 acquires R, S, R {
+                           //^ error: `S` is not acquired by the function
+                              //^ error: `R` is listed more than once in the `acquires` list
+        borrow_global<R>(@0x1).value
+    }
+    fun g(): bool acquires R {
+                         //^ error: `R` is not acquired by the function
+        exists<R>(@0x1)
+    }
+    native fun h() acquires R;
+    fun i(): u64 acquires R {
+        h();
+        0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_from_acquires_list() {
+        check_fix(
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    struct S has key, drop { value: u64 }
+    fun f(): u64 acquires S$0, R {
+        borrow_global<R>(@0x1).value
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    struct S has key, drop { value: u64 }
+    fun f(): u64 acquires R {
+        borrow_global<R>(@0x1).value
+    }
+}
+"#,
+        );
+        check_fix(
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    struct S has key, drop { value: u64 }
+    fun f(): u64 acquires R, S$0 {
+        borrow_global<R>(@0x1).value
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    struct S has key, drop { value: u64 }
+    fun f(): u64 acquires R {
+        borrow_global<R>(@0x1).value
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_acquires_list() {
+        check_fix(
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    fun f(): bool acquires R$0 {
+        exists<R>(@0x1)
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct R has key, drop { value: u64 }
+    fun f(): bool {
+        exists<R>(@0x1)
+    }
+}
+"#,
+        );
+    }
+}
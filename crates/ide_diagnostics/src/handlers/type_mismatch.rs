@@ -0,0 +1,67 @@
+use ide_db::hir_def::InFile;
+use syntax::SyntaxNodePtr;
+
+use crate::Diagnostic;
+
+// Diagnostic: type-mismatch
+//
+// This diagnostic is triggered if the type of an expression or a pattern
+// isn't the one its position requires.
+pub(crate) fn type_mismatch(
+    node: &InFile<SyntaxNodePtr>,
+    expected: &str,
+    actual: &str,
+) -> Diagnostic {
+    Diagnostic::new(
+        "type-mismatch",
+        format!("expected {}, found {}", expected, actual),
+        node.value.text_range(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn type_mismatch() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct S has drop { f: u64 }
+    fun take(s: &S) {}
+    fun f(s: S, flag: bool): u64 {
+        let a: u8 = true;
+                  //^^^^ error: expected u8, found bool
+        let b: u8 = 1u64;
+                  //^^^^ error: expected u8, found u64
+        take(&mut s);
+        take(s);
+           //^ error: expected &S, found S
+        if (1) {};
+          //^ error: expected bool, found {integer}
+        let (x, y) = s;
+          //^^^^^^ error: expected S, found ({unknown}, {unknown})
+        flag
+      //^^^^ error: expected u64, found bool
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_mismatch_after_unknown() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    fun f(): u64 {
+        let x = y;
+              //^ error: unresolved name `y`
+        x
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use ide_db::hir_def::InFile;
+use syntax::{ast, AstPtr};
+
+use crate::Diagnostic;
+
+// Diagnostic: unresolved-import
+//
+// This diagnostic is triggered if a `use` names a module or an item which
+// doesn't exist.
+pub(crate) fn unresolved_import(use_tree: &InFile<AstPtr<ast::UseTree>>) -> Diagnostic {
+    Diagnostic::new("unresolved-import", "unresolved import", use_tree.value.text_range())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn unresolved_import() {
+        check_diagnostics(
+            r#"
+//- /sources/Vector.move
+module 0x1::Vector {
+    public fun empty() {}
+}
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector;
+    use 0x1::Vectr;
+      //^^^^^^^^^^ error: unresolved import
+    use 0x1::Vector::{Self, empty, length};
+                                 //^^^^^^ error: unresolved import
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unresolved_import_in_script() {
+        check_diagnostics(
+            r#"
+script {
+    use Std::Signer;
+      //^^^^^^^^^^^ error: unresolved import
+    fun main() {}
+}
+"#,
+        );
+    }
+}
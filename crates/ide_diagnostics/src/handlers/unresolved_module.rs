@@ -0,0 +1,69 @@
+use ide_db::hir_def::InFile;
+use syntax::{ast, AstPtr};
+
+use crate::Diagnostic;
+
+// Diagnostic: unresolved-module
+//
+// This diagnostic is triggered if the qualifier of a path, or the path of a
+// `friend`, doesn't name a module.
+pub(crate) fn unresolved_module(path: &InFile<AstPtr<ast::Path>>, module: &str) -> Diagnostic {
+    Diagnostic::new(
+        "unresolved-module",
+        format!("unresolved module `{}`", module),
+        path.value.text_range(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn unresolved_module() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    friend 0x1::N;
+         //^^^^^^ error: unresolved module `0x1::N`
+    fun f() {
+        Vector::empty();
+      //^^^^^^ error: unresolved module `Vector`
+        0x1::Vector::empty();
+      //^^^^^^^^^^^ error: unresolved module `0x1::Vector`
+        Self::f();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn ss58_address() {
+        check_diagnostics(
+            r#"
+//- /sources/M.move
+module 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY::M {
+    public fun f() {}
+}
+//- /sources/N.move
+module 0x1::N {
+    use 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY::M;
+    fun g() { M::f(); }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn self_in_script() {
+        check_diagnostics(
+            r#"
+script {
+    fun main() { Self::main(); }
+               //^^^^ error: unresolved module `Self`
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use ide_db::hir_def::InFile;
+use syntax::SyntaxNodePtr;
+
+use crate::Diagnostic;
+
+// Diagnostic: unresolved-name
+//
+// This diagnostic is triggered if a path in an expression doesn't name a
+// local, a function, a constant or a builtin function, or if the name of
+// `move x` or `copy x` isn't a local.
+pub(crate) fn unresolved_name(node: &InFile<SyntaxNodePtr>, name: &str) -> Diagnostic {
+    Diagnostic::new(
+        "unresolved-name",
+        format!("unresolved name `{}`", name),
+        node.value.text_range(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn unresolved_name() {
+        check_diagnostics(
+            r#"
+//- /sources/Vector.move
+module 0x1::Vector {
+    public fun empty() {}
+}
+//- /sources/M.move
+module 0x1::M {
+    use 0x1::Vector;
+    const C: u64 = 1;
+    fun f(a: u64) {
+        { let b = a; };
+        b;
+      //^ error: unresolved name `b`
+        move c;
+           //^ error: unresolved name `c`
+        copy a;
+        Vector::length();
+      //^^^^^^^^^^^^^^ error: unresolved name `Vector::length`
+        Vector::empty();
+        C;
+        D;
+      //^ error: unresolved name `D`
+        exists<u64>(@0x1);
+             //^^^ error: type `u64` doesn't have the `key` ability required by `exists`
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_diagnostics_in_specs() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    fun f(): u64 { 1 }
+    spec f {
+        ensures result == old(global<u64>(@0x1));
+    }
+}
+"#,
+        );
+    }
+}
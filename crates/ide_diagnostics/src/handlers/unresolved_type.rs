@@ -0,0 +1,55 @@
+use ide_db::hir_def::InFile;
+use syntax::{ast, AstPtr};
+
+use crate::Diagnostic;
+
+// Diagnostic: unresolved-type
+//
+// This diagnostic is triggered if a path in a type, an `acquires` list, or a
+// struct literal or pattern doesn't name a struct, a type parameter or a
+// builtin type.
+pub(crate) fn unresolved_type(path: &InFile<AstPtr<ast::Path>>, name: &str) -> Diagnostic {
+    Diagnostic::new(
+        "unresolved-type",
+        format!("unresolved type `{}`", name),
+        path.value.text_range(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn unresolved_type() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct S<T> { a: T, b: u64, c: vector<Coin> }
+                                        //^^^^ error: unresolved type `Coin`
+    fun f(s: S<u8>): Self::R acquires R {
+                   //^^^^^^^ error: unresolved type `Self::R`
+                                    //^ error: unresolved type `R`
+        let R { } = s;
+          //^ error: unresolved type `R`
+        S { a: 0, b: 1, c: vector[] };
+      //^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ error: cannot drop a value of type `S<u64>` without the `drop` ability
+        abort 0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn functions_are_not_types() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    fun f(x: f) {}
+           //^ error: unresolved type `f`
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use ide_db::hir_def::{ability::Ability, InFile};
+use ide_db::hir_ty::diagnostics::AbilityFix;
+use ide_db::RootDatabase;
+use syntax::SyntaxNodePtr;
+
+use crate::{add_ability_fix, Diagnostic};
+
+// Diagnostic: unsatisfied-ability
+//
+// This diagnostic is triggered if a type argument doesn't have an ability
+// which its type parameter requires, like a struct without `key` given to
+// `move_to`, or a type without `store` given to a `T: store` parameter.
+pub(crate) fn unsatisfied_ability(
+    db: &RootDatabase,
+    node: &InFile<SyntaxNodePtr>,
+    ty: &str,
+    ability: Ability,
+    required_by: &str,
+    fix: Option<&AbilityFix>,
+) -> Diagnostic {
+    let range = node.value.text_range();
+    Diagnostic::new(
+        "unsatisfied-ability",
+        format!(
+            "type `{}` doesn't have the `{}` ability required by `{}`",
+            ty, ability, required_by
+        ),
+        range,
+    )
+    .with_fixes(fix.and_then(|fix| add_ability_fix(db, fix, range)).map(|it| vec![it]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix};
+
+    #[test]
+    fn global_storage_requires_key() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct R has store { value: u64 }
+    struct K has key { value: u64 }
+    fun f(s: &signer) acquires R, K {
+        move_to(s, R { value: 1 });
+      //^^^^^^^ error: type `R` doesn't have the `key` ability required by `move_to`
+        move_to(s, K { value: 1 });
+        let r = borrow_global<R>(@0x1);
+                            //^ error: type `R` doesn't have the `key` ability required by `borrow_global`
+        exists<u64>(@0x1);
+             //^^^ error: type `u64` doesn't have the `key` ability required by `exists`
+        let K { value: _ } = move_from<K>(@0x1);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn type_parameter_bounds() {
+        check_diagnostics(
+            r#"
+module 0x1::M {
+    struct Coin has key { value: u64 }
+    struct Box<T: store> has drop { item: T }
+    fun store<T: store + drop>(t: T) {}
+    fun f<T: drop>(c: Coin, t: T, b: bool) {
+        store(c);
+      //^^^^^ error: type `Coin` doesn't have the `drop` ability required by `store`
+      //^^^^^ error: type `Coin` doesn't have the `store` ability required by `store`
+        store<T>(t);
+            //^ error: type `T` doesn't have the `store` ability required by `store`
+        store(b);
+    }
+    fun g(c: Coin): Box<Coin> {
+        Box { item: c }
+      //^^^ error: type `Coin` doesn't have the `store` ability required by `Box`
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_key_to_struct() {
+        check_fix(
+            r#"
+module 0x1::M {
+    struct R { value: u64 }
+    fun f(s: &signer) {
+        move_to$0(s, R { value: 1 });
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct R has key { value: u64 }
+    fun f(s: &signer) {
+        move_to(s, R { value: 1 });
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_store_bound() {
+        check_fix(
+            r#"
+module 0x1::M {
+    struct Pool<T> has key { items: vector<T> }
+    fun publish<T>(s: &signer, items: vector<T>) {
+        move_to$0(s, Pool { items });
+    }
+}
+"#,
+            r#"
+module 0x1::M {
+    struct Pool<T> has key { items: vector<T> }
+    fun publish<T: store>(s: &signer, items: vector<T>) {
+        move_to(s, Pool { items });
+    }
+}
+"#,
+        );
+    }
+}
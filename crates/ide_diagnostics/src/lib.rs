@@ -25,11 +25,39 @@
 //! There are also a couple of ad-hoc diagnostics implemented directly here, we
 //! don't yet have a great pattern for how to do them properly.
 
+mod handlers {
+    pub(crate) mod cannot_copy;
+    pub(crate) mod cannot_drop;
+    pub(crate) mod cannot_infer_type;
+    pub(crate) mod invalid_cast;
+    pub(crate) mod mismatched_arg_count;
+    pub(crate) mod missing_acquires;
+    pub(crate) mod missing_fields;
+    pub(crate) mod no_such_field;
+    pub(crate) mod redundant_acquires;
+    pub(crate) mod type_mismatch;
+    pub(crate) mod unsatisfied_ability;
+    pub(crate) mod unresolved_import;
+    pub(crate) mod unresolved_module;
+    pub(crate) mod unresolved_name;
+    pub(crate) mod unresolved_type;
+}
+
+#[cfg(test)]
+mod tests;
+
 use ide_db::base_db::FileId;
 use ide_db::base_db::SourceDatabase;
+use ide_db::hir_def::diagnostics::{file_diagnostics, DefDiagnostic};
+use ide_db::hir_ty::diagnostics::{AbilityFix, TyDiagnostic};
+use ide_db::source_change::SourceChange;
 use ide_db::RootDatabase;
 use rustc_hash::FxHashSet;
-use syntax::{ast::AstNode, TextRange};
+use syntax::{
+    ast::{AstNode, HasName, HasTypeParams},
+    TextRange,
+};
+use text_edit::Indel;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DiagnosticCode(pub &'static str);
@@ -48,6 +76,16 @@ pub struct Diagnostic {
     pub severity: Severity,
     pub unused: bool,
     pub experimental: bool,
+    pub fixes: Option<Vec<Fix>>,
+}
+
+/// An edit which fixes the code a diagnostic is about.
+#[derive(Debug)]
+pub struct Fix {
+    pub label: String,
+    pub source_change: SourceChange,
+    /// The range in which the fix is offered.
+    pub target: TextRange,
 }
 
 impl Diagnostic {
@@ -60,6 +98,7 @@ impl Diagnostic {
             severity: Severity::Error,
             unused: false,
             experimental: false,
+            fixes: None,
         }
     }
 
@@ -77,6 +116,11 @@ impl Diagnostic {
         self.unused = unused;
         self
     }
+
+    fn with_fixes(mut self, fixes: Option<Vec<Fix>>) -> Diagnostic {
+        self.fixes = fixes;
+        self
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -97,5 +141,116 @@ pub fn diagnostics(db: &RootDatabase, file_id: FileId) -> Vec<Diagnostic> {
             Diagnostic::new("syntax-error", format!("Syntax Error: {}", err), err.range())
         }),
     );
+
+    for d in file_diagnostics(db, file_id) {
+        let d = match &d {
+            DefDiagnostic::UnresolvedImport { use_tree } => {
+                handlers::unresolved_import::unresolved_import(use_tree)
+            }
+            DefDiagnostic::UnresolvedModule { path, module } => {
+                handlers::unresolved_module::unresolved_module(path, module)
+            }
+            DefDiagnostic::UnresolvedType { path, name } => {
+                handlers::unresolved_type::unresolved_type(path, name)
+            }
+            DefDiagnostic::UnresolvedName { node, name } => {
+                handlers::unresolved_name::unresolved_name(node, name)
+            }
+        };
+        res.push(d);
+    }
+
+    for d in ide_db::hir_ty::diagnostics::file_diagnostics(db, file_id) {
+        let d = match &d {
+            TyDiagnostic::TypeMismatch { node, expected, actual } => {
+                handlers::type_mismatch::type_mismatch(node, expected, actual)
+            }
+            TyDiagnostic::MismatchedArgCount { call, expected, found } => {
+                handlers::mismatched_arg_count::mismatched_arg_count(call, *expected, *found)
+            }
+            TyDiagnostic::NoSuchField { node, field, ty } => {
+                handlers::no_such_field::no_such_field(node, field, ty)
+            }
+            TyDiagnostic::MissingFields { node, fields } => {
+                handlers::missing_fields::missing_fields(node, fields)
+            }
+            TyDiagnostic::InvalidCast { type_ref, ty } => {
+                handlers::invalid_cast::invalid_cast(type_ref, ty)
+            }
+            TyDiagnostic::CannotInferType { node } => {
+                handlers::cannot_infer_type::cannot_infer_type(node)
+            }
+            TyDiagnostic::CannotDrop { node, ty, fix } => {
+                handlers::cannot_drop::cannot_drop(db, node, ty, fix.as_ref())
+            }
+            TyDiagnostic::CannotCopy { node, ty, fix } => {
+                handlers::cannot_copy::cannot_copy(db, node, ty, fix.as_ref())
+            }
+            TyDiagnostic::UnsatisfiedAbility { node, ty, ability, required_by, fix } => {
+                handlers::unsatisfied_ability::unsatisfied_ability(
+                    db,
+                    node,
+                    ty,
+                    *ability,
+                    required_by,
+                    fix.as_ref(),
+                )
+            }
+            TyDiagnostic::MissingAcquires { call, name, function } => {
+                handlers::missing_acquires::missing_acquires(db, call, name, function)
+            }
+            TyDiagnostic::RedundantAcquires { path, name, is_duplicate } => {
+                handlers::redundant_acquires::redundant_acquires(db, path, name, *is_duplicate)
+            }
+        };
+        res.push(d);
+    }
     res
 }
+
+/// Adds the ability to the `has` list of the struct, or to the bounds of the
+/// type parameter.
+fn add_ability_fix(db: &RootDatabase, fix: &AbilityFix, target: TextRange) -> Option<Fix> {
+    let (file_id, label, edit) = match fix {
+        AbilityFix::Struct { strukt, name, ability } => {
+            let root = db.parse(strukt.file_id).syntax_node();
+            let strukt_node = strukt.value.to_node(&root);
+            let edit = match strukt_node.ability_list() {
+                Some(list) => match list.abilities().last() {
+                    Some(last) => {
+                        Indel::insert(last.syntax().text_range().end(), format!(", {}", ability))
+                    }
+                    None => {
+                        Indel::insert(list.syntax().text_range().end(), format!(" {}", ability))
+                    }
+                },
+                None => {
+                    let anchor = match strukt_node.generic_param_list() {
+                        Some(it) => it.syntax().text_range(),
+                        None => strukt_node.name()?.syntax().text_range(),
+                    };
+                    Indel::insert(anchor.end(), format!(" has {}", ability))
+                }
+            };
+            (strukt.file_id, format!("Add the `{}` ability to `{}`", ability, name), edit)
+        }
+        AbilityFix::TypeParam { type_param, name, ability } => {
+            let root = db.parse(type_param.file_id).syntax_node();
+            let param = type_param.value.to_node(&root);
+            let edit = match param.ability_bound_list() {
+                Some(list) => {
+                    Indel::insert(list.syntax().text_range().end(), format!(" + {}", ability))
+                }
+                None => match param.colon_token() {
+                    Some(colon) => Indel::insert(colon.text_range().end(), format!(" {}", ability)),
+                    None => Indel::insert(
+                        param.name()?.syntax().text_range().end(),
+                        format!(": {}", ability),
+                    ),
+                },
+            };
+            (type_param.file_id, format!("Add the `{}` bound to `{}`", ability, name), edit)
+        }
+    };
+    Some(Fix { label, source_change: SourceChange::from_edit(file_id, edit), target })
+}
@@ -0,0 +1,48 @@
+use ide_db::base_db::{fixture::WithFixture, SourceDatabase};
+use ide_db::RootDatabase;
+use test_utils::{assert_eq_text, extract_annotations};
+
+use crate::{diagnostics, Severity};
+
+/// Checks that the diagnostics of every file of the fixture match its
+/// `//^^^ error: message` annotations.
+#[track_caller]
+pub(crate) fn check_diagnostics(ra_fixture: &str) {
+    let (db, files) = RootDatabase::with_many_files(ra_fixture);
+    for file_id in files {
+        let expected = extract_annotations(&db.file_text(file_id));
+        let mut actual = diagnostics(&db, file_id)
+            .into_iter()
+            .map(|d| {
+                let severity = match d.severity {
+                    Severity::Error => "error",
+                    Severity::WeakWarning => "weak",
+                };
+                (d.range, format!("{}: {}", severity, d.message))
+            })
+            .collect::<Vec<_>>();
+        actual.sort_by_key(|(range, _)| range.start());
+        assert_eq!(expected, actual);
+    }
+}
+
+/// Applies the first fix of the diagnostic at the `$0` cursor, and checks
+/// that every file of the fixture then has the text of the same file of
+/// `ra_fixture_after`.
+#[track_caller]
+pub(crate) fn check_fix(ra_fixture_before: &str, ra_fixture_after: &str) {
+    let (db, position) = RootDatabase::with_position(ra_fixture_before);
+    let diagnostic = diagnostics(&db, position.file_id)
+        .into_iter()
+        .find(|d| d.fixes.is_some() && d.range.contains_inclusive(position.offset))
+        .expect("no diagnostic with a fix at the cursor");
+    let fix = &diagnostic.fixes.as_ref().unwrap()[0];
+    assert!(fix.target.contains_inclusive(position.offset));
+
+    let (db_after, files) = RootDatabase::with_many_files(ra_fixture_after);
+    for file_id in files {
+        let mut actual = db.file_text(file_id).to_string();
+        fix.source_change.apply(file_id, &mut actual);
+        assert_eq_text!(&*db_after.file_text(file_id), &*actual);
+    }
+}
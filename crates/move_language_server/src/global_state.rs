@@ -22,7 +22,7 @@ use lsp_types::{SemanticTokens, Url};
 use parking_lot::{Mutex, RwLock};
 use project_model::ProjectWorkspace;
 use rustc_hash::FxHashMap;
-use vfs::AnchoredPathBuf;
+use vfs::{AnchoredPathBuf, ChangeKind};
 
 // Enforces drop order
 pub(crate) struct Handle<H, C> {
@@ -137,7 +137,14 @@ impl GlobalState {
                 } else {
                     None
                 };
-                change.change_file(file.file_id, text);
+                // Files open in the editor change one edit at a time, and
+                // their previous parse is already there to be reparsed.
+                let is_edit = file.change_kind == ChangeKind::Modify
+                    && self.mem_docs.contains(&vfs.file_path(file.file_id));
+                match text {
+                    Some(text) if is_edit => change.edit_file(file.file_id, text),
+                    text => change.change_file(file.file_id, text),
+                }
             }
             if has_structure_changes {
                 let roots = self.source_root_config.partition(vfs);
@@ -14,6 +14,7 @@ use ide_db::base_db::FileId;
 use ide_db::base_db::SourceDatabase;
 use lsp_server::Connection;
 use lsp_types::notification::Notification;
+use project_model::ProjectWorkspace;
 use vfs::VfsPath;
 
 pub fn main_loop(config: Config, connection: lsp_server::Connection) -> Result<()> {
@@ -78,6 +79,7 @@ impl fmt::Debug for Event {
 pub(crate) enum Task {
     Response(lsp_server::Response),
     Diagnostics(Vec<(FileId, Vec<lsp_types::Diagnostic>)>),
+    FetchWorkspace(Vec<anyhow::Result<ProjectWorkspace>>),
 }
 
 impl GlobalState {
@@ -155,6 +157,7 @@ impl GlobalState {
                                 self.diagnostics.set_native_diagnostics(file_id, diagnostics)
                             }
                         }
+                        Task::FetchWorkspace(workspaces) => self.switch_workspaces(workspaces),
                     }
                     // Coalesce multiple task events into one loop turn
                     task = match self.task_pool.receiver.try_recv() {
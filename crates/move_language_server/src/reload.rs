@@ -1,11 +1,12 @@
 //! Project loading & configuration updates
-use ide_db::base_db::{SourceRoot, VfsPath};
+use ide_db::base_db::{Change, Dialect, SourceRoot, VfsPath};
 use project_model::ProjectWorkspace;
 use std::{mem, sync::Arc};
 
 use crate::config::LinkedProject;
 use crate::global_state::GlobalState;
 use crate::lsp_ext;
+use crate::main_loop::Task;
 use vfs::{file_set::FileSetConfig, AbsPath, AbsPathBuf, ChangeKind};
 
 // #[derive(Debug)]
@@ -56,13 +57,43 @@ impl GlobalState {
                     workspaces.push(ProjectWorkspace::load_detached_files(detached_files));
                 }
 
-                // tracing::info!("did fetch workspaces {:?}", workspaces);
-                // sender
-                //     .send(Task::FetchWorkspace(ProjectWorkspaceProgress::End(workspaces)))
-                //     .unwrap();
+                tracing::info!("did fetch workspaces {:?}", workspaces);
+                sender.send(Task::FetchWorkspace(workspaces)).unwrap();
             }
         });
     }
+
+    pub(crate) fn switch_workspaces(&mut self, workspaces: Vec<anyhow::Result<ProjectWorkspace>>) {
+        let workspaces = workspaces
+            .into_iter()
+            .filter_map(|it| match it {
+                Ok(ws) => Some(ws),
+                Err(err) => {
+                    tracing::error!("failed to load workspace: {:#}", err);
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let dialect = match workspaces.iter().find_map(|ws| ws.dialect()) {
+            Some(name) => Dialect::from_name(name).unwrap_or_else(|| {
+                tracing::warn!("unknown dialect `{}`, using `{}`", name, Dialect::default());
+                Dialect::default()
+            }),
+            None => Dialect::default(),
+        };
+        let named_addresses = workspaces
+            .iter()
+            .flat_map(|ws| ws.named_addresses())
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        let mut change = Change::new();
+        change.set_dialect(dialect);
+        change.set_named_addresses(named_addresses);
+        self.analysis_host.apply_change(change);
+
+        self.workspaces = Arc::new(workspaces);
+    }
 }
 
 #[derive(Default, Debug)]
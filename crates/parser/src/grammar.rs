@@ -1,3 +1,4 @@
+mod attributes;
 mod expressions;
 mod items;
 mod params;
@@ -5,6 +6,7 @@ mod paths;
 mod types;
 mod generic_params;
 mod patterns;
+mod specs;
 
 use crate::grammar::expressions::{stmt, StmtWithSemi};
 use crate::grammar::items::{address, item, module, script};
@@ -63,21 +65,34 @@ fn name_ref(p: &mut Parser) {
 pub(crate) fn root(p: &mut Parser) {
     let m = p.start();
     while !(p.at(EOF)) {
-        if p.at(T![module]) {
-            module(p);
-        } else if p.at(T![script]) {
-            script(p);
-        } else if p.at(T![address]) {
-            address(p);
-        } else if p.current().is_trivia() {
-            p.bump_any()
-        } else {
-            p.error("expected module or script");
+        let m = p.start();
+        attributes::outer_attrs(p);
+        match p.current() {
+            T![module] => module(p, m),
+            T![script] => script(p, m),
+            T![address] => address(p, m),
+            _ => {
+                p.error_expected(items::TOP_LEVEL_ITEM_FIRST);
+                p.bump_any();
+                m.complete(p, ERROR);
+            }
         }
     }
     m.complete(p, SOURCE_FILE);
 }
 
+/// The parsing function for the braced blocks which can be reparsed on their
+/// own: they don't depend on what surrounds them.
+pub(crate) fn reparser(node: SyntaxKind) -> Option<fn(&mut Parser)> {
+    let res = match node {
+        BLOCK_EXPR => block_expr,
+        ITEM_LIST => items::item_list,
+        ADDRESS_ITEM_LIST => items::address_item_list,
+        _ => return None,
+    };
+    Some(res)
+}
+
 fn error_block(p: &mut Parser, message: &str) {
     assert!(p.at(T!['{']));
     let m = p.start();
@@ -0,0 +1,71 @@
+use super::*;
+
+pub(super) fn outer_attrs(p: &mut Parser) {
+    while p.at(T![#]) {
+        attr_list(p);
+    }
+}
+
+// test attr_list
+// #[test_only]
+// module M {
+//     #[test_only]
+//     use Std::Debug;
+//     #[test, expected_failure(abort_code = 1)]
+//     fun f() {}
+//     #[test(account = @0x1, other = @Std), allow(unused,)]
+//     fun g(account: signer, other: signer) {}
+// }
+fn attr_list(p: &mut Parser) {
+    assert!(p.at(T![#]));
+    let m = p.start();
+    p.bump(T![#]);
+    if p.expect(T!['[']) {
+        attrs(p, T![']']);
+        p.expect(T![']']);
+    }
+    m.complete(p, ATTR_LIST);
+}
+
+fn attrs(p: &mut Parser, closing: SyntaxKind) {
+    while !p.at(EOF) && !p.at(closing) {
+        if !p.at(IDENT) {
+            p.error("expected an attribute name");
+            break;
+        }
+        attr(p);
+        if !p.at(closing) && !p.expect(T![,]) {
+            break;
+        }
+    }
+}
+
+fn attr(p: &mut Parser) {
+    assert!(p.at(IDENT));
+    let m = p.start();
+    name_ref(p);
+    if p.eat(T![=]) {
+        attr_value(p);
+    } else if p.eat(T!['(']) {
+        attrs(p, T![')']);
+        p.expect(T![')']);
+    }
+    m.complete(p, ATTR);
+}
+
+// test attr_values
+// module M {
+//     #[expected_failure(abort_code = 0x1::Errors::ENOT_FOUND, location = Self, ok = true)]
+//     fun f() {}
+// }
+fn attr_value(p: &mut Parser) {
+    if paths::is_path_start(p) {
+        let m = p.start();
+        paths::expr_path(p);
+        m.complete(p, PATH_EXPR);
+        return;
+    }
+    if expressions::atom::literal(p).is_none() {
+        p.error("expected an attribute value");
+    }
+}
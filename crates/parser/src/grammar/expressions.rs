@@ -8,6 +8,9 @@ use crate::parser::Parser;
 use crate::SyntaxKind::{self, *};
 use crate::TokenSet;
 
+pub(super) const EXPR_FIRST: TokenSet =
+    atom::ATOM_EXPR_FIRST.union(TokenSet::new(&[T![!], T![*], T![&], T![&mut]]));
+
 pub(crate) enum StmtWithSemi {
     Yes,
     No,
@@ -28,7 +31,9 @@ fn expr_bp(p: &mut Parser, bp: u8) -> Option<CompletedMarker> {
         }
         let m = lhs.precede(p);
         p.bump(op);
-        expr_bp(p, op_bp + 1);
+        // `==>` is right-associative: `a ==> b ==> c` is `a ==> (b ==> c)`
+        let rhs_bp = if op == T![==>] { op_bp } else { op_bp + 1 };
+        expr_bp(p, rhs_bp);
         // if expr_bp(p, op_bp + 1).is_none() {
         //     p.error_and_skip_until("expected expression", TokenSet::new(&[T![')']]));
         //     m.complete(p, BIN_EXPR);
@@ -44,6 +49,7 @@ fn postfix_expr(p: &mut Parser, mut lhs: CompletedMarker) -> CompletedMarker {
         lhs = match p.current() {
             T!['('] => call_expr(p, lhs),
             T![.] => field_expr(p, lhs),
+            T!['['] => index_expr(p, lhs),
             _ => break,
         }
     }
@@ -74,9 +80,13 @@ fn arg_list(p: &mut Parser) {
     let m = p.start();
     p.bump(T!['(']);
     while !p.at(T![')']) && !p.at(EOF) {
-        // test arg_with_attr
-        // fn main() {
-        //     foo(92)
+        // test call_expr
+        // script {
+        //     fun main() {
+        //         foo(92);
+        //         Vector::push_back<u8>(&mut v, 1,);
+        //         f()(x);
+        //     }
         // }
         let parsed_expr = expr(p);
         if parsed_expr.is_none() {
@@ -90,14 +100,29 @@ fn arg_list(p: &mut Parser) {
     m.complete(p, ARG_LIST);
 }
 
+// test prefix_expr
+// script {
+//     fun main() {
+//         let a = !b;
+//         let r = &x;
+//         let m = &mut borrow_global_mut<T>(addr).field;
+//         *r = *m + 1;
+//         !a && *&b == c;
+//     }
+// }
 fn lhs(p: &mut Parser) -> Option<CompletedMarker> {
     let m;
     let kind = match p.current() {
-        T![*] | T![!] | T![-] => {
+        T![*] | T![!] => {
             m = p.start();
             p.bump_any();
             PREFIX_EXPR
         }
+        T![&] | T![&mut] => {
+            m = p.start();
+            p.bump_any();
+            BORROW_EXPR
+        }
         _ => {
             let lhs = atom_expr(p)?;
             return Some(postfix_expr(p, lhs));
@@ -109,11 +134,14 @@ fn lhs(p: &mut Parser) -> Option<CompletedMarker> {
 }
 
 // test let_stmt
-// fn foo() {
-//     let a;
-//     let b: i32;
-//     let c = 92;
-//     let d: i32 = 92;
+// script {
+//     fun main() {
+//         let a;
+//         let b: u64;
+//         let c = 92;
+//         let d: u64 = 92;
+//         let (e, f): (u8, bool) = (1, true);
+//     }
 // }
 fn let_stmt(p: &mut Parser, m: Marker, with_semi: StmtWithSemi) {
     assert!(p.at(T![let]));
@@ -142,6 +170,12 @@ fn let_stmt(p: &mut Parser, m: Marker, with_semi: StmtWithSemi) {
 }
 
 pub(crate) fn stmt(p: &mut Parser, with_semi: StmtWithSemi) {
+    // test_err stray_r_paren
+    // script { fun main() { let b = (1, 2 as u8); } }
+    if p.at(T![')']) {
+        p.err_and_bump("unmatched `)`");
+        return;
+    }
     let m = p.start();
     if p.at(T![let]) {
         let_stmt(p, m, with_semi);
@@ -163,57 +197,67 @@ pub(crate) fn stmt(p: &mut Parser, with_semi: StmtWithSemi) {
     // p.eat(T![;]);
 }
 
+// test index_expr
+// module M {
+//     spec module {
+//         fun last(v: vector<u64>): u64 { v[len(v) - 1] + v[0] }
+//     }
+// }
+fn index_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(T!['[']));
+    let m = lhs.precede(p);
+    p.bump(T!['[']);
+    expr(p);
+    p.expect(T![']']);
+    m.complete(p, INDEX_EXPR)
+}
+
 /// Binding powers of operators for a Pratt parser.
 ///
+/// Follows the precedence of binary operators in Move, from loosest to
+/// tightest: `=`, the spec-only `==>` and `<==>`, `||`, `&&`, comparisons,
+/// the spec-only range `..`, `|`, `^`, `&`, shifts, additive and
+/// multiplicative operators.
+///
 /// See <https://www.oilshell.org/blog/2016/11/03.html>
 #[rustfmt::skip]
 fn current_op(p: &Parser) -> (u8, SyntaxKind) {
     const NOT_AN_OP: (u8, SyntaxKind) = (0, T![@]);
     match p.current() {
-        // T![|] if p.at(T![||])  => (3,  T![||]),
-        // T![|] if p.at(T![|=])  => (1,  T![|=]),
-        T![|]                  => (6,  T![|]),
-        // T![>] if p.at(T![>>=]) => (1,  T![>>=]),
-        // T![>] if p.at(T![>>])  => (9,  T![>>]),
-        // T![>] if p.at(T![>=])  => (5,  T![>=]),
-        T![>]                  => (5,  T![>]),
-        // T![=] if p.at(T![=>])  => NOT_AN_OP,
-        // T![=] if p.at(T![==])  => (5,  T![==]),
-        T![=]                  => (1,  T![=]),
-        // T![<] if p.at(T![<=])  => (5,  T![<=]),
-        // T![<] if p.at(T![<<=]) => (1,  T![<<=]),
-        // T![<] if p.at(T![<<])  => (9,  T![<<]),
-        T![<]                  => (5,  T![<]),
-        // T![+] if p.at(T![+=])  => (1,  T![+=]),
-        T![+]                  => (10, T![+]),
-        // T![^] if p.at(T![^=])  => (1,  T![^=]),
-        T![^]                  => (7,  T![^]),
-        // T![%] if p.at(T![%=])  => (1,  T![%=]),
-        T![%]                  => (11, T![%]),
-        // T![&] if p.at(T![&=])  => (1,  T![&=]),
-        // T![&] if p.at(T![&&])  => (4,  T![&&]),
-        T![&]                  => (8,  T![&]),
-        // T![/] if p.at(T![/=])  => (1,  T![/=]),
-        T![/]                  => (11, T![/]),
-        // T![*] if p.at(T![*=])  => (1,  T![*=]),
-        T![*]                  => (11, T![*]),
-        // T![.] if p.at(T![..=]) => (2,  T![..=]),
-        // T![.] if p.at(T![..])  => (2,  T![..]),
-        // T![!] if p.at(T![!=])  => (5,  T![!=]),
-        // T![-] if p.at(T![-=])  => (1,  T![-=]),
-        T![-]                  => (10, T![-]),
-        // T![as]                 => (12, T![as]),
-
-        _                      => NOT_AN_OP
+        T![=]  => (1,  T![=]),
+        T![==>] => (2, T![==>]),
+        T![<==>] => (3, T![<==>]),
+        T![||] => (4,  T![||]),
+        T![&&] => (5,  T![&&]),
+        T![==] => (6,  T![==]),
+        T![!=] => (6,  T![!=]),
+        T![<]  => (6,  T![<]),
+        T![>]  => (6,  T![>]),
+        T![<=] => (6,  T![<=]),
+        T![>=] => (6,  T![>=]),
+        T![..] => (7,  T![..]),
+        T![|]  => (8,  T![|]),
+        T![^]  => (9,  T![^]),
+        T![&]  => (10, T![&]),
+        T![<<] => (11, T![<<]),
+        T![>>] => (11, T![>>]),
+        T![+]  => (12, T![+]),
+        T![-]  => (12, T![-]),
+        T![*]  => (13, T![*]),
+        T![/]  => (13, T![/]),
+        T![%]  => (13, T![%]),
+        _      => NOT_AN_OP
     }
 }
 
-// test record_lit
-// fn foo() {
-//     S {};
-//     S { x, y: 32, };
-//     S { x, y: 32, ..Default::default() };
-//     TupleStruct { 0: 1 };
+// test record_expr
+// script {
+//     fun main() {
+//         S {};
+//         S { x, y: 32, };
+//         Coin<T> { value: 0 };
+//         0x1::M::S { inner: S { x } };
+//     }
 // }
 pub(crate) fn record_expr_field_list(p: &mut Parser) {
     assert!(p.at(T!['{']));
@@ -223,9 +267,12 @@ pub(crate) fn record_expr_field_list(p: &mut Parser) {
         let m = p.start();
         match p.current() {
             IDENT | INTEGER_NUMBER => {
-                // test_err record_literal_before_ellipsis_recovery
-                // fn main() {
-                //     S { field ..S::default() }
+                // test_err record_expr_field_recovery
+                // script {
+                //     fun main() {
+                //         S { field ..S::default() };
+                //         S { x: 1 y: 2 };
+                //     }
                 // }
                 if p.nth_at(1, T![:]) {
                     name_ref(p);
@@ -1,21 +1,21 @@
 #![allow(unused)]
 
 use super::*;
-use crate::grammar::{block_expr_unchecked, paths};
+use crate::grammar::{block_expr_unchecked, paths, specs};
 use crate::parser;
 
 // test expr_literals
-// fn foo() {
-//     let _ = true;
-//     let _ = false;
-//     let _ = 1;
-//     let _ = 2.0;
-//     let _ = b'a';
-//     let _ = 'b';
-//     let _ = "c";
-//     let _ = r"d";
-//     let _ = b"e";
-//     let _ = br"f";
+// script {
+//     fun main() {
+//         let _ = true;
+//         let _ = false;
+//         let _ = 1;
+//         let _ = 255u8;
+//         let _ = 0xFFu64;
+//         let _ = b"bytes\n";
+//         let _ = x"00ff";
+//         let _ = @0x1;
+//     }
 // }
 pub(crate) const EXPR_STMT_RECOVERY: TokenSet =
     TokenSet::new(&[T![+], T![-], T![*], T![/], T![;], T![')']]);
@@ -31,17 +31,32 @@ pub(crate) const LITERAL_FIRST: TokenSet = TokenSet::new(&[
     ATSIGN,
 ]);
 
+/// Parses the address part of `@0x1`, `address Std {}` or `module Std::M`.
+///
+/// Names become `NAMED_ADDRESS` nodes which later layers resolve through the
+/// package's address map. Numbers are remapped to `DIEM_ADDRESS`; whether
+/// they are valid addresses depends on the dialect and is checked later.
 pub(crate) fn bump_address(p: &mut Parser) -> bool {
-    if p.current() == IDENT {
-        p.bump(IDENT);
-    } else if p.current() == INTEGER_NUMBER && p.current_text().starts_with("0x") {
-        p.bump_remap(DIEM_ADDRESS);
-    } else {
-        return false;
+    match p.current() {
+        IDENT => {
+            let m = p.start();
+            p.bump(IDENT);
+            m.complete(p, NAMED_ADDRESS);
+        }
+        INTEGER_NUMBER => p.bump_remap(DIEM_ADDRESS),
+        _ => return false,
     }
     true
 }
 
+// test address_lit
+// script {
+//     fun main() {
+//         let a = @0x1;
+//         let b = @Std;
+//         let c = @0x42 == @Std;
+//     }
+// }
 pub(crate) fn address_lit(p: &mut Parser) -> CompletedMarker {
     assert!(p.at(T![@]));
     let m = p.start();
@@ -56,37 +71,38 @@ pub(crate) fn literal(p: &mut Parser) -> Option<CompletedMarker> {
     if !p.at_ts(LITERAL_FIRST) {
         return None;
     }
+    if p.at(T![@]) {
+        return Some(address_lit(p));
+    }
     let m = p.start();
-    match p.current() {
-        T![@] => {
-            address_lit(p);
-        }
-        _ => p.bump_any(),
-    };
+    p.bump_any();
     Some(m.complete(p, LITERAL))
 }
 
 // E.g. for after the break in `if break {}`, this should not match
 pub(super) const ATOM_EXPR_FIRST: TokenSet = LITERAL_FIRST.union(TokenSet::new(&[
+    IDENT,
     T!['('],
     T!['{'],
     T!['['],
     // L_DOLLAR,
     T![|],
     T![move],
+    T![copy],
     // T![box],
-    // T![if],
-    // T![while],
+    T![if],
+    T![while],
     // T![match],
     // T![unsafe],
     T![return],
+    T![abort],
     // T![yield],
-    // T![break],
-    // T![continue],
+    T![break],
+    T![continue],
     // T![async],
     // T![try],
     // T![const],
-    // T![loop],
+    T![loop],
     // T![for],
     // LIFETIME_IDENT,
 ]));
@@ -94,18 +110,45 @@ pub(super) const ATOM_EXPR_FIRST: TokenSet = LITERAL_FIRST.union(TokenSet::new(&
 const EXPR_RECOVERY_SET: TokenSet = TokenSet::new(&[LET_KW]);
 
 pub(super) fn atom_expr(p: &mut Parser) -> Option<CompletedMarker> {
-    if let Some(m) = literal(p) {
-        return Some(m);
+    if at_vector_lit(p) {
+        return Some(vector_lit_expr(p));
+    }
+    if specs::at_quant_expr(p) {
+        return Some(specs::quant_expr(p));
     }
-    if p.current() == IDENT {
+    if p.at_contextual_kw("spec") && p.nth_at(1, T!['{']) {
+        return Some(specs::spec_block_expr(p));
+    }
+    if paths::is_path_start(p) {
         return Some(path_expr(p));
     }
+    if let Some(m) = literal(p) {
+        return Some(m);
+    }
     let done = match p.current() {
         T!['('] => paren_expr(p),
         T!['{'] => block_expr_unchecked(p),
+        T![move] | T![copy] => move_or_copy_expr(p),
+        T![if] => if_expr(p),
+        T![while] => while_expr(p),
+        T![loop] => loop_expr(p),
+        T![return] => return_expr(p),
+        T![abort] => abort_expr(p),
+        T![break] => {
+            let m = p.start();
+            p.bump(T![break]);
+            m.complete(p, BREAK_EXPR)
+        }
+        T![continue] => {
+            let m = p.start();
+            p.bump(T![continue]);
+            m.complete(p, CONTINUE_EXPR)
+        }
         _ => {
-            let done =
-                p.error_and_skip_until("expected expression", TokenSet::new(&[T![;], T![')']]));
+            let done = p.error_and_skip_until(
+                "expected expression",
+                TokenSet::new(&[T![;], T![')'], T!['}']]),
+            );
             return Some(done);
         }
     };
@@ -136,39 +179,220 @@ fn path_expr(p: &mut Parser) -> CompletedMarker {
 }
 
 // test tuple_expr
-// fn foo() {
-//     ();
-//     (1);
-//     (1,);
+// script {
+//     fun main() {
+//         ();
+//         (1);
+//         (1, true);
+//         (a, b,);
+//     }
 // }
 fn paren_expr(p: &mut Parser) -> CompletedMarker {
     assert!(p.at(T!['(']));
     let m = p.start();
-    p.expect(T!['(']);
+    p.bump(T!['(']);
+    if p.eat(T![')']) {
+        return m.complete(p, UNIT_EXPR);
+    }
 
+    let mut saw_comma = false;
     while !p.at(EOF) && !p.at(T![')']) {
-        // saw_expr = true;
-
-        // test tuple_attrs
-        // const A: (i64, i64) = (1, #[cfg(test)] 2);
         let expr = expr(p);
         if expr.is_none() {
             p.error_and_skip_until("expected expression", TokenSet::new(&[T![')']]));
-            return m.complete(p, PAREN_EXPR);
+            break;
         }
-
-        // if !expr_with_attrs(p) {
-        //     break;
+        // test cast_expr
+        // script {
+        //     fun main() {
+        //         let a = (x as u128);
+        //         let b = ((a + 1) as u8) + 1;
+        //     }
         // }
+        if !saw_comma && p.at(T![as]) {
+            p.bump(T![as]);
+            types::type_(p);
+            p.expect(T![')']);
+            return m.complete(p, CAST_EXPR);
+        }
+        if !p.at(T![')']) {
+            saw_comma = true;
+            if !p.expect(T![,]) {
+                break;
+            }
+        }
+    }
+    p.expect(T![')']);
+    m.complete(p, if saw_comma { TUPLE_EXPR } else { PAREN_EXPR })
+}
 
-        // if !p.at(T![')'])
-        // if !p.at(T![')']) {
-        //     saw_comma = true;
-        //     p.expect(T![,]);
-        // }
+fn at_vector_lit(p: &Parser) -> bool {
+    p.at_contextual_kw("vector") && (p.nth_at(1, T!['[']) || p.nth_at(1, T![<]))
+}
+
+// test vector_lit_expr
+// script {
+//     fun main() {
+//         let a = vector[];
+//         let b = vector[1, 2, 3,];
+//         let c = vector<u8>[];
+//         let d = vector<vector<u8>>[vector[1], b"2"];
+//     }
+// }
+fn vector_lit_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(at_vector_lit(p));
+    let m = p.start();
+    p.bump_remap(T![vector]);
+    types::opt_type_arg_list(p);
+    if p.expect(T!['[']) {
+        while !p.at(EOF) && !p.at(T![']']) {
+            if !p.at_ts(EXPR_FIRST) {
+                p.error("expected expression");
+                break;
+            }
+            expr(p);
+            if !p.at(T![']']) && !p.expect(T![,]) {
+                break;
+            }
+        }
+        p.expect(T![']']);
     }
-    // assert!(!saw_comma);
+    m.complete(p, VECTOR_LIT_EXPR)
+}
 
-    p.expect(T![')']);
-    m.complete(p, PAREN_EXPR)
+// test move_copy_expr
+// script {
+//     fun main() {
+//         let a = move x;
+//         let b = copy y.f;
+//         consume(move a, copy b);
+//     }
+// }
+fn move_or_copy_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![move]) || p.at(T![copy]));
+    let m = p.start();
+    let kind = if p.eat(T![move]) {
+        MOVE_EXPR
+    } else {
+        p.bump(T![copy]);
+        COPY_EXPR
+    };
+    // test_err move_copy_non_local
+    // script {
+    //     fun main() {
+    //         move 1;
+    //         copy;
+    //     }
+    // }
+    if p.at(IDENT) {
+        name_ref(p);
+    } else {
+        p.error("expected a local variable name");
+    }
+    m.complete(p, kind)
+}
+
+// test if_expr
+// script {
+//     fun main() {
+//         if (true) {};
+//         if (true) {} else {};
+//         if (a) 1 else if (b) 2 else 3;
+//         if (a) return 1 else abort 2;
+//         let x = if (a) b + 1 else c * 2;
+//     }
+// }
+fn if_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![if]));
+    let m = p.start();
+    p.bump(T![if]);
+    condition(p);
+    expr(p);
+    if p.eat(T![else]) {
+        expr(p);
+    }
+    m.complete(p, IF_EXPR)
+}
+
+// test while_expr
+// script {
+//     fun main() {
+//         while (true) {};
+//         while (i < 10) i = i + 1;
+//     }
+// }
+fn while_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![while]));
+    let m = p.start();
+    p.bump(T![while]);
+    condition(p);
+    expr(p);
+    m.complete(p, WHILE_EXPR)
+}
+
+/// Parses the parenthesized condition of `if` and `while`. The parentheses
+/// are part of the syntax, so `if (a) -1 else 1` does not read as `(a) - 1`.
+// test_err condition_recovery
+// script {
+//     fun main() {
+//         if true {};
+//         while (a {};
+//     }
+// }
+fn condition(p: &mut Parser) {
+    let has_l_paren = p.expect(T!['(']);
+    expr(p);
+    if has_l_paren {
+        p.expect(T![')']);
+    }
+}
+
+// test loop_expr
+// script {
+//     fun main() {
+//         loop {
+//             if (a) break;
+//             if (b) continue;
+//         }
+//     }
+// }
+fn loop_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![loop]));
+    let m = p.start();
+    p.bump(T![loop]);
+    expr(p);
+    m.complete(p, LOOP_EXPR)
+}
+
+// test return_expr
+// script {
+//     fun main() {
+//         return;
+//         return 1 + 2;
+//         if (a) return else return
+//     }
+// }
+fn return_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![return]));
+    let m = p.start();
+    p.bump(T![return]);
+    if p.at_ts(EXPR_FIRST) {
+        expr(p);
+    }
+    m.complete(p, RETURN_EXPR)
+}
+
+// test abort_expr
+// script {
+//     fun main() {
+//         abort 1;
+//         abort ERR + 1
+//     }
+// }
+fn abort_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![abort]));
+    let m = p.start();
+    p.bump(T![abort]);
+    expr(p);
+    m.complete(p, ABORT_EXPR)
 }
@@ -1,6 +1,8 @@
 use super::*;
 use crate::marker::Marker;
 
+const ABILITIES: &[&str] = &["drop", "store", "key"];
+
 pub(super) fn opt_generic_param_list(p: &mut Parser) {
     if p.at(T![<]) {
         generic_param_list(p);
@@ -8,7 +10,7 @@ pub(super) fn opt_generic_param_list(p: &mut Parser) {
 }
 
 // test generic_param_list
-// fn f<T: Clone>() {}
+// module M { fun f<T: copy, U>() {} }
 fn generic_param_list(p: &mut Parser) {
     assert!(p.at(T![<]));
     let m = p.start();
@@ -36,60 +38,58 @@ fn generic_param(p: &mut Parser) {
 }
 
 // test type_param
-// fn f<T: Clone>() {}
+// module M {
+//     struct S<phantom T: store + drop, U, phantom> { f: U }
+// }
 fn type_param(p: &mut Parser, m: Marker) {
     assert!(p.at(IDENT));
+    if p.at_contextual_kw("phantom") && p.nth(1) == IDENT {
+        p.bump_remap(T![phantom]);
+    }
     name(p);
     if p.at(T![:]) {
         bounds(p);
     }
-    // if p.at(T![=]) {
-    //     // test type_param_default
-    //     // struct S<T = i32>;
-    //     p.bump(T![=]);
-    //     types::type_(p)
-    // }
     m.complete(p, TYPE_PARAM);
 }
 
-// test type_param_bounds
-// struct S<T: 'a + ?Sized + (Copy)>;
+// test ability_bounds
+// module M { fun f<T: copy + drop + store + key>() {} }
 pub(super) fn bounds(p: &mut Parser) {
     assert!(p.at(T![:]));
     p.bump(T![:]);
-    bounds_without_colon(p);
-}
-
-pub(super) fn bounds_without_colon(p: &mut Parser) {
     let m = p.start();
-    bounds_without_colon_m(p, m);
-}
-
-pub(super) fn bounds_without_colon_m(p: &mut Parser, marker: Marker) -> CompletedMarker {
-    while type_bound(p) {
+    while ability(p) {
         if !p.eat(T![+]) {
             break;
         }
     }
-    marker.complete(p, TYPE_BOUND_LIST)
+    m.complete(p, ABILITY_BOUND_LIST);
 }
 
-fn type_bound(p: &mut Parser) -> bool {
+/// Parses a single ability: `copy`, `drop`, `store` or `key`.
+///
+/// `copy` is a keyword, the rest are plain identifiers. Unknown identifiers
+/// are still consumed as abilities, so that a typo does not break the rest
+/// of the declaration.
+pub(super) fn ability(p: &mut Parser) -> bool {
     let m = p.start();
-    // let has_paren = p.eat(T!['(']);
-    // p.eat(T![?]);
     match p.current() {
-        // T![for] => types::for_type(p, false),
-        IDENT => name(p),
+        T![copy] => p.bump(T![copy]),
+        // test_err unknown_ability
+        // module M { struct S has copy, drip {} }
+        IDENT => {
+            if !ABILITIES.contains(&p.current_text()) {
+                p.error("unknown ability, expected one of `copy`, `drop`, `store`, `key`");
+            }
+            p.bump(IDENT);
+        }
         _ => {
             m.abandon(p);
+            p.error("expected an ability");
             return false;
         }
     }
-    // if has_paren {
-    //     p.expect(T![')']);
-    // }
-    m.complete(p, TYPE_BOUND);
-
+    m.complete(p, ABILITY);
     true
 }
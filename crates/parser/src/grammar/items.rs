@@ -1,32 +1,56 @@
 mod adt;
+mod use_item;
 
 use crate::grammar::expressions::atom::bump_address;
 use crate::grammar::params::param_list;
 use crate::grammar::types::type_;
-use crate::grammar::{block_expr, name, name_r};
+use crate::grammar::{
+    attributes, block_expr, error_block, expressions, generic_params, name, name_r, paths, specs,
+    types,
+};
 use crate::marker::Marker;
-use crate::parser::Parser;
+use crate::parser::{expected_message, Parser};
 use crate::SyntaxKind::{self, *};
 use crate::TokenSet;
 
 pub(crate) const TOP_LEVEL_ITEM_RECOVERY_SET: TokenSet =
     TokenSet::new(&[T![address], T![module], T![script]]);
 
+/// Tokens a top level item can start with, listed when it is missing.
+pub(crate) const TOP_LEVEL_ITEM_FIRST: TokenSet =
+    TOP_LEVEL_ITEM_RECOVERY_SET.union(TokenSet::new(&[T![#]]));
+
+/// Tokens an item of a module or a script can start with, listed when it
+/// is missing.
+pub(crate) const ITEM_FIRST: TokenSet = TokenSet::new(&[
+    T![#],
+    T![public],
+    T![native],
+    T![entry],
+    T![fun],
+    T![struct],
+    T![const],
+    T![use],
+    T![friend],
+    T![spec],
+]);
+
 pub(crate) const ITEM_RECOVERY_SET: TokenSet = TokenSet::new(&[
     T![struct],
-    // T![const],
+    T![const],
     // T![let],
-    // T![use],
+    T![use],
     T![module],
     T![fun],
-    // T![public],
+    T![public],
+    T![native],
+    T![friend],
+    T![#],
     // T![script],
     T![;],
 ]);
 
-pub(crate) fn address(p: &mut Parser) {
-    let m = p.start();
-
+pub(crate) fn address(p: &mut Parser, m: Marker) {
     assert!(p.eat(T![address]));
     let recovery_set = TOP_LEVEL_ITEM_RECOVERY_SET.union(TokenSet::new(&[T!['{']]));
     address_ident_r(p, recovery_set);
@@ -40,9 +64,7 @@ pub(crate) fn address(p: &mut Parser) {
     m.complete(p, ADDRESS_DEF);
 }
 
-pub(crate) fn script(p: &mut Parser) {
-    let m = p.start();
-
+pub(crate) fn script(p: &mut Parser, m: Marker) {
     assert!(p.eat(T![script]));
     if p.at(T!['{']) {
         item_list(p);
@@ -60,9 +82,7 @@ pub(crate) fn address_ident_r(p: &mut Parser, recovery_tokens: TokenSet) {
     m.complete(p, ADDRESS_IDENT);
 }
 
-pub(crate) fn module(p: &mut Parser) {
-    let m = p.start();
-
+pub(crate) fn module(p: &mut Parser, m: Marker) {
     assert!(p.at(T![module]));
     p.bump(T![module]);
 
@@ -85,7 +105,27 @@ pub(crate) fn address_item_list(p: &mut Parser) {
     p.bump(T!['{']);
 
     while !(p.at(T!['}']) || p.at(EOF)) {
-        module(p);
+        let m = p.start();
+        attributes::outer_attrs(p);
+        if p.at(T![module]) {
+            module(p, m);
+            continue;
+        }
+        // test_err address_item_recovery
+        // address 0x1 { fun f() {} #[test_only] module M {} }
+        let expected = expected_message(TokenSet::new(&[T![#], T![module]]));
+        if p.at(T!['{']) {
+            m.abandon(p);
+            error_block(p, &expected);
+            continue;
+        }
+        p.error(expected);
+        if let Err(m) = opt_item(p, m) {
+            if !p.at(T!['}']) {
+                p.bump_any();
+            }
+            m.complete(p, ERROR);
+        }
     }
     p.expect(T!['}']);
     m.complete(p, ADDRESS_ITEM_LIST);
@@ -104,6 +144,7 @@ pub(crate) fn item_list(p: &mut Parser) {
 
 pub(crate) fn item(p: &mut Parser) {
     let m = p.start();
+    attributes::outer_attrs(p);
     let m = match opt_item(p, m) {
         Ok(()) => {
             if p.at(T![;]) {
@@ -117,38 +158,193 @@ pub(crate) fn item(p: &mut Parser) {
         Err(m) => m,
     };
     m.abandon(p);
-    // if p.at(T!['{']) {
-    //     error_block(p, "expected an item");
-    // } else if p.at(T!['}']) && !stop_on_r_curly {
-    //     let e = p.start();
-    //     p.error("unmatched `}`");
-    //     p.bump(T!['}']);
-    //     e.complete(p, ERROR);
-    if !p.at(EOF) && !p.at(T!['}']) {
-        p.err_and_bump("expected an item");
+    // Unexpected tokens are merged into a single `ERROR` node, up to the next
+    // item or `;`.
+    let expected = expected_message(ITEM_FIRST);
+    if p.at(T!['{']) {
+        error_block(p, &expected);
+    } else if !p.at(EOF) && !p.at(T!['}']) {
+        p.err_and_bump(&expected);
     } else {
-        p.error("expected an item");
+        p.error(expected);
     }
 }
 
 /// Try to parse an item, completing `m` in case of success.
 pub(crate) fn opt_item(p: &mut Parser, m: Marker) -> Result<(), Marker> {
+    let modifiers = opt_item_modifiers(p);
     match p.current() {
-        T![fun] => function_def(p, m),
-        T![struct] => adt::struct_(p, m),
-        _ => {
-            p.error("expected an item");
+        T![fun] => function_def(p, m, modifiers),
+        T![struct] => adt::struct_(p, m, modifiers),
+        // test_err modifiers_without_item
+        // module M { public const A: u64 = 1; }
+        _ if !modifiers.is_empty() => {
+            p.error("expected `fun` or `struct`");
             m.complete(p, ERROR);
         }
+        T![use] => use_item::use_decl(p, m),
+        T![const] => const_def(p, m),
+        T![friend] => friend_decl(p, m),
+        IDENT if p.at_contextual_kw("spec") => specs::spec_def(p, m),
+        _ => return Err(m),
     }
     Ok(())
 }
 
-pub(crate) fn function_def(p: &mut Parser, m: Marker) {
+/// Modifiers seen in front of an item, used to validate the item itself.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ItemModifiers {
+    pub(crate) visibility: bool,
+    pub(crate) native: bool,
+    pub(crate) entry: bool,
+}
+
+impl ItemModifiers {
+    pub(crate) fn is_empty(&self) -> bool {
+        !(self.visibility || self.native || self.entry)
+    }
+}
+
+// test item_modifiers
+// module M {
+//     public fun a() {}
+//     public(script) fun b() {}
+//     public(friend) fun c() {}
+//     native fun d();
+//     native public fun e(): u64;
+//     public entry fun f() {}
+//     entry fun g() {}
+// }
+fn opt_item_modifiers(p: &mut Parser) -> ItemModifiers {
+    let mut modifiers = ItemModifiers::default();
+    loop {
+        // test_err duplicate_modifiers
+        // module M { public public fun f() {} native native fun g(); }
+        match p.current() {
+            T![public] => {
+                if modifiers.visibility {
+                    p.error("duplicate visibility modifier");
+                }
+                visibility(p);
+                modifiers.visibility = true;
+            }
+            T![native] => {
+                if modifiers.native {
+                    p.error("duplicate `native` modifier");
+                }
+                p.bump(T![native]);
+                modifiers.native = true;
+            }
+            IDENT if p.at_contextual_kw("entry") => {
+                if modifiers.entry {
+                    p.error("duplicate `entry` modifier");
+                }
+                p.bump_remap(T![entry]);
+                modifiers.entry = true;
+            }
+            _ => break,
+        }
+    }
+    modifiers
+}
+
+fn visibility(p: &mut Parser) {
+    assert!(p.at(T![public]));
+    let m = p.start();
+    p.bump(T![public]);
+    if p.at(T!['(']) {
+        p.bump(T!['(']);
+        match p.current() {
+            T![script] | T![friend] => p.bump_any(),
+            // test_err visibility_recovery
+            // module M { public(crate) fun f() {} public(script fun g() {} }
+            _ => {
+                let recovery = ITEM_RECOVERY_SET.union(TokenSet::new(&[T![')']]));
+                p.err_recover("expected `script` or `friend`", recovery);
+            }
+        }
+        p.expect(T![')']);
+    }
+    m.complete(p, VISIBILITY);
+}
+
+// test friend_decl
+// module M {
+//     friend 0x1::Coin;
+//     friend Std::Vector;
+//     friend Pool;
+// }
+fn friend_decl(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![friend]));
+    p.bump(T![friend]);
+    // test_err friend_decl_recovery
+    // module M { friend; friend 0x1::Coin }
+    if paths::is_path_start(p) {
+        paths::use_path(p);
+    } else {
+        p.error("expected a module path");
+    }
+    p.expect(T![;]);
+    m.complete(p, FRIEND_DECL);
+}
+
+// test const_def
+// module M {
+//     const ENOT_OWNER: u64 = 1;
+//     const MAX: u128 = 340282366920938463463374607431768211455;
+//     const ADMIN: address = @0x1;
+//     const ENABLED: bool = true;
+// }
+pub(crate) fn const_def(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![const]));
+    p.bump(T![const]);
+    name_r(p, ITEM_RECOVERY_SET.union(TokenSet::new(&[T![:], T![=]])));
+
+    // test_err const_def_missing_type
+    // module M { const A = 1; }
+    if p.at(T![:]) {
+        types::ascription(p);
+    } else {
+        p.error("missing type for `const` item");
+    }
+    if p.eat(T![=]) {
+        expressions::expr(p);
+    } else {
+        p.error("expected `=`");
+    }
+    p.expect(T![;]);
+    m.complete(p, CONST_DEF);
+}
+
+pub(crate) fn function_def(p: &mut Parser, m: Marker, modifiers: ItemModifiers) {
+    function_signature(p);
+    if modifiers.native {
+        // test_err native_fun_with_body
+        // module M { native fun f() {} }
+        if p.at(T!['{']) {
+            p.error("native functions cannot have a body");
+            block_expr(p);
+        } else {
+            p.expect(T![;]);
+        }
+    } else if p.at(T![;]) {
+        // test_err fun_without_body
+        // module M { fun f(); }
+        p.error("expected a block, only native functions can omit the body");
+        p.bump(T![;]);
+    } else {
+        block_expr(p);
+    }
+    m.complete(p, FUNCTION_DEF);
+}
+
+/// Parses everything from `fun` up to the body: name, type parameters,
+/// parameters, return type and the `acquires` list.
+pub(super) fn function_signature(p: &mut Parser) {
     assert!(p.at(T![fun]));
     p.bump(T![fun]);
-
     name_r(p, ITEM_RECOVERY_SET);
+    generic_params::opt_generic_param_list(p);
 
     if p.at(T!['(']) {
         param_list(p);
@@ -157,12 +353,45 @@ pub(crate) fn function_def(p: &mut Parser, m: Marker) {
     }
 
     // test function_ret_type
-    // fn foo() {}
-    // fn bar() -> () {}
+    // module M {
+    //     fun foo() {}
+    //     fun bar(): u64 { 1 }
+    // }
     opt_ret_type(p);
+    if p.at(T![acquires]) {
+        acquires_list(p);
+    }
+}
 
-    block_expr(p);
-    m.complete(p, FUNCTION_DEF);
+// test acquires_list
+// module M {
+//     fun f(): u64 acquires Balance, Self::Coin, 0x1::M::R { 1 }
+//     native fun g() acquires Balance;
+// }
+fn acquires_list(p: &mut Parser) {
+    assert!(p.at(T![acquires]));
+    let m = p.start();
+    p.bump(T![acquires]);
+    // test_err acquires_list_recovery
+    // module M {
+    //     fun f() acquires {}
+    //     fun g() acquires A, {}
+    //     fun h() acquires A B {}
+    // }
+    if !paths::is_path_start(p) {
+        p.error("expected a resource name");
+    }
+    while paths::is_path_start(p) {
+        paths::type_path(p);
+        if p.eat(T![,]) {
+            if !paths::is_path_start(p) {
+                p.error("expected a resource name");
+            }
+        } else if paths::is_path_start(p) {
+            p.expect(T![,]);
+        }
+    }
+    m.complete(p, ACQUIRES_LIST);
 }
 
 fn opt_ret_type(p: &mut Parser) -> bool {
@@ -1,53 +1,72 @@
 use super::*;
-use crate::grammar::{generic_params, types};
+use crate::grammar::{error_block, generic_params, types};
 
-pub(crate) fn struct_(p: &mut Parser, m: Marker) {
+// test struct_def
+// module M {
+//     struct Coin has key, store { value: u64 }
+//     struct Wrapper<phantom T: store> has copy, drop { inner: u64 }
+//     native struct Signer has drop;
+// }
+pub(crate) fn struct_(p: &mut Parser, m: Marker, modifiers: ItemModifiers) {
+    // test_err struct_with_visibility
+    // module M { public struct S {} }
+    if modifiers.visibility || modifiers.entry {
+        p.error("structs cannot have visibility or `entry` modifiers");
+    }
     p.bump(T![struct]);
     name_r(p, ITEM_RECOVERY_SET);
     generic_params::opt_generic_param_list(p);
+    if p.at_contextual_kw("has") {
+        ability_list(p);
+    }
     match p.current() {
-        // T![where] => {
-        //     generic_params::opt_where_clause(p);
-        //     match p.current() {
-        //         T![;] => p.bump(T![;]),
-        //         T!['{'] => record_field_list(p),
-        //         _ => {
-        //             //FIXME: special case `(` error message
-        //             p.error("expected `;` or `{`");
-        //         }
-        //     }
-        // }
-        T!['{'] => record_field_list(p),
-        // test unit_struct
-        // struct S;
+        T!['{'] => {
+            // test_err native_struct_with_fields
+            // module M { native struct S { f: u64 } }
+            if modifiers.native {
+                p.error("native structs cannot have fields");
+            }
+            record_field_list(p)
+        }
+        T![;] if modifiers.native => {
+            p.bump(T![;]);
+        }
+        // test_err struct_without_fields
+        // module M { struct S; }
         T![;] => {
+            p.error("expected `{`, only native structs can omit the fields");
             p.bump(T![;]);
         }
-        // test tuple_struct
-        // struct S(String, usize);
-        // T!['('] if is_struct => {
-        //     tuple_field_list(p);
-        //     // test tuple_struct_where
-        //     // struct S<T>(T) where T: Clone;
-        //     generic_params::opt_where_clause(p);
-        //     p.expect(T![;]);
-        // }
         _ => p.error("expected `;` or `{`"),
     }
     m.complete(p, STRUCT);
 }
 
+fn ability_list(p: &mut Parser) {
+    assert!(p.at_contextual_kw("has"));
+    let m = p.start();
+    p.bump_remap(T![has]);
+    while generic_params::ability(p) {
+        if !p.eat(T![,]) {
+            break;
+        }
+    }
+    m.complete(p, ABILITY_LIST);
+}
+
 // test record_field_list
-// struct S { a: i32, b: f32 }
+// module M { struct S { a: u64, b: bool, } }
 pub(crate) fn record_field_list(p: &mut Parser) {
     assert!(p.at(T!['{']));
     let m = p.start();
     p.bump(T!['{']);
     while !p.at(T!['}']) && !p.at(EOF) {
-        // if p.at(T!['{']) {
-        //     error_block(p, "expected field");
-        //     continue;
-        // }
+        // test_err record_field_list_block
+        // module M { struct S { a: u64, { b: bool } } }
+        if p.at(T!['{']) {
+            error_block(p, "expected field");
+            continue;
+        }
         record_field(p);
         if !p.at(T!['}']) {
             p.expect(T![,]);
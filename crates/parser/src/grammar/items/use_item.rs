@@ -0,0 +1,83 @@
+use super::*;
+use crate::grammar::paths;
+
+// test use_decl
+// module M {
+//     use 0x1::Signer;
+//     use Std::Vector as V;
+//     use Std::Vector::{Self, length as len};
+// }
+pub(super) fn use_decl(p: &mut Parser, m: Marker) {
+    p.bump(T![use]);
+    use_tree(p, true);
+    p.expect(T![;]);
+    m.complete(p, USE_DECL);
+}
+
+/// Parses a use "tree", such as `0x1::Signer` in `use 0x1::Signer;`.
+/// A tree is a module path optionally followed either by a rename
+/// (`as Alias`) or by a braced list of member trees.
+fn use_tree(p: &mut Parser, top_level: bool) {
+    let m = p.start();
+    match p.current() {
+        // test_err use_tree_list_without_path
+        // module M { use {Self}; use 0x1::M::{{Self}}; }
+        T!['{'] => {
+            if top_level {
+                p.error("expected a module path");
+            } else {
+                p.error("nested use lists are not allowed");
+            }
+            use_tree_list(p);
+        }
+        _ if paths::is_path_start(p) => {
+            paths::use_path(p);
+            match p.current() {
+                T![as] => rename(p),
+                T![::] => {
+                    p.bump(T![::]);
+                    if p.at(T!['{']) {
+                        use_tree_list(p);
+                    } else {
+                        p.error("expected `{`");
+                    }
+                }
+                _ => (),
+            }
+        }
+        _ => {
+            m.abandon(p);
+            let msg = "expected one of `address`, `identifier`, `{`";
+            p.err_recover(msg, ITEM_RECOVERY_SET);
+            return;
+        }
+    }
+    m.complete(p, USE_TREE);
+}
+
+fn use_tree_list(p: &mut Parser) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if p.at_ts(ITEM_RECOVERY_SET) {
+            break;
+        }
+        use_tree(p, false);
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, USE_TREE_LIST);
+}
+
+// test use_rename
+// module M { use Std::Vector::{length as len}; }
+fn rename(p: &mut Parser) {
+    assert!(p.at(T![as]));
+    let m = p.start();
+    p.bump(T![as]);
+    name_r(p, ITEM_RECOVERY_SET);
+    m.complete(p, RENAME);
+}
@@ -12,8 +12,11 @@ pub(crate) fn param_list(p: &mut Parser) {
 
     let mut param_marker = None;
     while !p.at(EOF) && !p.at(T![')']) {
-        // test param_outer_arg
-        // fn f(#[attr1] pat: Type) {}
+        // test param_list
+        // module M {
+        //     fun f(a: u64, b: &mut S<T>,) {}
+        //     fun g(account: &signer) {}
+        // }
         let m = match param_marker.take() {
             Some(m) => m,
             None => {
@@ -48,7 +51,7 @@ fn param(p: &mut Parser, m: Marker) {
         types::ascription(p)
     } else {
         // test_err missing_fn_param_type
-        // fn f(x y: i32, z, t: i32) {}
+        // module M { fun f(x y: u64, z, t: u64) {} }
         p.error("missing type for function parameter")
     }
     m.complete(p, PARAM);
@@ -1,4 +1,4 @@
-use crate::grammar::{items, name_ref};
+use crate::grammar::{items, name_ref, types};
 use crate::marker::CompletedMarker;
 use crate::parser::Parser;
 use crate::SyntaxKind::{self, *};
@@ -14,6 +14,10 @@ enum Mode {
     Expr,
 }
 
+pub(crate) fn use_path(p: &mut Parser) {
+    path(p, Mode::Use)
+}
+
 pub(crate) fn type_path(p: &mut Parser) {
     path(p, Mode::Type)
 }
@@ -31,7 +35,7 @@ fn path(p: &mut Parser, mode: Mode) {
 
 fn path_for_qualifier(p: &mut Parser, mode: Mode, mut qual: CompletedMarker) {
     loop {
-        let use_tree = matches!(p.nth(2), T![*] | T!['{']);
+        let use_tree = mode == Mode::Use && p.nth_at(1, T!['{']);
         if p.at(T![::]) && !use_tree {
             let path = qual.precede(p);
             p.bump(T![::]);
@@ -46,20 +50,6 @@ fn path_for_qualifier(p: &mut Parser, mode: Mode, mut qual: CompletedMarker) {
 
 fn path_segment(p: &mut Parser, mode: Mode, first: bool) {
     let m = p.start();
-    // test qual_paths
-    // type X = <A as B>::Output;
-    // fn foo() { <usize as Default>::default(); }
-    // if first && p.eat(T![<]) {
-    //     types::type_(p);
-    //     if p.eat(T![as]) {
-    //         if is_use_path_start(p) {
-    //             types::path_type(p);
-    //         } else {
-    //             p.error("expected a trait");
-    //         }
-    //     }
-    //     p.expect(T![>]);
-    // } else {
     let mut empty = true;
     if first {
         p.eat(T![::]);
@@ -68,32 +58,58 @@ fn path_segment(p: &mut Parser, mode: Mode, first: bool) {
     match p.current() {
         IDENT => {
             name_ref(p);
-            // opt_path_type_args(p, mode);
+            opt_path_type_args(p, mode);
+        }
+        // test address_path
+        // module M { use 0x1::Signer; }
+        INTEGER_NUMBER if first => {
+            items::address_ident_r(p, TokenSet::new(&[T![::]]));
+        }
+        // `address` is a keyword, but it also names the builtin type.
+        T![address] if first && mode == Mode::Type => {
+            let m = p.start();
+            p.bump(T![address]);
+            m.complete(p, NAME_REF);
         }
-        // test crate_path
-        // use crate::foo;
-        // T![self] | T![super] | T![crate] => {
-        //     let m = p.start();
-        //     p.bump_any();
-        //     m.complete(p, NAME_REF);
-        // }
         _ => {
             p.err_recover("expected identifier", items::ITEM_RECOVERY_SET);
             if empty {
                 // test_err empty_segment
-                // use crate::;
+                // module M { use 0x1::; fun f() { Std::M::; } }
                 m.abandon(p);
                 return;
             }
         }
     };
-    // }
     m.complete(p, PATH_SEGMENT);
 }
 
+// test path_type_args
+// module M {
+//     fun f(): Option<u64> {
+//         let s = borrow_global<S>(@0x1);
+//         let b = a < c;
+//         Vector::empty<u8>()
+//     }
+// }
+fn opt_path_type_args(p: &mut Parser, mode: Mode) {
+    match mode {
+        Mode::Use => {}
+        Mode::Type => types::opt_type_arg_list(p),
+        // Like the Move compiler, only read `<` as the start of type arguments
+        // when it is glued to the name, so `a < b` stays a comparison.
+        Mode::Expr => {
+            if p.at_joint_to_prev() {
+                types::opt_type_arg_list(p)
+            }
+        }
+    }
+}
+
 pub(crate) fn is_path_start(p: &Parser) -> bool {
     match p.current() {
-        IDENT /* todo: address */ => true,
+        IDENT => true,
+        INTEGER_NUMBER => p.nth_at(1, T![::]),
         // IDENT | T![self] | T![super] | T![crate] => true,
         // T![:] if p.at(T![::]) => true,
         _ => false,
@@ -4,7 +4,6 @@ pub(super) const PATTERN_FIRST: TokenSet =
     expressions::atom::LITERAL_FIRST.union(paths::PATH_FIRST).union(TokenSet::new(&[
         // T![box],
         // T![ref],
-        T![mut],
         T!['('],
         T!['['],
         T![&],
@@ -14,11 +13,14 @@ pub(super) const PATTERN_FIRST: TokenSet =
     ]));
 
 // test tuple_pat
-// fn main() {
-//     let (a, b, ..) = ();
-//     let (a,) = ();
-//     let (..) = ();
-//     let () = ();
+// script {
+//     fun main() {
+//         let (a, b) = (1, 2);
+//         let (a,) = (1,);
+//         let (a) = 1;
+//         let () = ();
+//         let (a, (b, c)) = x;
+//     }
 // }
 fn tuple_pat(p: &mut Parser) {
     assert!(p.at(T!['(']));
@@ -48,7 +50,6 @@ fn tuple_pat(p: &mut Parser) {
 
 pub(crate) fn pattern(p: &mut Parser) {
     match p.current() {
-        T![mut] => ident_pat(p),
         IDENT => match p.nth(1) {
             // record_pat
             T!['{'] => path_pat(p),
@@ -68,10 +69,12 @@ pub(crate) fn pattern(p: &mut Parser) {
 const PAT_RECOVERY_SET: TokenSet =
     TokenSet::new(&[T![let], T![if], T![while], T![')'], T![,], T![=]]);
 
-// test path_part
-// fn foo() {
-//     let Bar { .. } = ();
-//     let Bar(..) = ();
+// test path_pat
+// script {
+//     fun main() {
+//         let Bar { f } = bar;
+//         let Bar {} = bar;
+//     }
 // }
 fn path_pat(p: &mut Parser) {
     assert!(paths::is_path_start(p));
@@ -102,11 +105,21 @@ fn path_pat(p: &mut Parser) {
 // }
 
 // test record_pat_field_list
-// fn foo() {
-//     let S {} = ();
-//     let S { f, ref mut g } = ();
-//     let S { h: _, ..} = ();
-//     let S { h: _, } = ();
+// script {
+//     fun main() {
+//         let S {} = s;
+//         let S { f, g } = s;
+//         let S { h: _, } = s;
+//     }
+// }
+
+// test_err mut_pat
+// script {
+//     fun main() {
+//         let mut b = 2;
+//         let (mut a, b) = x;
+//         let S { f, mut g } = s;
+//     }
 // }
 fn record_pat_field_list(p: &mut Parser) {
     assert!(p.at(T!['{']));
@@ -123,10 +136,12 @@ fn record_pat_field_list(p: &mut Parser) {
                 // attributes::outer_attrs(p);
                 match p.current() {
                     // test record_pat_field
-                    // fn foo() {
-                    //     let S { 0: 1 } = ();
-                    //     let S { x: 1 } = ();
-                    //     let S { #[cfg(any())] x: 1 } = ();
+                    // script {
+                    //     fun main() {
+                    //         let S { x: y } = s;
+                    //         let S { inner: Inner { value } } = s;
+                    //         let S { f: (a, _) } = s;
+                    //     }
                     // }
                     IDENT if p.nth(1) == T![:] => {
                         name_ref(p);
@@ -146,8 +161,8 @@ fn record_pat_field_list(p: &mut Parser) {
     m.complete(p, RECORD_PAT_FIELD_LIST);
 }
 
-// test placeholder_pat
-// fn main() { let _ = (); }
+// test wildcard_pat
+// script { fun main() { let _ = f(); let (_, x) = g(); } }
 fn wildcard_pat(p: &mut Parser) {
     assert!(p.at(T![_]));
     let m = p.start();
@@ -155,32 +170,6 @@ fn wildcard_pat(p: &mut Parser) {
     m.complete(p, WILDCARD_PAT);
 }
 
-// test dot_dot_pat
-// fn main() {
-//     let .. = ();
-//     //
-//     // Tuples
-//     //
-//     let (a, ..) = ();
-//     let (a, ..,) = ();
-//     let Tuple(a, ..) = ();
-//     let Tuple(a, ..,) = ();
-//     let (.., ..) = ();
-//     let Tuple(.., ..) = ();
-//     let (.., a, ..) = ();
-//     let Tuple(.., a, ..) = ();
-//     //
-//     // Slices
-//     //
-//     let [..] = ();
-//     let [head, ..] = ();
-//     let [head, tail @ ..] = ();
-//     let [head, .., cons] = ();
-//     let [head, mid @ .., cons] = ();
-//     let [head, .., .., cons] = ();
-//     let [head, .., mid, tail @ ..] = ();
-//     let [head, .., mid, .., cons] = ();
-// }
 fn rest_pat(p: &mut Parser) {
     assert!(p.at(T![..]));
     let m = p.start();
@@ -202,14 +191,15 @@ fn rest_pat(p: &mut Parser) {
 //     }
 // }
 
-// test bind_pat
-// fn main() {
-//     let a = ();
-//     let mut b = ();
+// test ident_pat
+// script {
+//     fun main() {
+//         let a = 1;
+//         let (b, c) = (2, 3);
+//     }
 // }
 fn ident_pat(p: &mut Parser) {
     let m = p.start();
-    p.eat(T![mut]);
     name(p);
     m.complete(p, IDENT_PAT);
 }
@@ -0,0 +1,405 @@
+//! The Move Specification Language: `spec` items inside modules, inline
+//! `spec { .. }` blocks inside function bodies and the members of both.
+//!
+//! `old(..)`, `result`, `global<T>(..)` and friends are builtins of the spec
+//! language rather than keywords, so they are parsed as ordinary calls and
+//! paths.
+
+use crate::grammar::expressions::expr;
+use crate::grammar::{block_expr, generic_params, items, name, name_ref, types};
+use crate::marker::{CompletedMarker, Marker};
+use crate::parser::Parser;
+use crate::SyntaxKind::{self, *};
+use crate::TokenSet;
+
+const CONDITION_KWS: &[&str] = &[
+    "requires",
+    "ensures",
+    "aborts_if",
+    "aborts_with",
+    "succeeds_if",
+    "modifies",
+    "invariant",
+    "assert",
+    "assume",
+    "decreases",
+    "emits",
+    "axiom",
+];
+
+const MEMBER_RECOVERY_SET: TokenSet = TokenSet::new(&[T![;], T!['}']]);
+
+// test spec_def
+// module M {
+//     spec module { pragma verify = false; }
+//     spec fun f { requires x > 0; }
+//     spec f { ensures result == x + 1; }
+//     spec struct S { invariant value > 0; }
+//     spec g<T> { aborts_if false; }
+// }
+pub(super) fn spec_def(p: &mut Parser, m: Marker) {
+    assert!(p.at_contextual_kw("spec"));
+    p.bump_remap(T![spec]);
+    if p.at_contextual_kw("schema") {
+        spec_schema(p, m);
+        return;
+    }
+    // test_err spec_def_recovery
+    // module M {
+    //     spec { requires true; }
+    //     spec fun { }
+    //     spec f { requires; }
+    // }
+    match p.current() {
+        T![module] => p.bump(T![module]),
+        T![fun] | T![struct] => {
+            p.bump_any();
+            spec_target_name(p);
+        }
+        _ => spec_target_name(p),
+    }
+    generic_params::opt_generic_param_list(p);
+    if p.at(T!['{']) {
+        spec_block(p);
+    } else {
+        p.error("expected a spec block");
+    }
+    m.complete(p, SPEC_DEF);
+}
+
+fn spec_target_name(p: &mut Parser) {
+    if p.at(IDENT) {
+        name_ref(p);
+    } else {
+        p.error("expected `module`, `schema` or the name of a function or struct");
+    }
+}
+
+// test spec_schema
+// module M {
+//     spec schema Increases<T> {
+//         x: u64;
+//         requires x > 0;
+//         ensures result > x;
+//     }
+// }
+fn spec_schema(p: &mut Parser, m: Marker) {
+    assert!(p.at_contextual_kw("schema"));
+    p.bump_remap(T![schema]);
+    name(p);
+    generic_params::opt_generic_param_list(p);
+    if p.at(T!['{']) {
+        spec_block(p);
+    } else {
+        p.error("expected a spec block");
+    }
+    m.complete(p, SPEC_SCHEMA);
+}
+
+// test spec_block_expr
+// module M {
+//     fun f(x: u64) {
+//         spec { assert x > 0; };
+//         let y = x;
+//         spec { assume y == x; }
+//     }
+// }
+pub(super) fn spec_block_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at_contextual_kw("spec") && p.nth_at(1, T!['{']));
+    let m = p.start();
+    p.bump_remap(T![spec]);
+    spec_block(p);
+    m.complete(p, SPEC_BLOCK_EXPR)
+}
+
+fn spec_block(p: &mut Parser) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        spec_member(p);
+    }
+    p.expect(T!['}']);
+    m.complete(p, SPEC_BLOCK);
+}
+
+// test_err spec_member_recovery
+// module M {
+//     spec f {
+//         1 + 2;
+//         requires true
+//         ensures false;
+//         pragma;
+//     }
+// }
+fn spec_member(p: &mut Parser) {
+    let m = p.start();
+    match p.current() {
+        IDENT if CONDITION_KWS.iter().any(|kw| p.at_contextual_kw(kw)) => spec_condition(p, m),
+        IDENT if p.at_contextual_kw("pragma") => spec_pragma(p, m),
+        IDENT if p.at_contextual_kw("include") => spec_include(p, m),
+        IDENT if p.at_contextual_kw("local") || p.at_contextual_kw("global") => spec_var(p, m),
+        IDENT if p.nth_at(1, T![:]) || p.nth_at(1, T![<]) => spec_var(p, m),
+        T![let] => spec_let(p, m),
+        T![fun] | T![native] => spec_fun(p, m),
+        T![;] => {
+            m.abandon(p);
+            p.bump(T![;]);
+        }
+        _ => {
+            m.abandon(p);
+            p.error_and_skip_until("expected a spec condition", MEMBER_RECOVERY_SET);
+        }
+    }
+}
+
+// test spec_condition
+// module M {
+//     spec f {
+//         requires x > 0 && y > 0;
+//         ensures x > 0 ==> result == old(x) + 1;
+//         aborts_if x == 0 with 7;
+//         aborts_with 1, 2;
+//         modifies global<T>(@0x1);
+//         emits Ev { } to handle if x > 0;
+//         decreases x;
+//     }
+//     spec S {
+//         invariant [abstract] value > 0;
+//         invariant update old(value) <= value;
+//     }
+// }
+fn spec_condition(p: &mut Parser, m: Marker) {
+    let kind = SyntaxKind::from_contextual_keyword(p.current_text())
+        .expect("spec conditions are contextual keywords");
+    p.bump_remap(kind);
+    if kind == T![invariant] {
+        if ["update", "pack", "unpack"].iter().any(|kw| p.at_contextual_kw(kw)) {
+            let kind = SyntaxKind::from_contextual_keyword(p.current_text()).unwrap();
+            p.bump_remap(kind);
+        } else {
+            p.eat(T![module]);
+        }
+    }
+    if p.at(T!['[']) {
+        spec_property_list(p);
+    }
+    expr(p);
+    if kind == T![aborts_with] || kind == T![modifies] {
+        while p.eat(T![,]) {
+            expr(p);
+        }
+    }
+    if kind == T![emits] && p.at_contextual_kw("to") {
+        p.bump_remap(T![to]);
+        expr(p);
+        if p.eat(T![if]) {
+            expr(p);
+        }
+    }
+    if kind == T![aborts_if] && p.at_contextual_kw("with") {
+        p.bump_remap(T![with]);
+        expr(p);
+    }
+    p.expect(T![;]);
+    m.complete(p, SPEC_CONDITION);
+}
+
+fn spec_property_list(p: &mut Parser) {
+    assert!(p.at(T!['[']));
+    let m = p.start();
+    p.bump(T!['[']);
+    while !p.at(EOF) && !p.at(T![']']) {
+        if !p.at(IDENT) {
+            p.error("expected a property name");
+            break;
+        }
+        spec_property(p);
+        if !p.at(T![']']) && !p.expect(T![,]) {
+            break;
+        }
+    }
+    p.expect(T![']']);
+    m.complete(p, SPEC_PROPERTY_LIST);
+}
+
+fn spec_property(p: &mut Parser) {
+    assert!(p.at(IDENT));
+    let m = p.start();
+    name_ref(p);
+    if p.eat(T![=]) {
+        expr(p);
+    }
+    m.complete(p, SPEC_PROPERTY);
+}
+
+// test spec_pragma
+// module M {
+//     spec module {
+//         pragma verify;
+//         pragma aborts_if_is_strict = true, timeout = 100;
+//     }
+// }
+fn spec_pragma(p: &mut Parser, m: Marker) {
+    assert!(p.at_contextual_kw("pragma"));
+    p.bump_remap(T![pragma]);
+    if p.at(IDENT) {
+        spec_property(p);
+        while p.eat(T![,]) {
+            if !p.at(IDENT) {
+                p.error("expected a pragma property");
+                break;
+            }
+            spec_property(p);
+        }
+    } else {
+        p.error("expected a pragma property");
+    }
+    p.expect(T![;]);
+    m.complete(p, SPEC_PRAGMA);
+}
+
+// test spec_include
+// module M {
+//     spec f {
+//         include Increases<u64> { x: y };
+//         include x > 0 ==> AbortsIfZero;
+//     }
+// }
+fn spec_include(p: &mut Parser, m: Marker) {
+    assert!(p.at_contextual_kw("include"));
+    p.bump_remap(T![include]);
+    expr(p);
+    p.expect(T![;]);
+    m.complete(p, SPEC_INCLUDE);
+}
+
+// test spec_var
+// module M {
+//     spec module {
+//         global counter: u64;
+//         global sums<T>: num = 0;
+//     }
+//     spec f {
+//         local y: u64;
+//         x: u64;
+//     }
+// }
+fn spec_var(p: &mut Parser, m: Marker) {
+    if p.at_contextual_kw("local") {
+        p.bump_remap(T![local]);
+    } else if p.at_contextual_kw("global") {
+        p.bump_remap(T![global]);
+    }
+    name(p);
+    generic_params::opt_generic_param_list(p);
+    if p.expect(T![:]) {
+        types::type_(p);
+    }
+    if p.eat(T![=]) {
+        expr(p);
+    }
+    p.expect(T![;]);
+    m.complete(p, SPEC_VAR);
+}
+
+// test spec_let
+// module M {
+//     spec f {
+//         let a = x + 1;
+//         let post b = a * 2;
+//     }
+// }
+fn spec_let(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![let]));
+    p.bump(T![let]);
+    if p.at_contextual_kw("post") && p.nth_at(1, IDENT) {
+        p.bump_remap(T![post]);
+    }
+    name(p);
+    p.expect(T![=]);
+    expr(p);
+    p.expect(T![;]);
+    m.complete(p, SPEC_LET);
+}
+
+// test spec_fun
+// module M {
+//     spec module {
+//         fun sum(a: u64, b: u64): u64 { a + b }
+//         native fun uninterpreted(x: u64): bool;
+//         fun abstract_fun(): u64;
+//     }
+// }
+fn spec_fun(p: &mut Parser, m: Marker) {
+    p.eat(T![native]);
+    if !p.at(T![fun]) {
+        p.error("expected `fun`");
+        m.complete(p, ERROR);
+        return;
+    }
+    items::function_signature(p);
+    // uninterpreted spec functions have no body
+    if !p.eat(T![;]) {
+        block_expr(p);
+    }
+    m.complete(p, FUNCTION_DEF);
+}
+
+/// Whether the parser is at a quantifier, e.g. `forall x: u64:` or
+/// `exists i in 0..len(v):`, as opposed to a call like `exists<T>(a)`.
+pub(super) fn at_quant_expr(p: &Parser) -> bool {
+    ["forall", "exists", "choose"].iter().any(|kw| p.at_contextual_kw(kw))
+        && p.nth_at(1, IDENT)
+        && (p.nth_at(2, T![:]) || p.nth_at(2, IDENT))
+}
+
+// test quant_expr
+// module M {
+//     spec f {
+//         ensures forall x: u64: x >= 0;
+//         ensures forall i in 0..len(v), j in 0..len(v) where i < j: v[i] <= v[j];
+//         ensures exists a: address: exists<T>(a);
+//         ensures result == choose x: u64 where x > 0;
+//     }
+// }
+pub(super) fn quant_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(at_quant_expr(p));
+    let m = p.start();
+    let kind = SyntaxKind::from_contextual_keyword(p.current_text()).unwrap();
+    p.bump_remap(kind);
+    quant_binding(p);
+    while p.eat(T![,]) {
+        quant_binding(p);
+    }
+    if p.at_contextual_kw("where") {
+        p.bump_remap(T![where]);
+        expr(p);
+    }
+    // `choose` yields the bound value and has no body
+    if kind != T![choose] && p.expect(T![:]) {
+        expr(p);
+    }
+    m.complete(p, QUANT_EXPR)
+}
+
+// test_err quant_binding_recovery
+// module M {
+//     spec f {
+//         ensures forall x: u64 x > 0;
+//         ensures forall x y: true;
+//     }
+// }
+fn quant_binding(p: &mut Parser) {
+    let m = p.start();
+    name(p);
+    if p.at(T![:]) {
+        types::ascription(p);
+    } else if p.at_contextual_kw("in") {
+        p.bump_remap(T![in]);
+        expr(p);
+    } else {
+        p.error("expected `:` or `in`");
+    }
+    m.complete(p, QUANT_BINDING);
+}
@@ -4,7 +4,8 @@ use crate::parser::Parser;
 use crate::SyntaxKind::{self, *};
 use crate::TokenSet;
 
-pub(super) const TYPE_FIRST: TokenSet = paths::PATH_FIRST.union(TokenSet::new(&[T![&]]));
+pub(super) const TYPE_FIRST: TokenSet =
+    paths::PATH_FIRST.union(TokenSet::new(&[T!['('], T![&], T![&mut], T![address]]));
 
 const TYPE_RECOVERY_SET: TokenSet = TokenSet::new(&[
     T![')'],
@@ -21,7 +22,11 @@ pub(super) fn ascription(p: &mut Parser) {
 pub(crate) fn type_(p: &mut Parser) {
     let allow_bounds = false;
     match p.current() {
-        T![&] => ref_type(p),
+        T!['('] => tuple_or_paren_type(p),
+        T![&] | T![&mut] => ref_type(p),
+        // test address_type
+        // module M { const A: address = @0x1; }
+        T![address] => path_type(p),
         _ if is_path_start(p) => path_type(p),
         _ => {
             p.err_recover("expected type", TYPE_RECOVERY_SET);
@@ -29,17 +34,45 @@ pub(crate) fn type_(p: &mut Parser) {
     }
 }
 
+// test tuple_type
+// module M {
+//     fun f(): () {}
+//     fun g(): (u64, &mut vector<u8>) { (1, x) }
+//     fun h(x: (u64)) {}
+// }
+fn tuple_or_paren_type(p: &mut Parser) {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+    let mut n_types = 0;
+    let mut trailing_comma = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        if !p.at_ts(TYPE_FIRST) {
+            p.error("expected type");
+            break;
+        }
+        n_types += 1;
+        type_(p);
+        trailing_comma = p.at(T![,]);
+        if !p.at(T![')']) && !p.expect(T![,]) {
+            break;
+        }
+    }
+    p.expect(T![')']);
+    let kind = if n_types == 1 && !trailing_comma { PAREN_TYPE } else { TUPLE_TYPE };
+    m.complete(p, kind);
+}
+
 pub(crate) fn ref_type(p: &mut Parser) {
-    assert!(p.at(T![&]));
+    assert!(p.at(T![&]) || p.at(T![&mut]));
     let m = p.start();
-    p.bump(T![&]);
-    p.eat(T![mut]);
+    p.bump_any();
     type_(p);
     m.complete(p, REF_TYPE);
 }
 
 pub(crate) fn path_type(p: &mut Parser) {
-    assert!(is_path_start(p));
+    assert!(is_path_start(p) || p.at(T![address]));
     let m = p.start();
     // let m = p.start();
 
@@ -54,3 +87,34 @@ pub(crate) fn path_type(p: &mut Parser) {
     //     opt_type_bounds_as_dyn_trait_type(p, path);
     // }
 }
+
+// test vector_type
+// module M { fun f(v: vector<u8>, r: &vector<address>) {} }
+pub(super) fn opt_type_arg_list(p: &mut Parser) {
+    if !p.at(T![<]) {
+        return;
+    }
+    let m = p.start();
+    p.bump(T![<]);
+    while !p.at(EOF) && !p.at(T![>]) && !p.at(T![>>]) {
+        if !p.at_ts(TYPE_FIRST) {
+            p.error("expected type");
+            break;
+        }
+        type_(p);
+        if !p.at(T![>]) && !p.at(T![>>]) && !p.expect(T![,]) {
+            break;
+        }
+    }
+    // test nested_type_args
+    // module M {
+    //     fun f(v: vector<vector<u8>>): Option<Option<u64>> {
+    //         borrow_global<Coin<T>>(a);
+    //     }
+    // }
+    if p.at(T![>>]) {
+        p.split_gt_gt();
+    }
+    p.expect(T![>]);
+    m.complete(p, TYPE_ARG_LIST);
+}
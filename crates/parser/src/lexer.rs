@@ -27,16 +27,22 @@ pub struct Lexer<'t> {
 }
 
 impl<'t> Lexer<'t> {
+    /// Returns the `n`th non-trivia token after the current one without
+    /// advancing the state of the lexer.
     pub(crate) fn lookahead_nth(&self, n: usize) -> Token {
-        if n == 0 {
-            self.current()
-        } else if n == 1 {
-            self.lookahead()
-        } else if n == 2 {
-            self.lookahead_2().1
-        } else {
-            todo!()
+        let mut token = self.current();
+        let mut offset = self.cur_end;
+        for _ in 0..n {
+            loop {
+                let (kind, len) = find_token(&self.text[offset..]);
+                offset += len;
+                if !kind.is_trivia() {
+                    token = Token::new(kind, len);
+                    break;
+                }
+            }
         }
+        token
     }
 }
 
@@ -44,8 +50,13 @@ impl<'t> Lexer<'t> {
     pub fn new(text: &'t str) -> Lexer<'t> {
         let (kind, len) = find_token(text);
         let token = Token::new(kind, len);
-        let lexer =
+        let mut lexer =
             Lexer { text, prev_end: 0, cur_start: 0, cur_end: len, token, all_tokens: vec![token] };
+        // Leading trivia is skipped like any other, so that the tree builder
+        // can attach a doc comment at the start of the file to the first item.
+        if kind.is_trivia() {
+            lexer.advance();
+        }
         lexer
     }
 
@@ -81,47 +92,40 @@ impl<'t> Lexer<'t> {
         &self.text[self.cur_start..self.cur_end]
     }
 
-    // pub fn start_loc(&self) -> usize {
-    //     self.cur_start
-    // }
+    pub fn start_loc(&self) -> usize {
+        self.cur_start
+    }
 
-    // pub fn previous_end_loc(&self) -> usize {
-    //     self.prev_end
-    // }
+    /// Returns the end offset of the previous non-trivia token.
+    pub fn previous_end_loc(&self) -> usize {
+        self.prev_end
+    }
 
     // Look ahead to the next token after the current one and return it without advancing
     // the state of the lexer.
     pub fn lookahead(&self) -> Token {
-        let text = self.text[self.cur_end..].trim_start();
-        // let offset = self.text.len() - text.len();
-        let (tok, len) = find_token(text);
-        Token::new(tok, len)
+        self.lookahead_nth(1)
     }
 
     // Look ahead to the next two tokens after the current one and return them without advancing
     // the state of the lexer.
     pub fn lookahead_2(&self) -> (Token, Token) {
-        let text = &self.text[self.cur_end..];
-        // let text = self.text[self.cur_end..].trim_start();
-        let offset = self.text.len() - text.len();
-        let (first, length) = find_token(text);
-
-        let text2 = &self.text[offset + length..];
-        // let text2 = self.text[offset + length..].trim_start();
-        // let offset2 = self.text.len() - text2.len();
-        let (second, sec_length) = find_token(text2);
-        (Token::new(first, length), Token::new(second, sec_length))
+        (self.lookahead_nth(1), self.lookahead_nth(2))
     }
 
     pub fn bump(&mut self) {
         self.prev_end = self.cur_end;
+        self.advance();
+    }
+
+    fn advance(&mut self) {
         let text = &self.text[self.cur_end..];
         // let text = self.text[self.cur_end..].trim_start();
         self.cur_start = self.text.len() - text.len();
         let (kind, len) = find_token(text);
         if kind.is_trivia() {
             self.advance_with_token(kind, len);
-            self.bump();
+            self.advance();
             return;
         }
         self.advance_with_token(kind, len);
@@ -141,10 +145,11 @@ impl<'t> Lexer<'t> {
     // Replace the current token. The lexer will always match the longest token,
     // but sometimes the parser will prefer to replace it with a shorter one,
     // e.g., ">" instead of ">>".
-    // pub fn replace_token(&mut self, kind: SyntaxKind, len: usize) {
-    //     self.token = Token::new(kind, len);
-    //     self.cur_end = self.cur_start + len
-    // }
+    pub fn replace_token(&mut self, kind: SyntaxKind, len: usize) {
+        self.token = Token::new(kind, len);
+        self.cur_end = self.cur_start + len;
+        *self.all_tokens.last_mut().unwrap() = self.token;
+    }
 }
 
 /// Determine if a character is a permitted newline character.
@@ -155,6 +160,33 @@ pub fn is_permitted_newline_char(c: char) -> bool {
     x == 0x0A
 }
 
+/// Lexes the, possibly nested, block comment at the start of `text`.
+///
+/// Returns the length of the comment and whether it is terminated. An
+/// unterminated comment extends to the end of the text; the error for it is
+/// reported by validation.
+pub fn lex_block_comment(text: &str) -> (usize, bool) {
+    assert!(text.starts_with("/*"));
+    let bytes = text.as_bytes();
+    let mut depth = 0;
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if bytes[pos..].starts_with(b"/*") {
+            depth += 1;
+            pos += 2;
+        } else if bytes[pos..].starts_with(b"*/") {
+            depth -= 1;
+            pos += 2;
+            if depth == 0 {
+                return (pos, true);
+            }
+        } else {
+            pos += 1;
+        }
+    }
+    (text.len(), false)
+}
+
 // Find the next token and its length without changing the state of the lexer.
 pub fn find_token(text: &str) -> (SyntaxKind, usize) {
     let c: char = match text.chars().next() {
@@ -179,14 +211,26 @@ pub fn find_token(text: &str) -> (SyntaxKind, usize) {
             (WHITESPACE, num_space_chars)
         }
         '/' if text.starts_with("//") => {
+            let kind = if text.starts_with("///") && !text.starts_with("////") {
+                DOC_COMMENT
+            } else {
+                COMMENT
+            };
             let res = text.char_indices().find(|(pos, ch)| is_permitted_newline_char(ch.clone()));
             match res {
-                Some((pos, _)) => (COMMENT, pos + 1),
-                None => (COMMENT, text.len()),
+                Some((pos, _)) => (kind, pos + 1),
+                None => (kind, text.len()),
             }
         }
+        '/' if text.starts_with("/*") => {
+            // `/**/` and `/*** ... */` are regular comments
+            let is_doc =
+                text.starts_with("/**") && !text.starts_with("/***") && !text.starts_with("/**/");
+            let (len, _terminated) = lex_block_comment(text);
+            (if is_doc { DOC_COMMENT } else { COMMENT }, len)
+        }
         '0'..='9' => {
-            if text.starts_with("0x") && text.len() > 2 {
+            let (tok, len) = if text.starts_with("0x") && text.len() > 2 {
                 let (tok, hex_len) = get_hex_number(&text[2..]);
                 if hex_len == 0 {
                     // Fall back to treating this as a "0" token.
@@ -196,27 +240,22 @@ pub fn find_token(text: &str) -> (SyntaxKind, usize) {
                 }
             } else {
                 get_decimal_number(text)
-            }
+            };
+            // Glue trailing name characters onto the number, so that an ss58
+            // address like `5GrwvaEF...` is a single token. Malformed numbers
+            // are reported by validation instead of splitting into two tokens.
+            (tok, len + get_name_len(&text[len..]))
         }
         'A'..='Z' | 'a'..='z' | '_' => {
             let is_hex = text.starts_with("x\"");
             if is_hex || text.starts_with("b\"") {
+                let kind = if is_hex { HEX_STRING } else { BYTE_STRING };
                 let line = &text.lines().next().unwrap()[2..];
+                // An unterminated string runs to the end of the line, the
+                // missing quote is reported by validation.
                 match get_string_len(line) {
-                    Some(last_quote) => (BYTE_STRING, 2 + last_quote + 1),
-                    None => {
-                        todo!()
-                        // let loc = make_loc(file, start_offset, start_offset + line.len() + 2);
-                        // return todo!()
-                        // return Err(diag!(
-                        //     if is_hex {
-                        //         Syntax::InvalidHexString
-                        //     } else {
-                        //         Syntax::InvalidByteString
-                        //     },
-                        //     (loc, "Missing closing quote (\") after byte string")
-                        // ));
-                    }
+                    Some(last_quote) => (kind, 2 + last_quote + 1),
+                    None => (kind, 2 + line.len()),
                 }
             } else {
                 let len = get_name_len(text);
@@ -306,15 +345,8 @@ pub fn find_token(text: &str) -> (SyntaxKind, usize) {
         '}' => (R_BRACE, 1),
         '#' => (NUMSIGN, 1),
         '@' => (ATSIGN, 1),
-        _ => {
-            todo!()
-            // let loc = make_loc(file, start_offset, start_offset);
-            // return todo!()
-            // return Err(diag!(
-            //     Syntax::InvalidCharacter,
-            //     (loc, format!("Invalid character: '{}'", c))
-            // ));
-        }
+        // The parser reports the unexpected token.
+        _ => (ERROR, c.len_utf8()),
     };
 
     (tok, len)
@@ -360,20 +392,20 @@ fn get_number_maybe_with_suffix(text: &str, num_text_len: usize) -> (SyntaxKind,
     }
 }
 
-// Return the length of the quoted string, or None if there is no closing quote.
+// Return the length in bytes of the quoted string, or None if there is no closing quote.
 fn get_string_len(text: &str) -> Option<usize> {
     let mut pos = 0;
     let mut iter = text.chars();
     while let Some(chr) = iter.next() {
         if chr == '\\' {
             // Skip over the escaped character (e.g., a quote or another backslash)
-            if iter.next().is_some() {
-                pos += 1;
+            if let Some(escaped) = iter.next() {
+                pos += escaped.len_utf8();
             }
         } else if chr == '"' {
             return Some(pos);
         }
-        pos += 1;
+        pos += chr.len_utf8();
     }
     None
 }
@@ -1,7 +1,7 @@
 #![allow(unused)]
 
 use crate::event::Event;
-pub use lexer::{Lexer, Token};
+pub use lexer::{find_token, lex_block_comment, Lexer, Token};
 pub use syntax_kind::SyntaxKind;
 pub use token_set::TokenSet;
 
@@ -43,6 +43,30 @@ pub fn parse_with_lexer<'t>(lexer: &'t mut Lexer<'t>) -> (Vec<Event>, Vec<Token>
     (events, tokens)
 }
 
+/// A parsing function for a specific braced-block.
+pub struct Reparser(fn(&mut parser::Parser));
+
+impl Reparser {
+    /// If the node is a braced block, return the corresponding `Reparser`.
+    pub fn for_node(node: SyntaxKind) -> Option<Reparser> {
+        grammar::reparser(node).map(Reparser)
+    }
+
+    /// Re-parse the text of the block using this `Reparser`.
+    ///
+    /// The text must start with `{`, end with `}` and have balanced braces.
+    /// Parsing may still stop at an inner `}`, in which case the returned
+    /// tokens don't cover the whole text.
+    pub fn parse<'t>(self, lexer: &'t mut Lexer<'t>) -> (Vec<Event>, Vec<Token>) {
+        let Reparser(r) = self;
+        let mut p = parser::Parser::new(lexer);
+        r(&mut p);
+        let tokens = p.tokens();
+        let events = p.finish();
+        (events, tokens)
+    }
+}
+
 fn parse_from_tokens<'t, F>(lexer: &'t mut Lexer<'t>, tree_sink: &mut dyn TreeSink, f: F)
 where
     F: FnOnce(&mut parser::Parser),
@@ -28,7 +28,8 @@ impl Marker {
         }
         let finish_pos = p.events.len() as u32;
         p.push_event(Event::Finish);
-        CompletedMarker::new(self.pos, finish_pos, kind)
+        let start_pos = if kind == ERROR { p.merge_error_node(self.pos) } else { self.pos };
+        CompletedMarker::new(start_pos, finish_pos, kind)
     }
 
     /// Abandons the syntax tree node. All its children
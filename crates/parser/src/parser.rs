@@ -7,7 +7,7 @@ use crate::marker::{CompletedMarker, Marker};
 use crate::{
     event::Event,
     ParseError,
-    SyntaxKind::{self, EOF, ERROR},
+    SyntaxKind::{self, BYTE_STRING, EOF, ERROR, HEX_STRING, IDENT, INTEGER_NUMBER, TOMBSTONE},
     Token, TokenSet,
 };
 
@@ -24,11 +24,32 @@ pub(crate) struct Parser<'t> {
     pub(crate) lexer: &'t mut Lexer<'t>,
     pub(crate) events: Vec<Event>,
     pub(crate) steps: Cell<u32>,
+    /// Tokens checked for with `at` since the last bump, reported by
+    /// `expect` when the current token is none of them.
+    expected: Cell<TokenSet>,
+    /// Number of tokens consumed so far.
+    bumps: u32,
+    /// The kind of the last consumed token.
+    last_bumped: SyntaxKind,
+    /// Value of `bumps` at the last reported error.
+    last_error_at: Option<u32>,
+    /// Finish and start events of the completed `ERROR` nodes which the next
+    /// sibling `ERROR` node can be merged into, ordered by the finish event.
+    error_nodes: Vec<(u32, u32)>,
 }
 
 impl<'t> Parser<'t> {
     pub(super) fn new(lexer: &'t mut Lexer<'t>) -> Parser<'t> {
-        Parser { lexer, events: Vec::new(), steps: Cell::new(0) }
+        Parser {
+            lexer,
+            events: Vec::new(),
+            steps: Cell::new(0),
+            expected: Cell::new(TokenSet::EMPTY),
+            bumps: 0,
+            last_bumped: EOF,
+            last_error_at: None,
+            error_nodes: Vec::new(),
+        }
     }
 
     pub(crate) fn tokens(&mut self) -> Vec<Token> {
@@ -72,34 +93,35 @@ impl<'t> Parser<'t> {
     }
 
     pub(crate) fn nth_at(&self, n: usize, kind: SyntaxKind) -> bool {
-        match kind {
-            // T![-=] => self.at_composite2(n, T![-], T![=]),
-            // T![->] => self.at_composite2(n, T![-], T![>]),
-            // T![::] => self.at_composite2(n, T![:], T![:]),
-            // T![!=] => self.at_composite2(n, T![!], T![=]),
-            // T![..] => self.at_composite2(n, T![.], T![.]),
-            // T![*=] => self.at_composite2(n, T![*], T![=]),
-            // T![/=] => self.at_composite2(n, T![/], T![=]),
-            // T![&&] => self.at_composite2(n, T![&], T![&]),
-            // T![&=] => self.at_composite2(n, T![&], T![=]),
-            // T![%=] => self.at_composite2(n, T![%], T![=]),
-            // T![^=] => self.at_composite2(n, T![^], T![=]),
-            // T![+=] => self.at_composite2(n, T![+], T![=]),
-            // T![<<] => self.at_composite2(n, T![<], T![<]),
-            // T![<=] => self.at_composite2(n, T![<], T![=]),
-            // T![==] => self.at_composite2(n, T![=], T![=]),
-            // T![=>] => self.at_composite2(n, T![=], T![>]),
-            // T![>=] => self.at_composite2(n, T![>], T![=]),
-            // T![>>] => self.at_composite2(n, T![>], T![>]),
-            // T![|=] => self.at_composite2(n, T![|], T![=]),
-            // T![||] => self.at_composite2(n, T![|], T![|]),
-
-            // T![...] => self.at_composite3(n, T![.], T![.], T![.]),
-            // T![..=] => self.at_composite3(n, T![.], T![.], T![=]),
-            // T![<<=] => self.at_composite3(n, T![<], T![<], T![=]),
-            // T![>>=] => self.at_composite3(n, T![>], T![>], T![=]),
-            _ => self.nth(n) == kind,
+        // T![-=] => self.at_composite2(n, T![-], T![=]),
+        // T![->] => self.at_composite2(n, T![-], T![>]),
+        // T![::] => self.at_composite2(n, T![:], T![:]),
+        // T![!=] => self.at_composite2(n, T![!], T![=]),
+        // T![..] => self.at_composite2(n, T![.], T![.]),
+        // T![*=] => self.at_composite2(n, T![*], T![=]),
+        // T![/=] => self.at_composite2(n, T![/], T![=]),
+        // T![&&] => self.at_composite2(n, T![&], T![&]),
+        // T![&=] => self.at_composite2(n, T![&], T![=]),
+        // T![%=] => self.at_composite2(n, T![%], T![=]),
+        // T![^=] => self.at_composite2(n, T![^], T![=]),
+        // T![+=] => self.at_composite2(n, T![+], T![=]),
+        // T![<<] => self.at_composite2(n, T![<], T![<]),
+        // T![<=] => self.at_composite2(n, T![<], T![=]),
+        // T![==] => self.at_composite2(n, T![=], T![=]),
+        // T![=>] => self.at_composite2(n, T![=], T![>]),
+        // T![>=] => self.at_composite2(n, T![>], T![=]),
+        // T![>>] => self.at_composite2(n, T![>], T![>]),
+        // T![|=] => self.at_composite2(n, T![|], T![=]),
+        // T![||] => self.at_composite2(n, T![|], T![|]),
+
+        // T![...] => self.at_composite3(n, T![.], T![.], T![.]),
+        // T![..=] => self.at_composite3(n, T![.], T![.], T![=]),
+        // T![<<=] => self.at_composite3(n, T![<], T![<], T![=]),
+        // T![>>=] => self.at_composite3(n, T![>], T![>], T![=]),
+        if n == 0 {
+            self.expected.set(self.expected.get().union(TokenSet::new(&[kind])));
         }
+        self.nth(n) == kind
     }
 
     /// Consume the next token if `kind` matches.
@@ -144,10 +166,26 @@ impl<'t> Parser<'t> {
         kinds.contains(self.current())
     }
 
-    // /// Checks if the current token is contextual keyword with text `t`.
-    // pub(crate) fn at_contextual_kw(&self, kw: &str) -> bool {
-    //     self.lexer.is_keyword(kw)
-    // }
+    /// Splits the current `>>` token in two `>`, so that nested type argument
+    /// lists like `vector<vector<u8>>` can be closed one at a time.
+    pub(crate) fn split_gt_gt(&mut self) {
+        assert!(self.at(T![>>]));
+        self.lexer.replace_token(T![>], 1);
+    }
+
+    /// Checks if the current token directly follows the previous one,
+    /// without any whitespace or comments in between.
+    pub(crate) fn at_joint_to_prev(&self) -> bool {
+        self.lexer.previous_end_loc() == self.lexer.start_loc()
+    }
+
+    /// Checks if the current token is contextual keyword with text `t`.
+    pub(crate) fn at_contextual_kw(&self, kw: &str) -> bool {
+        if let Some(kind) = SyntaxKind::from_contextual_keyword(kw) {
+            self.expected.set(self.expected.get().union(TokenSet::new(&[kind])));
+        }
+        self.current() == IDENT && self.current_text() == kw
+    }
 
     /// Starts a new node in the syntax tree. All nodes and tokens
     /// consumed between the `start` and the corresponding `Marker::complete`
@@ -188,21 +226,36 @@ impl<'t> Parser<'t> {
     }
 
     /// Emit error with the `message`
+    ///
+    /// Only the first error at a position is kept: once the parser is lost,
+    /// the errors of the rules it falls back on say nothing new.
     /// FIXME: this should be much more fancy and support
     /// structured errors with spans and notes, like rustc
     /// does.
     pub(crate) fn error<T: Into<String>>(&mut self, message: T) {
+        if self.last_error_at == Some(self.bumps) {
+            return;
+        }
+        self.last_error_at = Some(self.bumps);
         let msg = ParseError(Box::new(message.into()));
         self.push_event(Event::Error { msg })
     }
 
+    /// Emit an error listing the tokens which could have been at the current
+    /// position.
+    pub(crate) fn error_expected(&mut self, expected: TokenSet) {
+        self.error(expected_message(expected));
+    }
+
     /// Consume the next token if it is `kind` or emit an error
-    /// otherwise.
+    /// otherwise. The error lists all the tokens the parser looked for at
+    /// this position, not only `kind`.
     pub(crate) fn expect(&mut self, kind: SyntaxKind) -> bool {
         if self.eat(kind) {
             return true;
         }
-        self.error(format!("expected {:?}", kind));
+        let expected = self.expected.get().union(TokenSet::new(&[kind]));
+        self.error_expected(expected);
         false
     }
 
@@ -264,6 +317,10 @@ impl<'t> Parser<'t> {
         for _ in 0..n_raw_tokens {
             self.lexer.bump();
         }
+        self.steps.set(0);
+        self.expected.set(TokenSet::EMPTY);
+        self.bumps += 1;
+        self.last_bumped = kind;
 
         self.push_event(Event::Token { kind, n_raw_tokens });
     }
@@ -271,4 +328,65 @@ impl<'t> Parser<'t> {
     pub(crate) fn push_event(&mut self, event: Event) {
         self.events.push(event)
     }
+
+    /// Called when the `ERROR` node which starts at `start_pos` is completed,
+    /// returns the start of the node it ends up in.
+    ///
+    /// Consecutive unexpected tokens are reported once, as a single node: if
+    /// the previous node is an `ERROR` node too, the two are merged, and the
+    /// errors the second one starts with are dropped. A `;` or a `}` ends the
+    /// recovery, so an `ERROR` node ending with one is kept apart.
+    pub(crate) fn merge_error_node(&mut self, start_pos: u32) -> u32 {
+        let finish_pos = self.events.len() as u32 - 1;
+        let prev = match start_pos.checked_sub(1) {
+            Some(prev_finish) => {
+                self.error_nodes.binary_search_by_key(&prev_finish, |it| it.0).ok()
+            }
+            None => None,
+        };
+        let start = match prev {
+            Some(idx) => {
+                let (prev_finish, prev_start) = self.error_nodes.remove(idx);
+                self.events[prev_finish as usize] = Event::tombstone();
+                self.events[start_pos as usize] = Event::tombstone();
+                for event in &mut self.events[start_pos as usize..finish_pos as usize] {
+                    match event {
+                        Event::Error { .. } => *event = Event::tombstone(),
+                        Event::Start { kind: TOMBSTONE, .. } => (),
+                        _ => break,
+                    }
+                }
+                prev_start
+            }
+            _ => start_pos,
+        };
+        if !matches!(self.last_bumped, T![;] | T!['}']) {
+            self.error_nodes.push((finish_pos, start));
+        }
+        start
+    }
+}
+
+/// Formats an error like "expected one of `fun`, `struct`".
+pub(crate) fn expected_message(expected: TokenSet) -> String {
+    let kinds: Vec<String> = expected.iter().filter(|&it| it != EOF).map(describe_kind).collect();
+    match kinds.as_slice() {
+        [] => "unexpected token".to_string(),
+        [kind] => format!("expected {}", kind),
+        _ => format!("expected one of {}", kinds.join(", ")),
+    }
+}
+
+fn describe_kind(kind: SyntaxKind) -> String {
+    if let Some(text) = kind.text() {
+        return format!("`{}`", text);
+    }
+    let description = match kind {
+        IDENT => "an identifier",
+        INTEGER_NUMBER => "an integer",
+        BYTE_STRING => "a byte string",
+        HEX_STRING => "a hex string",
+        _ => return format!("{:?}", kind),
+    };
+    description.to_string()
 }
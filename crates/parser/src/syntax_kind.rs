@@ -19,7 +19,7 @@ impl From<SyntaxKind> for u16 {
 impl SyntaxKind {
     pub fn is_trivia(self) -> bool {
         match self {
-            SyntaxKind::WHITESPACE | SyntaxKind::COMMENT => true,
+            SyntaxKind::WHITESPACE | SyntaxKind::COMMENT | SyntaxKind::DOC_COMMENT => true,
             _ => false,
         }
     }
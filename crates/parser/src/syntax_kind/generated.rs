@@ -64,6 +64,46 @@ pub enum SyntaxKind {
     BREAK_KW,
     CONTINUE_KW,
     ADDRESS_KW,
+    PUBLIC_KW,
+    NATIVE_KW,
+    FRIEND_KW,
+    COPY_KW,
+    ACQUIRES_KW,
+    LOOP_KW,
+    ABORT_KW,
+    ENTRY_KW,
+    HAS_KW,
+    PHANTOM_KW,
+    VECTOR_KW,
+    SPEC_KW,
+    SCHEMA_KW,
+    PRAGMA_KW,
+    INCLUDE_KW,
+    LOCAL_KW,
+    GLOBAL_KW,
+    REQUIRES_KW,
+    ENSURES_KW,
+    ABORTS_IF_KW,
+    ABORTS_WITH_KW,
+    SUCCEEDS_IF_KW,
+    MODIFIES_KW,
+    INVARIANT_KW,
+    ASSERT_KW,
+    ASSUME_KW,
+    DECREASES_KW,
+    EMITS_KW,
+    AXIOM_KW,
+    UPDATE_KW,
+    PACK_KW,
+    UNPACK_KW,
+    POST_KW,
+    TO_KW,
+    WITH_KW,
+    FORALL_KW,
+    EXISTS_KW,
+    CHOOSE_KW,
+    WHERE_KW,
+    IN_KW,
     INTEGER_NUMBER,
     BYTE_STRING,
     HEX_STRING,
@@ -71,12 +111,14 @@ pub enum SyntaxKind {
     IDENT,
     WHITESPACE,
     COMMENT,
+    DOC_COMMENT,
     DIEM_ADDRESS,
     SOURCE_FILE,
     ADDRESS_DEF,
     ADDRESS_ITEM_LIST,
     MODULE_DEF,
     ADDRESS_IDENT,
+    NAMED_ADDRESS,
     SCRIPT_DEF,
     FUNCTION_DEF,
     ITEM_LIST,
@@ -86,8 +128,9 @@ pub enum SyntaxKind {
     RECORD_FIELD,
     GENERIC_PARAM_LIST,
     TYPE_PARAM,
-    TYPE_BOUND_LIST,
-    TYPE_BOUND,
+    ABILITY_BOUND_LIST,
+    ABILITY_LIST,
+    ABILITY,
     PARAM_LIST,
     PARAM,
     RET_TYPE,
@@ -102,15 +145,32 @@ pub enum SyntaxKind {
     LITERAL,
     PATH,
     PATH_SEGMENT,
+    TYPE_ARG_LIST,
     NAME,
     BIN_EXPR,
     PREFIX_EXPR,
+    BORROW_EXPR,
+    MOVE_EXPR,
+    COPY_EXPR,
     PATH_EXPR,
     PAREN_EXPR,
+    TUPLE_EXPR,
+    UNIT_EXPR,
+    CAST_EXPR,
+    VECTOR_LIT_EXPR,
+    IF_EXPR,
+    WHILE_EXPR,
+    LOOP_EXPR,
+    RETURN_EXPR,
+    ABORT_EXPR,
+    BREAK_EXPR,
+    CONTINUE_EXPR,
     EXPR_STMT,
     LET_STMT,
     REF_TYPE,
     PATH_TYPE,
+    TUPLE_TYPE,
+    PAREN_TYPE,
     PAREN_PAT,
     TUPLE_PAT,
     RECORD_PAT,
@@ -121,6 +181,30 @@ pub enum SyntaxKind {
     REST_PAT,
     IDENT_PAT,
     ADDRESS_LIT,
+    USE_DECL,
+    USE_TREE,
+    USE_TREE_LIST,
+    RENAME,
+    CONST_DEF,
+    FRIEND_DECL,
+    SPEC_DEF,
+    SPEC_SCHEMA,
+    SPEC_BLOCK,
+    SPEC_BLOCK_EXPR,
+    SPEC_CONDITION,
+    SPEC_PROPERTY_LIST,
+    SPEC_PROPERTY,
+    SPEC_PRAGMA,
+    SPEC_INCLUDE,
+    SPEC_VAR,
+    SPEC_LET,
+    QUANT_EXPR,
+    QUANT_BINDING,
+    INDEX_EXPR,
+    VISIBILITY,
+    ACQUIRES_LIST,
+    ATTR_LIST,
+    ATTR,
     #[doc(hidden)]
     __LAST,
 }
@@ -148,6 +232,46 @@ impl SyntaxKind {
                 | BREAK_KW
                 | CONTINUE_KW
                 | ADDRESS_KW
+                | PUBLIC_KW
+                | NATIVE_KW
+                | FRIEND_KW
+                | COPY_KW
+                | ACQUIRES_KW
+                | LOOP_KW
+                | ABORT_KW
+                | ENTRY_KW
+                | HAS_KW
+                | PHANTOM_KW
+                | VECTOR_KW
+                | SPEC_KW
+                | SCHEMA_KW
+                | PRAGMA_KW
+                | INCLUDE_KW
+                | LOCAL_KW
+                | GLOBAL_KW
+                | REQUIRES_KW
+                | ENSURES_KW
+                | ABORTS_IF_KW
+                | ABORTS_WITH_KW
+                | SUCCEEDS_IF_KW
+                | MODIFIES_KW
+                | INVARIANT_KW
+                | ASSERT_KW
+                | ASSUME_KW
+                | DECREASES_KW
+                | EMITS_KW
+                | AXIOM_KW
+                | UPDATE_KW
+                | PACK_KW
+                | UNPACK_KW
+                | POST_KW
+                | TO_KW
+                | WITH_KW
+                | FORALL_KW
+                | EXISTS_KW
+                | CHOOSE_KW
+                | WHERE_KW
+                | IN_KW
         )
     }
     pub fn is_punct(self) -> bool {
@@ -194,6 +318,110 @@ impl SyntaxKind {
         )
     }
     pub fn is_literal(self) -> bool { matches!(self, INTEGER_NUMBER | BYTE_STRING | HEX_STRING) }
+    #[doc = r" The text of a punctuation or keyword token."]
+    pub fn text(self) -> Option<&'static str> {
+        let text = match self {
+            COLON => ":",
+            COLON_COLON => "::",
+            SEMICOLON => ";",
+            COMMA => ",",
+            L_PAREN => "(",
+            R_PAREN => ")",
+            L_BRACE => "{",
+            R_BRACE => "}",
+            L_BRACK => "[",
+            R_BRACK => "]",
+            PLUS => "+",
+            MINUS => "-",
+            STAR => "*",
+            SLASH => "/",
+            MOD => "%",
+            NUMSIGN => "#",
+            ATSIGN => "@",
+            DOT => ".",
+            DOTDOT => "..",
+            AMP => "&",
+            AMP_AMP => "&&",
+            AMP_MUT => "&mut",
+            CARET => "^",
+            PIPE => "|",
+            PIPE_PIPE => "||",
+            BANG => "!",
+            BANG_EQ => "!=",
+            EQ => "=",
+            EQ_EQ => "==",
+            EQ_EQ_GT => "==>",
+            GT => ">",
+            GT_GT => ">>",
+            GT_EQ => ">=",
+            LT => "<",
+            LT_LT => "<<",
+            LT_EQ => "<=",
+            LT_EQ_EQ_GT => "<==>",
+            WILDCARD => "_",
+            STRUCT_KW => "struct",
+            SCRIPT_KW => "script",
+            MODULE_KW => "module",
+            CONST_KW => "const",
+            USE_KW => "use",
+            AS_KW => "as",
+            LET_KW => "let",
+            MUT_KW => "mut",
+            RETURN_KW => "return",
+            FUN_KW => "fun",
+            TRUE_KW => "true",
+            FALSE_KW => "false",
+            MOVE_KW => "move",
+            WHILE_KW => "while",
+            IF_KW => "if",
+            ELSE_KW => "else",
+            BREAK_KW => "break",
+            CONTINUE_KW => "continue",
+            ADDRESS_KW => "address",
+            PUBLIC_KW => "public",
+            NATIVE_KW => "native",
+            FRIEND_KW => "friend",
+            COPY_KW => "copy",
+            ACQUIRES_KW => "acquires",
+            LOOP_KW => "loop",
+            ABORT_KW => "abort",
+            ENTRY_KW => "entry",
+            HAS_KW => "has",
+            PHANTOM_KW => "phantom",
+            VECTOR_KW => "vector",
+            SPEC_KW => "spec",
+            SCHEMA_KW => "schema",
+            PRAGMA_KW => "pragma",
+            INCLUDE_KW => "include",
+            LOCAL_KW => "local",
+            GLOBAL_KW => "global",
+            REQUIRES_KW => "requires",
+            ENSURES_KW => "ensures",
+            ABORTS_IF_KW => "aborts_if",
+            ABORTS_WITH_KW => "aborts_with",
+            SUCCEEDS_IF_KW => "succeeds_if",
+            MODIFIES_KW => "modifies",
+            INVARIANT_KW => "invariant",
+            ASSERT_KW => "assert",
+            ASSUME_KW => "assume",
+            DECREASES_KW => "decreases",
+            EMITS_KW => "emits",
+            AXIOM_KW => "axiom",
+            UPDATE_KW => "update",
+            PACK_KW => "pack",
+            UNPACK_KW => "unpack",
+            POST_KW => "post",
+            TO_KW => "to",
+            WITH_KW => "with",
+            FORALL_KW => "forall",
+            EXISTS_KW => "exists",
+            CHOOSE_KW => "choose",
+            WHERE_KW => "where",
+            IN_KW => "in",
+            _ => return None,
+        };
+        Some(text)
+    }
     pub fn from_keyword(ident: &str) -> Option<SyntaxKind> {
         let kw = match ident {
             "struct" => STRUCT_KW,
@@ -215,6 +443,52 @@ impl SyntaxKind {
             "break" => BREAK_KW,
             "continue" => CONTINUE_KW,
             "address" => ADDRESS_KW,
+            "public" => PUBLIC_KW,
+            "native" => NATIVE_KW,
+            "friend" => FRIEND_KW,
+            "copy" => COPY_KW,
+            "acquires" => ACQUIRES_KW,
+            "loop" => LOOP_KW,
+            "abort" => ABORT_KW,
+            _ => return None,
+        };
+        Some(kw)
+    }
+    pub fn from_contextual_keyword(ident: &str) -> Option<SyntaxKind> {
+        let kw = match ident {
+            "entry" => ENTRY_KW,
+            "has" => HAS_KW,
+            "phantom" => PHANTOM_KW,
+            "vector" => VECTOR_KW,
+            "spec" => SPEC_KW,
+            "schema" => SCHEMA_KW,
+            "pragma" => PRAGMA_KW,
+            "include" => INCLUDE_KW,
+            "local" => LOCAL_KW,
+            "global" => GLOBAL_KW,
+            "requires" => REQUIRES_KW,
+            "ensures" => ENSURES_KW,
+            "aborts_if" => ABORTS_IF_KW,
+            "aborts_with" => ABORTS_WITH_KW,
+            "succeeds_if" => SUCCEEDS_IF_KW,
+            "modifies" => MODIFIES_KW,
+            "invariant" => INVARIANT_KW,
+            "assert" => ASSERT_KW,
+            "assume" => ASSUME_KW,
+            "decreases" => DECREASES_KW,
+            "emits" => EMITS_KW,
+            "axiom" => AXIOM_KW,
+            "update" => UPDATE_KW,
+            "pack" => PACK_KW,
+            "unpack" => UNPACK_KW,
+            "post" => POST_KW,
+            "to" => TO_KW,
+            "with" => WITH_KW,
+            "forall" => FORALL_KW,
+            "exists" => EXISTS_KW,
+            "choose" => CHOOSE_KW,
+            "where" => WHERE_KW,
+            "in" => IN_KW,
             _ => return None,
         };
         Some(kw)
@@ -252,4 +526,4 @@ impl SyntaxKind {
     }
 }
 #[macro_export]
-macro_rules ! T { [:] => { $ crate :: SyntaxKind :: COLON } ; [::] => { $ crate :: SyntaxKind :: COLON_COLON } ; [;] => { $ crate :: SyntaxKind :: SEMICOLON } ; [,] => { $ crate :: SyntaxKind :: COMMA } ; ['('] => { $ crate :: SyntaxKind :: L_PAREN } ; [')'] => { $ crate :: SyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: SyntaxKind :: L_BRACE } ; ['}'] => { $ crate :: SyntaxKind :: R_BRACE } ; ['['] => { $ crate :: SyntaxKind :: L_BRACK } ; [']'] => { $ crate :: SyntaxKind :: R_BRACK } ; [+] => { $ crate :: SyntaxKind :: PLUS } ; [-] => { $ crate :: SyntaxKind :: MINUS } ; [*] => { $ crate :: SyntaxKind :: STAR } ; [/] => { $ crate :: SyntaxKind :: SLASH } ; [%] => { $ crate :: SyntaxKind :: MOD } ; [#] => { $ crate :: SyntaxKind :: NUMSIGN } ; [@] => { $ crate :: SyntaxKind :: ATSIGN } ; [.] => { $ crate :: SyntaxKind :: DOT } ; [..] => { $ crate :: SyntaxKind :: DOTDOT } ; [&] => { $ crate :: SyntaxKind :: AMP } ; [&&] => { $ crate :: SyntaxKind :: AMP_AMP } ; [&mut] => { $ crate :: SyntaxKind :: AMP_MUT } ; [^] => { $ crate :: SyntaxKind :: CARET } ; [|] => { $ crate :: SyntaxKind :: PIPE } ; [||] => { $ crate :: SyntaxKind :: PIPE_PIPE } ; [!] => { $ crate :: SyntaxKind :: BANG } ; [!=] => { $ crate :: SyntaxKind :: BANG_EQ } ; [=] => { $ crate :: SyntaxKind :: EQ } ; [==] => { $ crate :: SyntaxKind :: EQ_EQ } ; [==>] => { $ crate :: SyntaxKind :: EQ_EQ_GT } ; [>] => { $ crate :: SyntaxKind :: GT } ; [>>] => { $ crate :: SyntaxKind :: GT_GT } ; [>=] => { $ crate :: SyntaxKind :: GT_EQ } ; [<] => { $ crate :: SyntaxKind :: LT } ; [<<] => { $ crate :: SyntaxKind :: LT_LT } ; [<=] => { $ crate :: SyntaxKind :: LT_EQ } ; [<==>] => { $ crate :: SyntaxKind :: LT_EQ_EQ_GT } ; [_] => { $ crate :: SyntaxKind :: WILDCARD } ; [struct] => { $ crate :: SyntaxKind :: STRUCT_KW } ; [script] => { $ crate :: SyntaxKind :: SCRIPT_KW } ; [module] => { $ crate :: SyntaxKind :: MODULE_KW } ; [const] => { $ crate :: SyntaxKind :: CONST_KW } ; [use] => { $ crate :: SyntaxKind :: USE_KW } ; [as] => { $ crate :: SyntaxKind :: AS_KW } ; [let] => { $ crate :: SyntaxKind :: LET_KW } ; [mut] => { $ crate :: SyntaxKind :: MUT_KW } ; [return] => { $ crate :: SyntaxKind :: RETURN_KW } ; [fun] => { $ crate :: SyntaxKind :: FUN_KW } ; [true] => { $ crate :: SyntaxKind :: TRUE_KW } ; [false] => { $ crate :: SyntaxKind :: FALSE_KW } ; [move] => { $ crate :: SyntaxKind :: MOVE_KW } ; [while] => { $ crate :: SyntaxKind :: WHILE_KW } ; [if] => { $ crate :: SyntaxKind :: IF_KW } ; [else] => { $ crate :: SyntaxKind :: ELSE_KW } ; [break] => { $ crate :: SyntaxKind :: BREAK_KW } ; [continue] => { $ crate :: SyntaxKind :: CONTINUE_KW } ; [address] => { $ crate :: SyntaxKind :: ADDRESS_KW } ; [ident] => { $ crate :: SyntaxKind :: IDENT } ; [shebang] => { $ crate :: SyntaxKind :: SHEBANG } ; }
+macro_rules ! T { [:] => { $ crate :: SyntaxKind :: COLON } ; [::] => { $ crate :: SyntaxKind :: COLON_COLON } ; [;] => { $ crate :: SyntaxKind :: SEMICOLON } ; [,] => { $ crate :: SyntaxKind :: COMMA } ; ['('] => { $ crate :: SyntaxKind :: L_PAREN } ; [')'] => { $ crate :: SyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: SyntaxKind :: L_BRACE } ; ['}'] => { $ crate :: SyntaxKind :: R_BRACE } ; ['['] => { $ crate :: SyntaxKind :: L_BRACK } ; [']'] => { $ crate :: SyntaxKind :: R_BRACK } ; [+] => { $ crate :: SyntaxKind :: PLUS } ; [-] => { $ crate :: SyntaxKind :: MINUS } ; [*] => { $ crate :: SyntaxKind :: STAR } ; [/] => { $ crate :: SyntaxKind :: SLASH } ; [%] => { $ crate :: SyntaxKind :: MOD } ; [#] => { $ crate :: SyntaxKind :: NUMSIGN } ; [@] => { $ crate :: SyntaxKind :: ATSIGN } ; [.] => { $ crate :: SyntaxKind :: DOT } ; [..] => { $ crate :: SyntaxKind :: DOTDOT } ; [&] => { $ crate :: SyntaxKind :: AMP } ; [&&] => { $ crate :: SyntaxKind :: AMP_AMP } ; [&mut] => { $ crate :: SyntaxKind :: AMP_MUT } ; [^] => { $ crate :: SyntaxKind :: CARET } ; [|] => { $ crate :: SyntaxKind :: PIPE } ; [||] => { $ crate :: SyntaxKind :: PIPE_PIPE } ; [!] => { $ crate :: SyntaxKind :: BANG } ; [!=] => { $ crate :: SyntaxKind :: BANG_EQ } ; [=] => { $ crate :: SyntaxKind :: EQ } ; [==] => { $ crate :: SyntaxKind :: EQ_EQ } ; [==>] => { $ crate :: SyntaxKind :: EQ_EQ_GT } ; [>] => { $ crate :: SyntaxKind :: GT } ; [>>] => { $ crate :: SyntaxKind :: GT_GT } ; [>=] => { $ crate :: SyntaxKind :: GT_EQ } ; [<] => { $ crate :: SyntaxKind :: LT } ; [<<] => { $ crate :: SyntaxKind :: LT_LT } ; [<=] => { $ crate :: SyntaxKind :: LT_EQ } ; [<==>] => { $ crate :: SyntaxKind :: LT_EQ_EQ_GT } ; [_] => { $ crate :: SyntaxKind :: WILDCARD } ; [struct] => { $ crate :: SyntaxKind :: STRUCT_KW } ; [script] => { $ crate :: SyntaxKind :: SCRIPT_KW } ; [module] => { $ crate :: SyntaxKind :: MODULE_KW } ; [const] => { $ crate :: SyntaxKind :: CONST_KW } ; [use] => { $ crate :: SyntaxKind :: USE_KW } ; [as] => { $ crate :: SyntaxKind :: AS_KW } ; [let] => { $ crate :: SyntaxKind :: LET_KW } ; [mut] => { $ crate :: SyntaxKind :: MUT_KW } ; [return] => { $ crate :: SyntaxKind :: RETURN_KW } ; [fun] => { $ crate :: SyntaxKind :: FUN_KW } ; [true] => { $ crate :: SyntaxKind :: TRUE_KW } ; [false] => { $ crate :: SyntaxKind :: FALSE_KW } ; [move] => { $ crate :: SyntaxKind :: MOVE_KW } ; [while] => { $ crate :: SyntaxKind :: WHILE_KW } ; [if] => { $ crate :: SyntaxKind :: IF_KW } ; [else] => { $ crate :: SyntaxKind :: ELSE_KW } ; [break] => { $ crate :: SyntaxKind :: BREAK_KW } ; [continue] => { $ crate :: SyntaxKind :: CONTINUE_KW } ; [address] => { $ crate :: SyntaxKind :: ADDRESS_KW } ; [public] => { $ crate :: SyntaxKind :: PUBLIC_KW } ; [native] => { $ crate :: SyntaxKind :: NATIVE_KW } ; [friend] => { $ crate :: SyntaxKind :: FRIEND_KW } ; [copy] => { $ crate :: SyntaxKind :: COPY_KW } ; [acquires] => { $ crate :: SyntaxKind :: ACQUIRES_KW } ; [loop] => { $ crate :: SyntaxKind :: LOOP_KW } ; [abort] => { $ crate :: SyntaxKind :: ABORT_KW } ; [entry] => { $ crate :: SyntaxKind :: ENTRY_KW } ; [has] => { $ crate :: SyntaxKind :: HAS_KW } ; [phantom] => { $ crate :: SyntaxKind :: PHANTOM_KW } ; [vector] => { $ crate :: SyntaxKind :: VECTOR_KW } ; [spec] => { $ crate :: SyntaxKind :: SPEC_KW } ; [schema] => { $ crate :: SyntaxKind :: SCHEMA_KW } ; [pragma] => { $ crate :: SyntaxKind :: PRAGMA_KW } ; [include] => { $ crate :: SyntaxKind :: INCLUDE_KW } ; [local] => { $ crate :: SyntaxKind :: LOCAL_KW } ; [global] => { $ crate :: SyntaxKind :: GLOBAL_KW } ; [requires] => { $ crate :: SyntaxKind :: REQUIRES_KW } ; [ensures] => { $ crate :: SyntaxKind :: ENSURES_KW } ; [aborts_if] => { $ crate :: SyntaxKind :: ABORTS_IF_KW } ; [aborts_with] => { $ crate :: SyntaxKind :: ABORTS_WITH_KW } ; [succeeds_if] => { $ crate :: SyntaxKind :: SUCCEEDS_IF_KW } ; [modifies] => { $ crate :: SyntaxKind :: MODIFIES_KW } ; [invariant] => { $ crate :: SyntaxKind :: INVARIANT_KW } ; [assert] => { $ crate :: SyntaxKind :: ASSERT_KW } ; [assume] => { $ crate :: SyntaxKind :: ASSUME_KW } ; [decreases] => { $ crate :: SyntaxKind :: DECREASES_KW } ; [emits] => { $ crate :: SyntaxKind :: EMITS_KW } ; [axiom] => { $ crate :: SyntaxKind :: AXIOM_KW } ; [update] => { $ crate :: SyntaxKind :: UPDATE_KW } ; [pack] => { $ crate :: SyntaxKind :: PACK_KW } ; [unpack] => { $ crate :: SyntaxKind :: UNPACK_KW } ; [post] => { $ crate :: SyntaxKind :: POST_KW } ; [to] => { $ crate :: SyntaxKind :: TO_KW } ; [with] => { $ crate :: SyntaxKind :: WITH_KW } ; [forall] => { $ crate :: SyntaxKind :: FORALL_KW } ; [exists] => { $ crate :: SyntaxKind :: EXISTS_KW } ; [choose] => { $ crate :: SyntaxKind :: CHOOSE_KW } ; [where] => { $ crate :: SyntaxKind :: WHERE_KW } ; [in] => { $ crate :: SyntaxKind :: IN_KW } ; [ident] => { $ crate :: SyntaxKind :: IDENT } ; [diem_address] => { $ crate :: SyntaxKind :: DIEM_ADDRESS } ; [shebang] => { $ crate :: SyntaxKind :: SHEBANG } ; }
@@ -24,6 +24,15 @@ impl TokenSet {
     pub const fn contains(&self, kind: SyntaxKind) -> bool {
         self.0 & mask(kind) != 0
     }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Kinds in the set, in the order of their declaration.
+    pub fn iter(self) -> impl Iterator<Item = SyntaxKind> {
+        (0..128u16).filter(move |&i| self.0 & (1u128 << i) != 0).map(SyntaxKind::from)
+    }
 }
 
 const fn mask(kind: SyntaxKind) -> u128 {
@@ -38,3 +47,12 @@ fn token_set_works_for_tokens() {
     assert!(ts.contains(EOF));
     assert!(!ts.contains(PLUS));
 }
+
+#[test]
+fn token_set_iterates_in_kind_order() {
+    use crate::SyntaxKind::*;
+
+    let ts = TokenSet::new(&[FUN_KW, COMMA, EOF]);
+    assert_eq!(ts.iter().collect::<Vec<_>>(), vec![EOF, COMMA, FUN_KW]);
+    assert!(TokenSet::EMPTY.is_empty());
+}
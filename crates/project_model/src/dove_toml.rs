@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Dove manifest.
@@ -5,6 +7,9 @@ use serde::{Deserialize, Serialize};
 pub struct DoveToml {
     /// Project info.
     pub package: Package,
+    /// Named addresses and their values, like `Std = "0x1"`.
+    #[serde(default)]
+    pub addresses: BTreeMap<String, String>,
 }
 
 /// Project info.
@@ -45,5 +50,23 @@ dialect = "pont"
         assert_eq!(dove_toml.package.account_address, Some("0x1".to_string()));
         assert_eq!(dove_toml.package.dove_version, Some("0.11.0".to_string()));
         assert_eq!(dove_toml.package.dialect, Some("pont".to_string()));
+        assert!(dove_toml.addresses.is_empty());
+    }
+
+    #[test]
+    fn test_dove_toml_addresses() {
+        let text = r#"
+[package]
+name = "move_project"
+
+[addresses]
+Std = "0x1"
+Owner = "0x42"
+        "#;
+
+        let dove_toml: DoveToml = toml::from_str(text).unwrap();
+        let addresses: Vec<_> =
+            dove_toml.addresses.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        assert_eq!(addresses, vec![("Owner", "0x42"), ("Std", "0x1")]);
     }
 }
@@ -16,7 +16,7 @@ pub struct PackageRoot {
     pub exclude: Vec<AbsPathBuf>,
 }
 
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ProjectWorkspace {
     Dove { dove_toml: DoveToml },
     DetachedFiles { files: Vec<AbsPathBuf> },
@@ -38,6 +38,24 @@ impl ProjectWorkspace {
         Ok(ws)
     }
 
+    /// Name of the Move dialect, as written in the manifest.
+    pub fn dialect(&self) -> Option<&str> {
+        match self {
+            ProjectWorkspace::Dove { dove_toml } => dove_toml.package.dialect.as_deref(),
+            ProjectWorkspace::DetachedFiles { .. } => None,
+        }
+    }
+
+    /// Named addresses and their values, as written in the manifest.
+    pub fn named_addresses(&self) -> Vec<(&str, &str)> {
+        match self {
+            ProjectWorkspace::Dove { dove_toml } => {
+                dove_toml.addresses.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect()
+            }
+            ProjectWorkspace::DetachedFiles { .. } => Vec::new(),
+        }
+    }
+
     pub fn load_detached_files(detached_files: Vec<AbsPathBuf>) -> Result<ProjectWorkspace> {
         Ok(ProjectWorkspace::DetachedFiles { files: detached_files })
     }
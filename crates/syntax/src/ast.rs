@@ -8,9 +8,18 @@ use std::marker::PhantomData;
 mod nodes;
 #[rustfmt::skip]
 mod tokens;
-
+mod traits;
+mod expr_ext;
+mod node_ext;
+mod token_ext;
+pub mod edit;
+pub mod edit_in_place;
+pub mod make;
+
+pub use edit_in_place::Removable;
 pub use nodes::*;
 pub use tokens::*;
+pub use traits::*;
 
 /// The main trait to go from untyped `SyntaxNode`  to a typed ast. The
 /// conversion itself has zero runtime cost: ast and syntax nodes have exactly
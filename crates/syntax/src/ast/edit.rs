@@ -0,0 +1,232 @@
+//! Indentation of syntax trees, and edits which return a modified copy of a
+//! node instead of changing it in place.
+use std::{fmt, iter, ops};
+
+use rowan::{NodeOrToken, WalkEvent};
+
+use crate::ast::{self, make, AstNode, AstToken};
+use crate::syntax_node::{SyntaxElement, SyntaxNode, SyntaxToken};
+use crate::ted::{self, Position};
+use crate::SyntaxKind::*;
+
+/// Width of one indentation level in the Move style.
+const INDENT_WIDTH: usize = 4;
+
+/// Indentation of a line, in levels of four spaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndentLevel(pub u8);
+
+impl From<u8> for IndentLevel {
+    fn from(level: u8) -> IndentLevel {
+        IndentLevel(level)
+    }
+}
+
+impl fmt::Display for IndentLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let spaces = "                                        ";
+        let buf;
+        let len = self.0 as usize * INDENT_WIDTH;
+        let indent = if len <= spaces.len() {
+            &spaces[..len]
+        } else {
+            buf = " ".repeat(len);
+            &buf
+        };
+        fmt::Display::fmt(indent, f)
+    }
+}
+
+impl ops::Add<u8> for IndentLevel {
+    type Output = IndentLevel;
+    fn add(self, rhs: u8) -> IndentLevel {
+        IndentLevel(self.0 + rhs)
+    }
+}
+
+impl IndentLevel {
+    pub fn single() -> IndentLevel {
+        IndentLevel(1)
+    }
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+    pub fn from_element(element: &SyntaxElement) -> IndentLevel {
+        match element {
+            NodeOrToken::Node(it) => IndentLevel::from_node(it),
+            NodeOrToken::Token(it) => IndentLevel::from_token(it),
+        }
+    }
+
+    /// Indentation of the line the node starts on.
+    pub fn from_node(node: &SyntaxNode) -> IndentLevel {
+        match node.first_token() {
+            Some(it) => Self::from_token(&it),
+            None => IndentLevel(0),
+        }
+    }
+
+    /// Indentation of the line the token is on.
+    ///
+    /// Line comments own their trailing newline, so the line after one is
+    /// indented by the whitespace token right after it, if any.
+    pub fn from_token(token: &SyntaxToken) -> IndentLevel {
+        let mut next = token.clone();
+        for prev in iter::successors(token.prev_token(), |it| it.prev_token()) {
+            if prev.kind() == WHITESPACE {
+                if let Some(pos) = prev.text().rfind('\n') {
+                    return IndentLevel::of_spaces(&prev.text()[pos + 1..]);
+                }
+            } else if prev.text().ends_with('\n') {
+                return match next.kind() {
+                    WHITESPACE => IndentLevel::of_spaces(next.text()),
+                    _ => IndentLevel(0),
+                };
+            }
+            next = prev;
+        }
+        IndentLevel(0)
+    }
+
+    fn of_spaces(text: &str) -> IndentLevel {
+        IndentLevel((text.chars().count() / INDENT_WIDTH) as u8)
+    }
+
+    /// Indents every line of the node except the first one, which is
+    /// indented by the whitespace in front of the node.
+    ///
+    /// ```text
+    /// fun f() {
+    ///     x
+    /// }
+    /// ```
+    /// becomes, with `self` at one level,
+    /// ```text
+    /// fun f() {
+    ///         x
+    ///     }
+    /// ```
+    pub(super) fn increase_indent(self, node: &SyntaxNode) {
+        for token in line_starts(node) {
+            match ast::Whitespace::cast(token.clone()) {
+                Some(ws) => {
+                    let new_ws = make::tokens::whitespace(&format!("{}{}", ws.syntax(), self));
+                    ted::replace(ws.syntax(), &new_ws);
+                }
+                None => {
+                    let ws = make::tokens::whitespace(&self.to_string());
+                    ted::insert_raw(Position::before(&token), ws);
+                }
+            }
+        }
+    }
+
+    pub(super) fn decrease_indent(self, node: &SyntaxNode) {
+        for token in line_starts(node) {
+            let ws = match ast::Whitespace::cast(token) {
+                Some(it) => it,
+                None => continue,
+            };
+            let indent = self.to_string();
+            let text = ws.text();
+            let new_text = match text.rfind('\n') {
+                Some(pos) => {
+                    let (before, after) = text.split_at(pos + 1);
+                    format!("{}{}", before, after.strip_prefix(indent.as_str()).unwrap_or(after))
+                }
+                None => text.strip_prefix(indent.as_str()).unwrap_or(text).to_string(),
+            };
+            if new_text.is_empty() {
+                ted::remove(ws.syntax());
+            } else {
+                ted::replace(ws.syntax(), make::tokens::whitespace(&new_text));
+            }
+        }
+    }
+}
+
+/// Tokens which decide the indentation of the lines of the node, except the
+/// first: whitespace containing a newline, and the first token after a line
+/// comment, unless it is a blank line.
+fn line_starts(node: &SyntaxNode) -> Vec<SyntaxToken> {
+    let mut res = Vec::new();
+    let mut prev: Option<SyntaxToken> = None;
+    let tokens = node.preorder_with_tokens().filter_map(|event| match event {
+        WalkEvent::Leave(NodeOrToken::Token(it)) => Some(it),
+        _ => None,
+    });
+    for token in tokens {
+        let after_line_comment =
+            matches!(&prev, Some(it) if it.kind() != WHITESPACE && it.text().ends_with('\n'));
+        let is_line_start = if token.kind() == WHITESPACE {
+            token.text().contains('\n') || after_line_comment
+        } else {
+            after_line_comment
+        };
+        if is_line_start {
+            res.push(token.clone());
+        }
+        prev = Some(token);
+    }
+    res
+}
+
+pub trait AstNodeEdit: AstNode + Clone + Sized {
+    fn indent_level(&self) -> IndentLevel {
+        IndentLevel::from_node(self.syntax())
+    }
+    #[must_use]
+    fn indent(&self, level: IndentLevel) -> Self {
+        fn indent_inner(node: &SyntaxNode, level: IndentLevel) -> SyntaxNode {
+            let res = node.clone_subtree().clone_for_update();
+            level.increase_indent(&res);
+            res.clone_subtree()
+        }
+
+        Self::cast(indent_inner(self.syntax(), level)).unwrap()
+    }
+    #[must_use]
+    fn dedent(&self, level: IndentLevel) -> Self {
+        fn dedent_inner(node: &SyntaxNode, level: IndentLevel) -> SyntaxNode {
+            let res = node.clone_subtree().clone_for_update();
+            level.decrease_indent(&res);
+            res.clone_subtree()
+        }
+
+        Self::cast(dedent_inner(self.syntax(), level)).unwrap()
+    }
+    #[must_use]
+    fn reset_indent(&self) -> Self {
+        let level = IndentLevel::from_node(self.syntax());
+        self.dedent(level)
+    }
+}
+
+impl<N: AstNode + Clone> AstNodeEdit for N {}
+
+#[test]
+fn test_increase_indent() {
+    let block = {
+        let block = make::block_expr(
+            vec![make::expr_stmt(make::expr_path(make::path_from_text("a")))],
+            Some(make::expr_path(make::path_from_text("b"))),
+        );
+        block.indent(IndentLevel(2))
+    };
+    assert_eq!(block.syntax().to_string(), "{\n            a;\n            b\n        }");
+}
+
+#[test]
+fn test_reset_indent_after_line_comments() {
+    let text = "module M {\n    fun f() {\n        // comment\n        x;\n    }\n}";
+    let file = crate::SourceFile::parse(text).tree();
+    let fun = file.syntax().descendants().find_map(ast::FunctionDef::cast).unwrap();
+    assert_eq!(fun.indent_level(), IndentLevel(1));
+    let fun = fun.reset_indent();
+    assert_eq!(fun.syntax().to_string(), "fun f() {\n    // comment\n    x;\n}");
+    let fun = fun.indent(IndentLevel(2));
+    assert_eq!(
+        fun.syntax().to_string(),
+        "fun f() {\n            // comment\n            x;\n        }"
+    );
+}
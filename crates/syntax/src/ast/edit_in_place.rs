@@ -0,0 +1,334 @@
+//! Structural editing of the mutable AST: the nodes are changed in place, and
+//! the whitespace and commas around them are fixed up.
+//!
+//! New nodes must be mutable too, and already indented for their place.
+use std::iter;
+
+use rowan::Direction;
+
+use parser::T;
+
+use crate::ast::edit::IndentLevel;
+use crate::ast::{self, make, AstNode, HasModuleItem};
+use crate::syntax_node::{SyntaxElement, SyntaxNode, SyntaxToken};
+use crate::ted::{self, Position};
+use crate::SyntaxKind::{self, *};
+
+impl ast::ItemList {
+    /// Adds the item after the last one, on its own line. Items other than
+    /// `use`s are separated by a blank line.
+    pub fn add_item(&self, item: ast::Item) {
+        let (indent, position, whitespace) = match self.items().last() {
+            Some(last_item) => {
+                let both_uses =
+                    matches!((&last_item, &item), (ast::Item::UseDecl(_), ast::Item::UseDecl(_)));
+                let whitespace = if both_uses { "\n" } else { "\n\n" };
+                (
+                    IndentLevel::from_node(last_item.syntax()),
+                    Position::after(last_item.syntax()),
+                    whitespace,
+                )
+            }
+            None => match self.l_brace_token() {
+                Some(l_brace) => {
+                    normalize_ws_between_braces(self.syntax());
+                    (IndentLevel::from_token(&l_brace) + 1, Position::after(&l_brace), "\n")
+                }
+                None => (IndentLevel::single(), Position::last_child_of(self.syntax()), "\n"),
+            },
+        };
+        let elements: Vec<SyntaxElement> = vec![
+            make::tokens::whitespace(&format!("{}{}", whitespace, indent)).into(),
+            item.syntax().clone().into(),
+        ];
+        ted::insert_all(position, elements);
+    }
+}
+
+impl ast::RecordFieldList {
+    /// Adds the field after the last one. In a multiline list the field goes
+    /// on its own line and gets a trailing comma.
+    pub fn add_field(&self, field: ast::RecordField) {
+        let is_multiline = self.syntax().text().contains_char('\n');
+        let position = match self.record_fields().last() {
+            Some(last_field) => Position::after(get_or_insert_comma_after(last_field.syntax())),
+            None => match self.l_brace_token() {
+                Some(it) => Position::after(it),
+                None => Position::last_child_of(self.syntax()),
+            },
+        };
+        if is_multiline {
+            let indent = IndentLevel::from_node(self.syntax()) + 1;
+            let whitespace = make::tokens::whitespace(&format!("\n{}", indent));
+            let comma = make::token(T![,]);
+            ted::insert_all_raw(
+                position,
+                vec![whitespace.into(), field.syntax().clone().into(), comma.into()],
+            );
+        } else {
+            ted::insert(position, field.syntax());
+        }
+    }
+}
+
+impl ast::ParamList {
+    pub fn add_param(&self, param: ast::Param) {
+        let position = match self.params().last() {
+            Some(last_param) => Position::after(get_or_insert_comma_after(last_param.syntax())),
+            None => match self.l_paren_token() {
+                Some(it) => Position::after(it),
+                None => Position::last_child_of(self.syntax()),
+            },
+        };
+        ted::insert(position, param.syntax());
+    }
+}
+
+pub trait Removable: AstNode {
+    fn remove(&self);
+}
+
+impl Removable for ast::Item {
+    /// Removes the item along with the line break in front of it, or after it
+    /// if it is followed by another item, so that no blank line is left.
+    fn remove(&self) {
+        let next_ws =
+            self.syntax().next_sibling_or_token().filter(|it| it.kind() == WHITESPACE).filter(
+                |it| !matches!(it.next_sibling_or_token(), Some(next) if next.kind() == T!['}']),
+            );
+        match next_ws {
+            Some(ws) => ted::remove(ws),
+            None => {
+                if let Some(ws) =
+                    self.syntax().prev_sibling_or_token().filter(|it| it.kind() == WHITESPACE)
+                {
+                    ted::remove(ws);
+                }
+            }
+        }
+        ted::remove(self.syntax());
+    }
+}
+
+impl Removable for ast::RecordField {
+    fn remove(&self) {
+        remove_list_element(self.syntax());
+    }
+}
+
+impl Removable for ast::Param {
+    fn remove(&self) {
+        remove_list_element(self.syntax());
+    }
+}
+
+/// Removes an element of a comma separated list, along with one of the
+/// commas and the whitespace next to it.
+fn remove_list_element(node: &SyntaxNode) {
+    let mut first: SyntaxElement = node.clone().into();
+    let mut last = first.clone();
+    match non_trivia_sibling(&last, Direction::Next).filter(|it| it.kind() == T![,]) {
+        Some(comma) => {
+            last = comma;
+            let next_ws = last.next_sibling_or_token().filter(|it| it.kind() == WHITESPACE);
+            match next_ws {
+                Some(ws) if matches!(ws.next_sibling_or_token(), Some(it) if !is_closing(it.kind())) =>
+                {
+                    last = ws;
+                }
+                _ => {
+                    if let Some(ws) =
+                        first.prev_sibling_or_token().filter(|it| it.kind() == WHITESPACE)
+                    {
+                        first = ws;
+                    }
+                }
+            }
+        }
+        None => match non_trivia_sibling(&first, Direction::Prev).filter(|it| it.kind() == T![,]) {
+            Some(comma) => first = comma,
+            None => {
+                if let Some(ws) = first.prev_sibling_or_token().filter(|it| it.kind() == WHITESPACE)
+                {
+                    first = ws;
+                }
+            }
+        },
+    }
+    ted::remove_all(first..=last);
+}
+
+fn non_trivia_sibling(element: &SyntaxElement, direction: Direction) -> Option<SyntaxElement> {
+    let step = |it: &SyntaxElement| match direction {
+        Direction::Next => it.next_sibling_or_token(),
+        Direction::Prev => it.prev_sibling_or_token(),
+    };
+    iter::successors(step(element), step).find(|it| !it.kind().is_trivia())
+}
+
+fn is_closing(kind: SyntaxKind) -> bool {
+    matches!(kind, T![')'] | T!['}'] | T![>])
+}
+
+fn get_or_insert_comma_after(syntax: &SyntaxNode) -> SyntaxToken {
+    let element: SyntaxElement = syntax.clone().into();
+    let comma = non_trivia_sibling(&element, Direction::Next)
+        .and_then(|it| it.into_token())
+        .filter(|it| it.kind() == T![,]);
+    match comma {
+        Some(it) => it,
+        None => {
+            let comma = make::token(T![,]);
+            ted::insert_raw(Position::after(syntax), &comma);
+            comma
+        }
+    }
+}
+
+/// Puts the closing brace of an empty block on its own line.
+fn normalize_ws_between_braces(node: &SyntaxNode) -> Option<()> {
+    let l = node
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|it| it.kind() == T!['{'])?;
+    let r = node
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|it| it.kind() == T!['}'])?;
+
+    let indent = IndentLevel::from_node(node);
+
+    match l.next_sibling_or_token() {
+        Some(ws) if ws.kind() == WHITESPACE && ws.next_sibling_or_token()?.into_token()? == r => {
+            ted::replace(ws, make::tokens::whitespace(&format!("\n{}", indent)));
+        }
+        Some(ws) if ws.kind() == T!['}'] => {
+            ted::insert_raw(Position::after(l), make::tokens::whitespace(&format!("\n{}", indent)));
+        }
+        _ => (),
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::assert_eq_text;
+
+    use super::*;
+    use crate::ast::edit::AstNodeEdit;
+    use crate::SourceFile;
+
+    fn check<N: AstNode>(before: &str, edit: impl FnOnce(&N), after: &str) {
+        let file = SourceFile::parse(before).tree().syntax().clone_for_update();
+        let node = file.descendants().find_map(N::cast).unwrap();
+        edit(&node);
+        assert_eq_text!(after, &file.to_string());
+    }
+
+    #[test]
+    fn add_item() {
+        let use_decl = || {
+            let path = make::path_from_text("0x1::Signer");
+            ast::Item::from(make::use_decl(make::use_tree(path, None, None))).clone_for_update()
+        };
+        check(
+            "module M {}",
+            |list: &ast::ItemList| list.add_item(use_decl()),
+            "module M {\n    use 0x1::Signer;\n}",
+        );
+        check(
+            "address 0x1 {\nmodule M {\n    use 0x1::Vector;\n}\n}",
+            |list: &ast::ItemList| list.add_item(use_decl()),
+            "address 0x1 {\nmodule M {\n    use 0x1::Vector;\n    use 0x1::Signer;\n}\n}",
+        );
+        check(
+            "module M {\n    struct S {}\n}",
+            |list: &ast::ItemList| {
+                let fun = make::fun(
+                    Some(make::visibility_public()),
+                    make::name("f"),
+                    None,
+                    make::param_list(vec![make::param(make::name("s"), make::ty("&S"))]),
+                    Some(make::ret_type(make::ty("u64"))),
+                    make::block_expr(None, Some(make::expr_literal("0"))),
+                );
+                let fun = ast::Item::from(fun).indent(IndentLevel(1)).clone_for_update();
+                list.add_item(fun)
+            },
+            "module M {\n    struct S {}\n\n    public fun f(s: &S): u64 {\n        0\n    }\n}",
+        );
+    }
+
+    #[test]
+    fn add_field() {
+        let field = || make::struct_field(make::name("b"), make::ty("u64")).clone_for_update();
+        check(
+            "module M { struct S {} }",
+            |list: &ast::RecordFieldList| list.add_field(field()),
+            "module M { struct S { b: u64 } }",
+        );
+        check(
+            "module M { struct S { a: u8 } }",
+            |list: &ast::RecordFieldList| list.add_field(field()),
+            "module M { struct S { a: u8, b: u64 } }",
+        );
+        check(
+            "module M {\n    struct S {\n        a: u8\n    }\n}",
+            |list: &ast::RecordFieldList| list.add_field(field()),
+            "module M {\n    struct S {\n        a: u8,\n        b: u64,\n    }\n}",
+        );
+    }
+
+    #[test]
+    fn add_param() {
+        let param = || make::param(make::name("b"), make::ty("&mut T")).clone_for_update();
+        check(
+            "module M { fun f() {} }",
+            |list: &ast::ParamList| list.add_param(param()),
+            "module M { fun f(b: &mut T) {} }",
+        );
+        check(
+            "module M { fun f(a: u8) {} }",
+            |list: &ast::ParamList| list.add_param(param()),
+            "module M { fun f(a: u8, b: &mut T) {} }",
+        );
+    }
+
+    #[test]
+    fn remove() {
+        check(
+            "module M {\n    use 0x1::A;\n    use 0x1::B;\n}",
+            |item: &ast::Item| item.remove(),
+            "module M {\n    use 0x1::B;\n}",
+        );
+        check(
+            "module M {\n    fun f() {}\n\n    fun g() {}\n}",
+            |item: &ast::Item| {
+                let last = item.syntax().parent().unwrap().children().last().unwrap();
+                ast::Item::cast(last).unwrap().remove()
+            },
+            "module M {\n    fun f() {}\n}",
+        );
+        check(
+            "module M { fun f(a: u8, b: u64) {} }",
+            |param: &ast::Param| param.remove(),
+            "module M { fun f(b: u64) {} }",
+        );
+        check(
+            "module M { struct S { a: u8, b: u64 } }",
+            |field: &ast::RecordField| {
+                let last = field.syntax().next_sibling().unwrap();
+                ast::RecordField::cast(last).unwrap().remove()
+            },
+            "module M { struct S { a: u8 } }",
+        );
+        check(
+            "module M {\n    struct S {\n        a: u8,\n        b: u64,\n    }\n}",
+            |field: &ast::RecordField| {
+                let last = field.syntax().next_sibling().unwrap();
+                ast::RecordField::cast(last).unwrap().remove()
+            },
+            "module M {\n    struct S {\n        a: u8,\n    }\n}",
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! Various extension methods to ast expression nodes, which are hard to code-generate.
+//!
+//! Nodes with several `Expr` children can't tell them apart by kind, so the
+//! accessors here pick them by position.
+use crate::ast::{self, support, AstChildren, AstNode};
+use crate::syntax_node::SyntaxToken;
+use crate::SyntaxKind;
+
+impl ast::IfExpr {
+    pub fn then_branch(&self) -> Option<ast::Expr> {
+        self.exprs().nth(1)
+    }
+    pub fn else_branch(&self) -> Option<ast::Expr> {
+        self.else_token()?;
+        self.exprs().nth(2)
+    }
+    fn exprs(&self) -> AstChildren<ast::Expr> {
+        support::children(self.syntax())
+    }
+}
+
+impl ast::WhileExpr {
+    pub fn body(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl ast::LoopExpr {
+    pub fn body(&self) -> Option<ast::Expr> {
+        support::child(self.syntax())
+    }
+}
+
+impl ast::IndexExpr {
+    pub fn base(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).next()
+    }
+    pub fn index(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl ast::QuantExpr {
+    pub fn predicate(&self) -> Option<ast::Expr> {
+        self.where_token()?;
+        self.exprs().next()
+    }
+    pub fn body(&self) -> Option<ast::Expr> {
+        self.colon_token()?;
+        self.exprs().last()
+    }
+    fn exprs(&self) -> AstChildren<ast::Expr> {
+        support::children(self.syntax())
+    }
+}
+
+impl ast::BinExpr {
+    pub fn lhs(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).next()
+    }
+    pub fn rhs(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+    /// The operator, the only token of the node which isn't trivia.
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|it| !it.kind().is_trivia())
+    }
+    pub fn op_kind(&self) -> Option<SyntaxKind> {
+        self.op_token().map(|it| it.kind())
+    }
+}
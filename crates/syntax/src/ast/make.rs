@@ -0,0 +1,272 @@
+//! Factories for AST nodes.
+//!
+//! Nodes are built by parsing a small piece of Move code with the fragments
+//! spliced in, so they are always well formed. They are immutable and start at
+//! indentation level zero: call `clone_for_update` before inserting them into
+//! a tree with `ted`, and `indent` to fit them in their new place.
+use stdx::format_to;
+
+use crate::ast::{self, AstNode};
+use crate::syntax_node::SyntaxToken;
+use crate::{SourceFile, SyntaxKind};
+
+pub fn name(text: &str) -> ast::Name {
+    ast_from_text(&format!("module {} {{}}", text))
+}
+
+pub fn name_ref(text: &str) -> ast::NameRef {
+    ast_from_text(&format!("script {{ fun f() {{ {}; }} }}", text))
+}
+
+/// A path like `0x1::Vector`, `Std::Signer::address_of` or `Self::S`.
+pub fn path_from_text(text: &str) -> ast::Path {
+    ast_from_text(&format!("script {{ fun f() {{ {}; }} }}", text))
+}
+
+pub fn ty(text: &str) -> ast::Type {
+    ast_from_text(&format!("script {{ fun f(x: {}) {{}} }}", text))
+}
+
+pub fn use_decl(use_tree: ast::UseTree) -> ast::UseDecl {
+    ast_from_text(&format!("module M {{ use {}; }}", use_tree))
+}
+
+pub fn use_tree(
+    path: ast::Path,
+    use_tree_list: Option<ast::UseTreeList>,
+    alias: Option<ast::Rename>,
+) -> ast::UseTree {
+    let mut buf = "use ".to_string();
+    buf += &path.syntax().to_string();
+    if let Some(use_tree_list) = use_tree_list {
+        format_to!(buf, "::{}", use_tree_list);
+    }
+    if let Some(alias) = alias {
+        format_to!(buf, " {}", alias);
+    }
+    ast_from_text(&format!("module M {{ {}; }}", buf))
+}
+
+pub fn use_tree_list(use_trees: impl IntoIterator<Item = ast::UseTree>) -> ast::UseTreeList {
+    let use_trees = join(use_trees, ", ");
+    ast_from_text(&format!("module M {{ use 0x1::M::{{{}}}; }}", use_trees))
+}
+
+pub fn rename(name: ast::Name) -> ast::Rename {
+    ast_from_text(&format!("module M {{ use 0x1::M as {}; }}", name))
+}
+
+pub fn visibility_public() -> ast::Visibility {
+    ast_from_text("module M { public fun f() {} }")
+}
+
+pub fn visibility_friend() -> ast::Visibility {
+    ast_from_text("module M { public(friend) fun f() {} }")
+}
+
+pub fn ability(text: &str) -> ast::Ability {
+    ast_from_text(&format!("module M {{ struct S has {} {{}} }}", text))
+}
+
+pub fn ability_list(abilities: impl IntoIterator<Item = ast::Ability>) -> ast::AbilityList {
+    let abilities = join(abilities, ", ");
+    ast_from_text(&format!("module M {{ struct S has {} {{}} }}", abilities))
+}
+
+pub fn type_param(
+    name: ast::Name,
+    bounds: impl IntoIterator<Item = ast::Ability>,
+) -> ast::TypeParam {
+    let bounds = join(bounds, " + ");
+    let bounds = if bounds.is_empty() { String::new() } else { format!(": {}", bounds) };
+    ast_from_text(&format!("module M {{ fun f<{}{}>() {{}} }}", name, bounds))
+}
+
+pub fn generic_param_list(
+    params: impl IntoIterator<Item = ast::TypeParam>,
+) -> ast::GenericParamList {
+    let params = join(params, ", ");
+    ast_from_text(&format!("module M {{ fun f<{}>() {{}} }}", params))
+}
+
+pub fn param(name: ast::Name, ty: ast::Type) -> ast::Param {
+    ast_from_text(&format!("module M {{ fun f({}: {}) {{}} }}", name, ty))
+}
+
+pub fn param_list(params: impl IntoIterator<Item = ast::Param>) -> ast::ParamList {
+    let params = join(params, ", ");
+    ast_from_text(&format!("module M {{ fun f({}) {{}} }}", params))
+}
+
+pub fn ret_type(ty: ast::Type) -> ast::RetType {
+    ast_from_text(&format!("module M {{ fun f(): {} {{}} }}", ty))
+}
+
+pub fn fun(
+    visibility: Option<ast::Visibility>,
+    name: ast::Name,
+    type_params: Option<ast::GenericParamList>,
+    params: ast::ParamList,
+    ret_type: Option<ast::RetType>,
+    body: ast::BlockExpr,
+) -> ast::FunctionDef {
+    let visibility = match visibility {
+        Some(it) => format!("{} ", it),
+        None => String::new(),
+    };
+    let type_params = type_params.map(|it| it.to_string()).unwrap_or_default();
+    let ret_type = ret_type.map(|it| it.to_string()).unwrap_or_default();
+    ast_from_text(&format!(
+        "module M {{\n{}fun {}{}{}{} {}\n}}",
+        visibility, name, type_params, params, ret_type, body
+    ))
+}
+
+/// A field of a struct definition, like `value: u64`.
+pub fn struct_field(name: ast::Name, ty: ast::Type) -> ast::RecordField {
+    ast_from_text(&format!("module M {{ struct S {{ {}: {} }} }}", name, ty))
+}
+
+pub fn struct_field_list(
+    fields: impl IntoIterator<Item = ast::RecordField>,
+) -> ast::RecordFieldList {
+    let fields = join(fields, ", ");
+    let fields = if fields.is_empty() { String::new() } else { format!(" {} ", fields) };
+    ast_from_text(&format!("module M {{ struct S {{{}}} }}", fields))
+}
+
+pub fn struct_(
+    name: ast::Name,
+    type_params: Option<ast::GenericParamList>,
+    abilities: Option<ast::AbilityList>,
+    fields: ast::RecordFieldList,
+) -> ast::Struct {
+    let type_params = type_params.map(|it| it.to_string()).unwrap_or_default();
+    let abilities = match abilities {
+        Some(it) => format!(" {}", it),
+        None => String::new(),
+    };
+    ast_from_text(&format!(
+        "module M {{\nstruct {}{}{} {}\n}}",
+        name, type_params, abilities, fields
+    ))
+}
+
+pub fn block_expr(
+    stmts: impl IntoIterator<Item = ast::Stmt>,
+    tail_expr: Option<ast::Expr>,
+) -> ast::BlockExpr {
+    let mut buf = "{\n".to_string();
+    for stmt in stmts.into_iter() {
+        format_to!(buf, "    {}\n", stmt);
+    }
+    if let Some(tail_expr) = tail_expr {
+        format_to!(buf, "    {}\n", tail_expr);
+    }
+    buf += "}";
+    ast_from_text(&format!("module M {{\nfun f() {}\n}}", buf))
+}
+
+pub fn expr_stmt(expr: ast::Expr) -> ast::Stmt {
+    let stmt: ast::ExprStmt = ast_from_text(&format!("script {{ fun f() {{ {}; }} }}", expr));
+    stmt.into()
+}
+
+pub fn let_stmt(
+    pattern: ast::Pat,
+    ty: Option<ast::Type>,
+    initializer: Option<ast::Expr>,
+) -> ast::Stmt {
+    let mut text = format!("let {}", pattern);
+    if let Some(ty) = ty {
+        format_to!(text, ": {}", ty);
+    }
+    if let Some(initializer) = initializer {
+        format_to!(text, " = {}", initializer);
+    }
+    let stmt: ast::LetStmt = ast_from_text(&format!("script {{ fun f() {{ {}; }} }}", text));
+    stmt.into()
+}
+
+pub fn ident_pat(name: ast::Name) -> ast::Pat {
+    let pat: ast::IdentPat = ast_from_text(&format!("script {{ fun f() {{ let {}; }} }}", name));
+    pat.into()
+}
+
+pub fn expr_path(path: ast::Path) -> ast::Expr {
+    expr_from_text(&path.to_string())
+}
+
+/// A literal like `1`, `true`, `@0x1` or `b"bytes"`.
+pub fn expr_literal(text: &str) -> ast::Expr {
+    expr_from_text(text)
+}
+
+pub fn expr_call(f: ast::Expr, arg_list: ast::ArgList) -> ast::Expr {
+    expr_from_text(&format!("{}{}", f, arg_list))
+}
+
+pub fn arg_list(args: impl IntoIterator<Item = ast::Expr>) -> ast::ArgList {
+    ast_from_text(&format!("script {{ fun f() {{ g({}); }} }}", join(args, ", ")))
+}
+
+fn expr_from_text(text: &str) -> ast::Expr {
+    // The body of the function is an expression too, so look into the statement.
+    let stmt: ast::ExprStmt = ast_from_text(&format!("script {{ fun f() {{ {}; }} }}", text));
+    stmt.expr().unwrap().clone_subtree()
+}
+
+fn join(items: impl IntoIterator<Item = impl ToString>, sep: &str) -> String {
+    items.into_iter().map(|it| it.to_string()).collect::<Vec<_>>().join(sep)
+}
+
+/// Parses `text` and returns its first node of type `N`.
+fn ast_from_text<N: AstNode>(text: &str) -> N {
+    let parse = SourceFile::parse(text);
+    let node = match parse.tree().syntax().descendants().find_map(N::cast) {
+        Some(it) => it,
+        None => {
+            panic!("Failed to make ast node `{}` from text {}", std::any::type_name::<N>(), text)
+        }
+    };
+    let node = node.clone_subtree();
+    assert_eq!(node.syntax().text_range().start(), 0.into());
+    node
+}
+
+/// Creates a mutable token of the given kind, with its canonical text.
+pub fn token(kind: SyntaxKind) -> SyntaxToken {
+    let file = SourceFile::parse(tokens::SOURCE).tree().syntax().clone_for_update();
+    file.descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|it| it.kind() == kind)
+        .unwrap_or_else(|| panic!("unhandled token: {:?}", kind))
+}
+
+pub mod tokens {
+    use crate::ast::{AstNode, SourceFile};
+    use crate::syntax_node::SyntaxToken;
+
+    /// A piece of code with every token which is worth making on its own.
+    pub(super) const SOURCE: &str = "module M { use 0x1::M; \
+        fun f<T: copy + drop>(a: &mut T, b: u64): (u64, bool) acquires R { \
+        let x = !a; *b = x + 1; (x, true) } }\n\n";
+
+    pub fn single_space() -> SyntaxToken {
+        whitespace(" ")
+    }
+
+    pub fn whitespace(text: &str) -> SyntaxToken {
+        assert!(text.trim().is_empty());
+        let file = SourceFile::parse(text).tree().syntax().clone_for_update();
+        file.first_child_or_token().unwrap().into_token().unwrap()
+    }
+
+    pub fn single_newline() -> SyntaxToken {
+        whitespace("\n")
+    }
+
+    pub fn blank_line() -> SyntaxToken {
+        whitespace("\n\n")
+    }
+}
@@ -0,0 +1,19 @@
+//! Various extension methods to ast nodes, which are hard to code-generate.
+use smol_str::SmolStr;
+
+use crate::ast::{self, HasAttrs};
+
+impl ast::Attr {
+    /// The name of the attribute, e.g. `test` for `#[test(account = @0x1)]`.
+    pub fn simple_name(&self) -> Option<SmolStr> {
+        let ident = self.name_ref()?.ident_token()?;
+        Some(SmolStr::new(ident.text()))
+    }
+}
+
+impl ast::FunctionDef {
+    /// Whether the function is a unit test, i.e. is marked with `#[test]`.
+    pub fn is_test(&self) -> bool {
+        self.has_attr("test")
+    }
+}
@@ -0,0 +1,44 @@
+//! There are many AstNodes, but only a few tokens, so we hand-write them here.
+
+use crate::ast::{self, AstToken};
+
+impl ast::DocComment {
+    /// Whether this is a `/** */` comment rather than a `///` one.
+    pub fn is_block(&self) -> bool {
+        self.text().starts_with("/**")
+    }
+
+    /// The text of the comment without the `///` or `/** */` markers.
+    pub fn text_without_prefix(&self) -> &str {
+        let text = self.text();
+        match text.strip_prefix("/**") {
+            Some(block) => block.strip_suffix("*/").unwrap_or(block),
+            None => text.trim_start_matches("///").trim_end_matches('\n'),
+        }
+    }
+
+    /// Lines of the comment as they would be rendered: the space after `///`
+    /// and the `*` decorating block comment lines are stripped, as are the
+    /// blank first and last lines of a block comment.
+    pub fn doc_lines(&self) -> Vec<&str> {
+        let text = self.text_without_prefix();
+        if !self.is_block() {
+            return vec![text.strip_prefix(' ').unwrap_or(text).trim_end()];
+        }
+        let mut lines: Vec<&str> = text
+            .lines()
+            .map(|line| {
+                let line = line.trim();
+                let line = line.strip_prefix('*').unwrap_or(line);
+                line.strip_prefix(' ').unwrap_or(line)
+            })
+            .collect();
+        if lines.first() == Some(&"") {
+            lines.remove(0);
+        }
+        if lines.last() == Some(&"") {
+            lines.pop();
+        }
+        lines
+    }
+}
@@ -0,0 +1,82 @@
+//! Various traits that are implemented by ast nodes.
+//!
+//! The implementations are usually trivial, and live in the generated `nodes`
+//! module.
+use std::iter;
+
+use crate::ast::{self, support, AstChildren, AstNode, AstToken};
+use crate::syntax_node::SyntaxElementChildren;
+
+pub trait HasName: AstNode {
+    fn name(&self) -> Option<ast::Name> {
+        support::child(self.syntax())
+    }
+}
+
+pub trait HasVisibility: AstNode {
+    fn visibility(&self) -> Option<ast::Visibility> {
+        support::child(self.syntax())
+    }
+}
+
+pub trait HasTypeParams: AstNode {
+    fn generic_param_list(&self) -> Option<ast::GenericParamList> {
+        support::child(self.syntax())
+    }
+}
+
+pub trait HasModuleItem: AstNode {
+    fn items(&self) -> AstChildren<ast::Item> {
+        support::children(self.syntax())
+    }
+}
+
+pub trait HasAttrs: AstNode {
+    fn attr_lists(&self) -> AstChildren<ast::AttrList> {
+        support::children(self.syntax())
+    }
+    /// All attributes of the node, across every `#[...]` list.
+    fn attrs(&self) -> AttrsIter {
+        fn list_attrs(list: ast::AttrList) -> AstChildren<ast::Attr> {
+            list.attrs()
+        }
+        self.attr_lists().flat_map(list_attrs as fn(_) -> _)
+    }
+    fn has_attr(&self, name: &str) -> bool {
+        self.attrs().any(|attr| attr.simple_name().as_deref() == Some(name))
+    }
+}
+
+pub type AttrsIter = iter::FlatMap<
+    AstChildren<ast::AttrList>,
+    AstChildren<ast::Attr>,
+    fn(ast::AttrList) -> AstChildren<ast::Attr>,
+>;
+
+pub trait HasDocComments: AstNode {
+    /// Doc comments directly preceding the node, which the tree builder
+    /// attaches to it.
+    fn doc_comments(&self) -> DocCommentIter {
+        DocCommentIter { iter: self.syntax().children_with_tokens() }
+    }
+    /// Text of the doc comments without the comment markers.
+    fn doc_comment_text(&self) -> Option<String> {
+        let comments: Vec<ast::DocComment> = self.doc_comments().collect();
+        if comments.is_empty() {
+            return None;
+        }
+        let lines: Vec<&str> = comments.iter().flat_map(|it| it.doc_lines()).collect();
+        Some(lines.join("\n"))
+    }
+}
+
+pub struct DocCommentIter {
+    iter: SyntaxElementChildren,
+}
+
+impl Iterator for DocCommentIter {
+    type Item = ast::DocComment;
+    fn next(&mut self) -> Option<ast::DocComment> {
+        self.iter.by_ref().find_map(|el| el.into_token().and_then(ast::DocComment::cast))
+    }
+}
@@ -0,0 +1,204 @@
+//! Move dialects. They share the syntax, but differ in the size of account
+//! addresses and in the textual forms an address literal may take.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// 16-byte addresses, written in hex.
+    Diem,
+    /// 20-byte addresses, written in hex or bech32 (`wallet1...`).
+    DFinance,
+    /// 32-byte addresses, written in hex or ss58 (`5GrwvaEF...`).
+    Pontem,
+}
+
+impl Default for Dialect {
+    /// Same as `dove` when `Dove.toml` doesn't specify a dialect.
+    fn default() -> Dialect {
+        Dialect::Pontem
+    }
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Dialect::Diem => "diem",
+            Dialect::DFinance => "dfinance",
+            Dialect::Pontem => "pont",
+        };
+        f.write_str(name)
+    }
+}
+
+const BECH32_HRP: &str = "wallet";
+const BECH32_PREFIX: &str = "wallet1";
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+impl Dialect {
+    /// Parses the `dialect` key of the `[package]` section of `Dove.toml`.
+    pub fn from_name(name: &str) -> Option<Dialect> {
+        let dialect = match name {
+            "diem" => Dialect::Diem,
+            "dfinance" => Dialect::DFinance,
+            "pont" | "pontem" => Dialect::Pontem,
+            _ => return None,
+        };
+        Some(dialect)
+    }
+
+    /// Size of an account address in bytes.
+    pub fn address_length(self) -> usize {
+        match self {
+            Dialect::Diem => 16,
+            Dialect::DFinance => 20,
+            Dialect::Pontem => 32,
+        }
+    }
+
+    /// Whether a name used as an address is in fact an address literal, like
+    /// bech32 addresses which lex as identifiers.
+    pub fn is_address_literal_name(self, name: &str) -> bool {
+        self == Dialect::DFinance && name.starts_with(BECH32_PREFIX)
+    }
+
+    /// Checks the text of an address literal against the rules of the
+    /// dialect, returning why it is invalid. Malformed hex is a syntax error in
+    /// any dialect, so it is left to the validation done while parsing.
+    pub fn check_address(self, text: &str) -> Result<(), String> {
+        let max_len = self.address_length();
+        if let Some(hex) = text.strip_prefix("0x") {
+            if hex.len() > max_len * 2 {
+                return Err(format!(
+                    "address is too long, `{}` addresses are at most {} bytes",
+                    self, max_len
+                ));
+            }
+            return Ok(());
+        }
+        match self {
+            Dialect::DFinance if text.starts_with(BECH32_PREFIX) => {
+                match bech32_payload(text).map(|it| it.len()) {
+                    Some(len) if len == max_len => Ok(()),
+                    Some(_) => Err(format!("bech32 address must encode {} bytes", max_len)),
+                    None => Err("invalid bech32 address".to_string()),
+                }
+            }
+            // Only the shape of an ss58 address is checked: verifying its
+            // checksum needs blake2b.
+            Dialect::Pontem if text.len() > max_len => match base58_decode(text).map(|it| it.len())
+            {
+                // one or two bytes of address type prefix, two bytes of checksum
+                Some(len) if len == max_len + 3 || len == max_len + 4 => Ok(()),
+                Some(_) => Err(format!("ss58 address must encode {} bytes", max_len)),
+                None => Err("invalid ss58 address".to_string()),
+            },
+            _ => Err(format!("`{}` addresses must be written in hex, like `0x1`", self)),
+        }
+    }
+
+    /// The account bytes of an address literal in any of the forms of the
+    /// dialects: hex, bech32 or ss58, without leading zero bytes for hex.
+    /// Returns `None` if the text isn't a valid address in any form.
+    pub fn decode_address(text: &str) -> Option<Vec<u8>> {
+        if let Some(hex) = text.strip_prefix("0x") {
+            if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            let hex = if hex.len() % 2 == 1 { format!("0{}", hex) } else { hex.to_string() };
+            let bytes = (0..hex.len())
+                .step_by(2)
+                .map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).unwrap())
+                .collect();
+            return Some(bytes);
+        }
+        if text.starts_with(BECH32_PREFIX) {
+            return bech32_payload(text);
+        }
+        ss58_account(text)
+    }
+}
+
+/// The bytes encoded by a valid bech32 string with our human readable part,
+/// or `None` if the string or its checksum is invalid.
+fn bech32_payload(text: &str) -> Option<Vec<u8>> {
+    let (hrp, data) = text.rsplit_once('1')?;
+    if hrp != BECH32_HRP || data.len() < 6 {
+        return None;
+    }
+    let mut values: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+    values.push(0);
+    values.extend(hrp.bytes().map(|b| b & 31));
+    for c in data.chars() {
+        values.push(BECH32_CHARSET.find(c)? as u8);
+    }
+    if bech32_polymod(&values) != 1 {
+        return None;
+    }
+    // The data without the checksum is in groups of 5 bits.
+    let groups = &values[values.len() - data.len()..values.len() - 6];
+    let mut bytes = Vec::new();
+    let (mut acc, mut bits) = (0u32, 0);
+    for &group in groups {
+        acc = (acc << 5) | group as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((acc >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GEN: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+    let mut chk: u32 = 1;
+    for &v in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ffffff) << 5) ^ v as u32;
+        for (i, gen) in GEN.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= gen;
+            }
+        }
+    }
+    chk
+}
+
+/// The account of an ss58 address: its base58 bytes without the one or two
+/// bytes of address type prefix and the two bytes of checksum.
+fn ss58_account(text: &str) -> Option<Vec<u8>> {
+    let bytes = base58_decode(text)?;
+    let prefix_len = match *bytes.first()? {
+        0..=63 => 1,
+        64..=127 => 2,
+        _ => return None,
+    };
+    if bytes.len() <= prefix_len + 2 {
+        return None;
+    }
+    Some(bytes[prefix_len..bytes.len() - 2].to_vec())
+}
+
+/// The bytes of a base58 string, or `None` if it isn't base58.
+fn base58_decode(text: &str) -> Option<Vec<u8>> {
+    // big-endian base 256 digits of the number, without leading zeros
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in text.chars() {
+        let mut carry = BASE58_ALPHABET.find(c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    let leading_zeros = text.chars().take_while(|&c| c == '1').count();
+    let mut res = vec![0; leading_zeros];
+    res.extend(bytes);
+    Some(res)
+}
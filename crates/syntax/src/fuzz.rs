@@ -0,0 +1,114 @@
+//! Invariants of the parser, checked on arbitrary input by the fuzz targets
+//! in `crates/syntax/fuzz` and on the inputs they found to fail by
+//! `cargo test`.
+//!
+//! The fuzz targets are run with `cargo fuzz` from `crates/syntax`, seeding
+//! the corpus with the parser tests so that the fuzzer mutates real Move code:
+//!
+//! ```text
+//! cargo +nightly fuzz run parser fuzz/corpus/parser test_data/parser/ok test_data/parser/err
+//! ```
+//!
+//! An input which makes a target fail goes to `test_data/fuzz-failures`.
+use std::str;
+
+use text_edit::Indel;
+
+use crate::{AstNode, Dialect, SourceFile, TextRange, TextSize};
+
+/// Parses `text` and checks that the tree covers it exactly and that every
+/// error points into it.
+pub fn check_parser(text: &str) {
+    let parse = SourceFile::parse(text);
+    let root = parse.tree().syntax().clone();
+    assert_eq!(root.text().to_string(), text, "tree text differs from the parsed text");
+    assert_eq!(root.text_range(), TextRange::up_to(TextSize::of(text)));
+
+    for err in parse.errors() {
+        let range = err.range();
+        assert!(
+            range.end() <= TextSize::of(text),
+            "error `{}` at {:?} is outside of the file of length {}",
+            err,
+            range,
+            text.len(),
+        );
+        assert!(
+            text.is_char_boundary(range.start().into())
+                && text.is_char_boundary(range.end().into()),
+            "error `{}` at {:?} splits a character",
+            err,
+            range,
+        );
+    }
+}
+
+/// An edit of a file, decoded from fuzzer data.
+#[derive(Debug, Clone)]
+pub struct CheckReparse {
+    text: String,
+    edit: Indel,
+    edited_text: String,
+}
+
+impl CheckReparse {
+    /// Decodes an edit from `data` in the format
+    ///
+    /// ```text
+    /// <offset> <length of the deleted range>
+    /// <inserted text, with `\n` for newlines>
+    /// <the text of the file>
+    /// ```
+    ///
+    /// Returns `None` if the data doesn't describe an edit of the file.
+    pub fn from_data(data: &[u8]) -> Option<CheckReparse> {
+        let data = str::from_utf8(data).ok()?;
+        let (header, rest) = data.split_once('\n')?;
+        let (insert, text) = rest.split_once('\n')?;
+
+        let mut numbers = header.split_whitespace().map(|it| it.parse::<u32>());
+        let offset = numbers.next()?.ok()?;
+        let len = numbers.next()?.ok()?;
+        let start = TextSize::from(offset);
+        let end = start.checked_add(TextSize::from(len))?;
+        if end > TextSize::of(text)
+            || !text.is_char_boundary(start.into())
+            || !text.is_char_boundary(end.into())
+        {
+            return None;
+        }
+
+        let insert = insert.replace("\\n", "\n");
+        let edit = Indel::replace(TextRange::new(start, end), insert);
+        let mut edited_text = text.to_string();
+        edit.apply(&mut edited_text);
+        Some(CheckReparse { text: text.to_string(), edit, edited_text })
+    }
+
+    /// Checks that reparsing the edited file gives the same result as parsing
+    /// it from scratch, with and without the address checks of each dialect.
+    pub fn run(&self) {
+        check_parser(&self.edited_text);
+        let dialects = [None, Some(Dialect::Diem), Some(Dialect::DFinance), Some(Dialect::Pontem)];
+        for dialect in dialects {
+            let parse = |text: &str| match dialect {
+                Some(dialect) => SourceFile::parse_with_dialect(text, dialect),
+                None => SourceFile::parse(text),
+            };
+            let reparsed = parse(&self.text).reparse(&self.edit);
+            let full = parse(&self.edited_text);
+            assert_eq!(
+                reparsed.tree().syntax().text().to_string(),
+                self.edited_text,
+                "reparsed tree text differs from the edited text",
+            );
+            assert_eq!(
+                reparsed.debug_dump(),
+                full.debug_dump(),
+                "reparse with {:?} differs from a full parse after {:?}",
+                dialect,
+                self.edit,
+            );
+        }
+    }
+}
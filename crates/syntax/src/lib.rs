@@ -7,16 +7,24 @@ use stdx::format_to;
 
 /// `SourceFile` represents a parse tree for a single Rust file.
 use rowan::GreenNode;
+use text_edit::Indel;
 
 use crate::syntax_error::SyntaxError;
 
 pub mod ast;
+mod dialect;
+pub mod fuzz;
 pub mod parsing;
+mod ptr;
 pub mod syntax_error;
 pub mod syntax_node;
+pub mod ted;
 mod tests;
+mod validation;
 
 pub use crate::ast::{AstNode, SourceFile};
+pub use crate::dialect::Dialect;
+pub use crate::ptr::{AstPtr, SyntaxNodePtr};
 use crate::syntax_node::SyntaxNode;
 pub use parser::SyntaxKind;
 pub use smol_str::SmolStr;
@@ -31,18 +39,26 @@ pub use text_size::{TextRange, TextSize};
 pub struct Parse<T> {
     green: GreenNode,
     errors: Arc<Vec<SyntaxError>>,
+    /// The dialect the addresses were checked against, if any, so that
+    /// reparsing checks them again.
+    dialect: Option<Dialect>,
     _ty: PhantomData<fn() -> T>,
 }
 
 impl<T> Clone for Parse<T> {
     fn clone(&self) -> Parse<T> {
-        Parse { green: self.green.clone(), errors: self.errors.clone(), _ty: PhantomData }
+        Parse {
+            green: self.green.clone(),
+            errors: self.errors.clone(),
+            dialect: self.dialect,
+            _ty: PhantomData,
+        }
     }
 }
 
 impl<T> Parse<T> {
     fn new(green: GreenNode, errors: Vec<SyntaxError>) -> Parse<T> {
-        Parse { green, errors: Arc::new(errors), _ty: PhantomData }
+        Parse { green, errors: Arc::new(errors), dialect: None, _ty: PhantomData }
     }
 
     pub fn syntax_node(&self) -> SyntaxNode {
@@ -52,7 +68,7 @@ impl<T> Parse<T> {
 
 impl<T: AstNode> Parse<T> {
     pub fn to_syntax(self) -> Parse<SyntaxNode> {
-        Parse { green: self.green, errors: self.errors, _ty: PhantomData }
+        Parse { green: self.green, errors: self.errors, dialect: self.dialect, _ty: PhantomData }
     }
 
     pub fn tree(&self) -> T {
@@ -75,7 +91,12 @@ impl<T: AstNode> Parse<T> {
 impl Parse<SyntaxNode> {
     pub fn cast<N: AstNode>(self) -> Option<Parse<N>> {
         if N::cast(self.syntax_node()).is_some() {
-            Some(Parse { green: self.green, errors: self.errors, _ty: PhantomData })
+            Some(Parse {
+                green: self.green,
+                errors: self.errors,
+                dialect: self.dialect,
+                _ty: PhantomData,
+            })
         } else {
             None
         }
@@ -91,26 +112,42 @@ impl Parse<SourceFile> {
         buf
     }
 
-    // pub fn reparse(&self, indel: &Indel) -> Parse<SourceFile> {
-    //     self.incremental_reparse(indel).unwrap_or_else(|| self.full_reparse(indel))
-    // }
-
-    // fn incremental_reparse(&self, indel: &Indel) -> Option<Parse<SourceFile>> {
-    //     // FIXME: validation errors are not handled here
-    //     parsing::incremental_reparse(self.tree().syntax(), indel, self.errors.to_vec()).map(
-    //         |(green_node, errors, _reparsed_range)| Parse {
-    //             green: green_node,
-    //             errors: Arc::new(errors),
-    //             _ty: PhantomData,
-    //         },
-    //     )
-    // }
-
-    // fn full_reparse(&self, indel: &Indel) -> Parse<SourceFile> {
-    //     let mut text = self.tree().syntax().text().to_string();
-    //     indel.apply(&mut text);
-    //     SourceFile::parse(&text)
-    // }
+    /// The dialect the addresses were checked against, if any.
+    pub fn dialect(&self) -> Option<Dialect> {
+        self.dialect
+    }
+
+    /// Applies the edit, reparsing only the token or block it touches when
+    /// possible. The result is the same as parsing the edited text from
+    /// scratch, with the same dialect.
+    pub fn reparse(&self, indel: &Indel) -> Parse<SourceFile> {
+        self.incremental_reparse(indel).unwrap_or_else(|| self.full_reparse(indel))
+    }
+
+    fn incremental_reparse(&self, indel: &Indel) -> Option<Parse<SourceFile>> {
+        // Validation is cheap compared to parsing, so instead of tracking
+        // which of its errors the edit invalidates, it is redone for the
+        // whole tree, address checks included.
+        let mut parser_errors = self.errors.to_vec();
+        for err in validate(&self.syntax_node(), self.dialect) {
+            if let Some(idx) = parser_errors.iter().position(|it| *it == err) {
+                parser_errors.remove(idx);
+            }
+        }
+        let (green, mut errors, _reparsed_range) =
+            parsing::incremental_reparse(&self.syntax_node(), indel, parser_errors)?;
+        errors.extend(validate(&SyntaxNode::new_root(green.clone()), self.dialect));
+        Some(Parse { dialect: self.dialect, ..Parse::new(green, errors) })
+    }
+
+    fn full_reparse(&self, indel: &Indel) -> Parse<SourceFile> {
+        let mut text = self.tree().syntax().text().to_string();
+        indel.apply(&mut text);
+        match self.dialect {
+            Some(dialect) => SourceFile::parse_with_dialect(&text, dialect),
+            None => SourceFile::parse(&text),
+        }
+    }
 }
 
 impl SourceFile {
@@ -122,11 +159,30 @@ impl SourceFile {
         //     validation::validate_block_structure(&root);
         // }
 
-        // errors.extend(validation::validate(&root));
+        errors.extend(validation::validate(&root));
 
         assert_eq!(root.kind(), SyntaxKind::SOURCE_FILE);
-        Parse { green, errors: Arc::new(errors), _ty: PhantomData }
+        Parse::new(green, errors)
+    }
+
+    /// Like [`SourceFile::parse`], but also checks address literals against
+    /// the rules of `dialect`, which is a property of the package rather than
+    /// of the file.
+    pub fn parse_with_dialect(text: &str, dialect: Dialect) -> Parse<SourceFile> {
+        let parse = SourceFile::parse(text);
+        let mut errors = parse.errors.to_vec();
+        errors.extend(validation::validate_addresses(&parse.syntax_node(), dialect));
+        Parse { dialect: Some(dialect), ..Parse::new(parse.green, errors) }
+    }
+}
+
+/// The errors which don't come from the parser.
+fn validate(root: &SyntaxNode, dialect: Option<Dialect>) -> Vec<SyntaxError> {
+    let mut errors = validation::validate(root);
+    if let Some(dialect) = dialect {
+        errors.extend(validation::validate_addresses(root, dialect));
     }
+    errors
 }
 
 #[cfg(test)]
@@ -3,8 +3,11 @@ use crate::syntax_error::SyntaxError;
 use parser::{event, Lexer};
 use rowan::GreenNode;
 
+mod reparsing;
 mod text_tree_sink;
 
+pub(crate) use crate::parsing::reparsing::incremental_reparse;
+
 pub(crate) fn parse_text(text: &str) -> (GreenNode, Vec<SyntaxError>) {
     let mut lexer = Lexer::new(text);
     let (events, tokens) = parser::parse_with_lexer(&mut lexer);
@@ -0,0 +1,384 @@
+//! Implementation of incremental re-parsing.
+//!
+//! We use two simple strategies for this:
+//!   - if the edit modifies only a single token (like changing an identifier's
+//!     letter), we replace only this token.
+//!   - otherwise, we search for the nearest `{}` block which contains the edit
+//!     and try to parse only this block.
+//!
+//! Both return the errors of the parser only: validation is redone for the
+//! whole tree by the caller.
+
+use parser::{Lexer, Reparser, T};
+use rowan::{GreenNode, GreenToken, NodeOrToken, TextRange, TextSize};
+use text_edit::Indel;
+
+use crate::parsing::text_tree_sink::TextTreeSink;
+use crate::syntax_error::SyntaxError;
+use crate::syntax_node::{SyntaxElement, SyntaxNode, SyntaxToken};
+use crate::SyntaxKind::{self, *};
+
+pub(crate) fn incremental_reparse(
+    node: &SyntaxNode,
+    edit: &Indel,
+    errors: Vec<SyntaxError>,
+) -> Option<(GreenNode, Vec<SyntaxError>, TextRange)> {
+    if let Some((green, old_range)) = reparse_token(node, edit) {
+        return Some((green, merge_errors(errors, Vec::new(), old_range, edit), old_range));
+    }
+
+    if let Some((green, new_errors, old_range)) = reparse_block(node, edit) {
+        return Some((green, merge_errors(errors, new_errors, old_range, edit), old_range));
+    }
+    None
+}
+
+fn reparse_token(root: &SyntaxNode, edit: &Indel) -> Option<(GreenNode, TextRange)> {
+    let prev_token = root.covering_element(edit.delete).as_token()?.clone();
+    let prev_token_kind = prev_token.kind();
+    match prev_token_kind {
+        WHITESPACE | COMMENT | DOC_COMMENT | IDENT | INTEGER_NUMBER | BYTE_STRING | HEX_STRING => {
+            if prev_token_kind.is_trivia() {
+                // Newlines decide which doc comments are attached to items
+                let deleted_range = edit.delete - prev_token.text_range().start();
+                if prev_token.text()[deleted_range].contains('\n') || edit.insert.contains('\n') {
+                    return None;
+                }
+            }
+
+            let mut new_text = get_text_after_edit(prev_token.clone().into(), edit);
+            let new_token_kind = lex_single_syntax_kind(&new_text)?;
+
+            if new_token_kind != prev_token_kind
+                || (new_token_kind == IDENT
+                    && (is_text_sensitive(&prev_token) || is_contextual_kw(&new_text)))
+            {
+                return None;
+            }
+
+            // Check that edited token is not a part of the bigger token.
+            // E.g. if for source code `ab"str"` the user removed `a`, then
+            // `b` no longer remains an identifier, but becomes a part of byte string literal
+            if let Some(next_char) = root.text().char_at(prev_token.text_range().end()) {
+                new_text.push(next_char);
+                if lex_single_syntax_kind(&new_text).is_some() {
+                    return None;
+                }
+                new_text.pop();
+            }
+
+            let new_token = GreenToken::new(rowan::SyntaxKind(prev_token_kind.into()), &new_text);
+            Some((prev_token.replace_with(new_token), prev_token.text_range()))
+        }
+        _ => None,
+    }
+}
+
+fn reparse_block(
+    root: &SyntaxNode,
+    edit: &Indel,
+) -> Option<(GreenNode, Vec<SyntaxError>, TextRange)> {
+    let (node, reparser) = find_reparsable_node(root, edit.delete)?;
+    let text = get_text_after_edit(node.clone().into(), edit);
+
+    if !is_balanced(&text) {
+        return None;
+    }
+
+    let mut lexer = Lexer::new(&text);
+    let (events, tokens) = reparser.parse(&mut lexer);
+
+    let mut tree_sink = TextTreeSink::new(&text, &tokens);
+    parser::event::process(&mut tree_sink, events);
+    let (green, new_errors) = tree_sink.finish();
+    // Error recovery stopped at an inner `}` or swallowed the last one: the
+    // block ends elsewhere now.
+    let new_node = SyntaxNode::new_root(green.clone());
+    if new_node.kind() != node.kind()
+        || new_node.text_range().len() != TextSize::of(&text)
+        || !is_closed(&new_node)
+    {
+        return None;
+    }
+
+    Some((node.replace_with(green), new_errors, node.text_range()))
+}
+
+fn get_text_after_edit(element: SyntaxElement, edit: &Indel) -> String {
+    let edit = Indel::replace(edit.delete - element.text_range().start(), edit.insert.clone());
+
+    let mut text = match element {
+        NodeOrToken::Token(token) => token.text().to_string(),
+        NodeOrToken::Node(node) => node.text().to_string(),
+    };
+    edit.apply(&mut text);
+    text
+}
+
+fn lex_single_syntax_kind(text: &str) -> Option<SyntaxKind> {
+    let (kind, len) = parser::find_token(text);
+    if kind == EOF || len != text.len() {
+        return None;
+    }
+    Some(kind)
+}
+
+fn is_contextual_kw(text: &str) -> bool {
+    SyntaxKind::from_contextual_keyword(text).is_some()
+}
+
+/// Whether the parser looks at the text of the identifier, so changing it may
+/// change the tree or the errors around it.
+fn is_text_sensitive(token: &SyntaxToken) -> bool {
+    is_contextual_kw(token.text()) || matches!(token.parent(), Some(it) if it.kind() == ABILITY)
+}
+
+fn find_reparsable_node(node: &SyntaxNode, range: TextRange) -> Option<(SyntaxNode, Reparser)> {
+    let node = node.covering_element(range);
+
+    let mut ancestors = match node {
+        NodeOrToken::Token(it) => it.parent()?.ancestors(),
+        NodeOrToken::Node(it) => it.ancestors(),
+    };
+    ancestors.find_map(|node| {
+        // The errors of a block without its `}` are reported at its end, where
+        // they can't be told apart from the errors after it.
+        if !is_closed(&node) {
+            return None;
+        }
+        Reparser::for_node(node.kind()).map(|r| (node, r))
+    })
+}
+
+fn is_closed(block: &SyntaxNode) -> bool {
+    matches!(block.last_child_or_token(), Some(it) if it.kind() == T!['}'])
+}
+
+fn is_balanced(text: &str) -> bool {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+    while offset < text.len() {
+        let (kind, len) = parser::find_token(&text[offset..]);
+        offset += len;
+        tokens.push(kind);
+    }
+    if tokens.is_empty() || tokens.first() != Some(&T!['{']) || tokens.last() != Some(&T!['}']) {
+        return false;
+    }
+    let mut balance = 0usize;
+    for kind in &tokens[1..tokens.len() - 1] {
+        match *kind {
+            T!['{'] => balance += 1,
+            T!['}'] => {
+                balance = match balance.checked_sub(1) {
+                    Some(b) => b,
+                    None => return false,
+                }
+            }
+            _ => (),
+        }
+    }
+    balance == 0
+}
+
+/// Keeps the old errors outside of the reparsed range, shifted by the edit,
+/// and puts the new ones in between, so that errors stay in text order.
+fn merge_errors(
+    old_errors: Vec<SyntaxError>,
+    new_errors: Vec<SyntaxError>,
+    range_before_reparse: TextRange,
+    edit: &Indel,
+) -> Vec<SyntaxError> {
+    let mut res = Vec::new();
+    let mut after = Vec::new();
+
+    for old_err in old_errors {
+        let old_err_range = old_err.range();
+        if old_err_range.end() <= range_before_reparse.start() {
+            res.push(old_err);
+        } else if old_err_range.start() >= range_before_reparse.end() {
+            let inserted_len = TextSize::of(&edit.insert);
+            // Note: extra parens are intentional to prevent uint underflow
+            after.push(old_err.with_range((old_err_range + inserted_len) - edit.delete.len()));
+        }
+    }
+    res.extend(new_errors.into_iter().map(|new_err| {
+        let offseted_range = new_err.range() + range_before_reparse.start();
+        new_err.with_range(offseted_range)
+    }));
+    res.extend(after);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::{assert_eq_text, extract_range};
+
+    use super::*;
+    use crate::{validation, AstNode, Parse, SourceFile};
+
+    fn do_check(before: &str, replace_with: &str, reparsed_len: u32) {
+        let (range, before) = extract_range(before);
+        let edit = Indel::replace(range, replace_with.to_owned());
+        let after = {
+            let mut after = before.clone();
+            edit.apply(&mut after);
+            after
+        };
+
+        let fully_reparsed = SourceFile::parse(&after);
+        let incrementally_reparsed: Parse<SourceFile> = {
+            let before = SourceFile::parse(&before);
+            let (green, mut new_errors, range) =
+                incremental_reparse(before.tree().syntax(), &edit, before.errors.to_vec()).unwrap();
+            assert_eq!(range.len(), reparsed_len.into(), "reparsed fragment has wrong length");
+            new_errors.extend(validation::validate(&SyntaxNode::new_root(green.clone())));
+            Parse::new(green, new_errors)
+        };
+
+        assert_eq_text!(
+            &format!("{:#?}", fully_reparsed.tree().syntax()),
+            &format!("{:#?}", incrementally_reparsed.tree().syntax()),
+        );
+        assert_eq!(fully_reparsed.errors(), incrementally_reparsed.errors());
+    }
+
+    fn check_not_reparsed(
+        reparse: fn(&SyntaxNode, &Indel) -> Option<TextRange>,
+        before: &str,
+        replace_with: &str,
+    ) {
+        let (range, before) = extract_range(before);
+        let edit = Indel::replace(range, replace_with.to_owned());
+        let before = SourceFile::parse(&before);
+        assert_eq!(reparse(before.tree().syntax(), &edit), None);
+    }
+
+    #[test]
+    fn reparse_block_tests() {
+        do_check(
+            r"
+script {
+    fun main() {
+        let x = foo + $0bar$0;
+    }
+}
+",
+            "baz + 1",
+            34,
+        );
+        do_check(
+            r"
+module M {
+    fun f() {}
+    $0fun g() {}$0
+}
+",
+            "struct S { f: u64 }",
+            33,
+        );
+        do_check(
+            r"
+address 0x1 {
+    module $0A$0 {}
+}
+",
+            "B {} module C",
+            19,
+        );
+        do_check(
+            r"
+module M {
+    fun f() {
+        $0if (a) b else c$0;
+    }
+}
+",
+            "{ 1 }",
+            32,
+        );
+    }
+
+    #[test]
+    fn reparse_token_tests() {
+        do_check(
+            r"
+script {
+    fun main() {
+        let $0x$0 = 1;
+    }
+}
+",
+            "long_name",
+            1,
+        );
+        do_check(
+            r"
+module M {
+    // a $0comment$0
+    fun f() {}
+}
+",
+            "remark",
+            13,
+        );
+        do_check(
+            r"
+module M {
+    /// Doc $0comment$0
+    fun f() {}
+}
+",
+            "string",
+            16,
+        );
+        do_check(
+            r"
+script {
+    fun main() {
+        let x =$0 $0  1;
+    }
+}
+",
+            "    ",
+            3,
+        );
+        do_check(
+            r"
+script {
+    fun main() {
+        let x = 2$0$0;
+    }
+}
+",
+            "55u8",
+            1,
+        );
+        do_check(
+            r#"
+script {
+    fun main() {
+        let x = b"he$0ll$0o";
+    }
+}
+"#,
+            "\\q",
+            8,
+        );
+    }
+
+    #[test]
+    fn does_not_reparse_when_the_structure_may_change() {
+        let token = |root: &SyntaxNode, edit: &Indel| reparse_token(root, edit).map(|it| it.1);
+        let block = |root: &SyntaxNode, edit: &Indel| reparse_block(root, edit).map(|it| it.2);
+        // contextual keywords change how the surrounding code parses
+        check_not_reparsed(token, "module M { spec$0x$0 {} }", "");
+        check_not_reparsed(token, "module M { struct S has $0key$0 {} }", "drop");
+        // newlines decide which doc comments are attached
+        check_not_reparsed(token, "/// doc\n$0 $0module M {}", "\n");
+        // the block would end early
+        check_not_reparsed(block, "module M { $0fun$0 f() {} }", "}");
+        // the block is not closed, its errors would be mixed with outer ones
+        check_not_reparsed(block, "module M { fun f() { $0x$0 }", "y {");
+    }
+}
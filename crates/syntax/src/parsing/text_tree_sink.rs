@@ -60,25 +60,22 @@ impl<'a> TreeSink for TextTreeSink<'a> {
 
         let n_trivias =
             self.tokens[self.token_pos..].iter().take_while(|it| it.kind.is_trivia()).count();
-        // let leading_trivias = &self.tokens[self.token_pos..self.token_pos + n_trivias];
-        // let mut trivia_end =
-        //     self.text_pos + leading_trivias.iter().map(|it| it.len).sum::<TextSize>();
-
-        // let n_attached_trivias = 0;
-        // let n_attached_trivias = {
-        //     let leading_trivias = leading_trivias.iter().rev().map(|it| {
-        //         let next_end = trivia_end - it.len;
-        //         let range = TextRange::new(next_end, trivia_end);
-        //         trivia_end = next_end;
-        //         (it.kind, &self.text[range])
-        //     });
-        //     0
-        //     // n_attached_trivias(kind, leading_trivias)
-        // };
-        // self.eat_n_trivias(n_trivias - n_attached_trivias);
-        self.eat_n_trivias(n_trivias);
+        let leading_trivias = &self.tokens[self.token_pos..self.token_pos + n_trivias];
+        let mut trivia_end =
+            self.text_pos + leading_trivias.iter().map(|it| it.len).sum::<TextSize>();
+
+        let n_attached_trivias = {
+            let leading_trivias = leading_trivias.iter().rev().map(|it| {
+                let next_end = trivia_end - it.len;
+                let range = TextRange::new(next_end, trivia_end);
+                trivia_end = next_end;
+                (it.kind, &self.text[range])
+            });
+            n_attached_trivias(kind, leading_trivias)
+        };
+        self.eat_n_trivias(n_trivias - n_attached_trivias);
         self.inner.start_node(kind);
-        // self.eat_n_trivias(n_attached_trivias);
+        self.eat_n_trivias(n_attached_trivias);
     }
 
     fn finish_node(&mut self) {
@@ -144,40 +141,37 @@ impl<'a> TextTreeSink<'a> {
     }
 }
 
-// fn n_attached_trivias<'a>(
-//     kind: SyntaxKind,
-//     trivias: impl Iterator<Item = (SyntaxKind, &'a str)>,
-// ) -> usize {
-//     match kind {
-//         SCRIPT_DEF | MODULE_DEF => {
-//             let mut res = 0;
-//             let mut trivias = trivias.enumerate().peekable();
-//
-//             while let Some((i, (kind, text))) = trivias.next() {
-//                 match kind {
-//                     WHITESPACE if text.contains("\n\n") => {
-//                         // we check whether the next token is a doc-comment
-//                         // and skip the whitespace in this case
-//                         if let Some((COMMENT, peek_text)) = trivias.peek().map(|(_, pair)| pair) {
-//                             let comment_kind = ast::CommentKind::from_text(peek_text);
-//                             if comment_kind.doc == Some(ast::CommentPlacement::Outer) {
-//                                 continue;
-//                             }
-//                         }
-//                         break;
-//                     }
-//                     COMMENT => {
-//                         let comment_kind = ast::CommentKind::from_text(text);
-//                         if comment_kind.doc == Some(ast::CommentPlacement::Inner) {
-//                             break;
-//                         }
-//                         res = i + 1;
-//                     }
-//                     _ => (),
-//                 }
-//             }
-//             res
-//         }
-//         _ => 0,
-//     }
-// }
+/// Number of trivias, counted backwards from the start of a `kind` node, which
+/// belong to the node: the doc comments directly above an item.
+fn n_attached_trivias<'a>(
+    kind: SyntaxKind,
+    trivias: impl Iterator<Item = (SyntaxKind, &'a str)>,
+) -> usize {
+    match kind {
+        ADDRESS_DEF | MODULE_DEF | SCRIPT_DEF | FUNCTION_DEF | STRUCT | RECORD_FIELD
+        | CONST_DEF | SPEC_DEF | SPEC_SCHEMA => {
+            let mut res = 0;
+            let mut trivias = trivias.enumerate().peekable();
+
+            while let Some((i, (kind, text))) = trivias.next() {
+                match kind {
+                    WHITESPACE => {
+                        // A line comment includes its newline, so a single
+                        // newline after one already makes a blank line.
+                        let after_line_comment = matches!(
+                            trivias.peek(),
+                            Some((_, (COMMENT | DOC_COMMENT, prev))) if prev.ends_with('\n')
+                        );
+                        if text.contains("\n\n") || (after_line_comment && text.contains('\n')) {
+                            break;
+                        }
+                    }
+                    DOC_COMMENT => res = i + 1,
+                    _ => break,
+                }
+            }
+            res
+        }
+        _ => 0,
+    }
+}
@@ -1,19 +1,28 @@
-use crate::ast::SourceFile;
+use crate::ast::{
+    self, HasAttrs, HasDocComments, HasModuleItem, HasName, HasTypeParams, HasVisibility,
+    SourceFile,
+};
+use crate::fuzz::{self, CheckReparse};
 use crate::syntax_error::SyntaxError;
+use crate::{AstNode, Dialect, TextRange, TextSize};
 use expect_test::expect_file;
+use parser::T;
 use std::fs;
 use std::path::{Path, PathBuf};
 use test_utils::project_root;
+use text_edit::Indel;
 
 #[test]
 fn parser_tests() {
-    dir_tests(&test_data_dir(), &["parser/ok"], "txt", |text, path| {
+    dir_tests(&test_data_dir(), &["parser/ok", "parser/inline/ok"], "txt", |text, path| {
+        fuzz::check_parser(text);
         let parse = SourceFile::parse(text);
         let errors = parse.errors();
         assert_errors_are_absent(errors, path);
         parse.debug_dump()
     });
-    dir_tests(&test_data_dir(), &["parser/err"], "txt", |text, path| {
+    dir_tests(&test_data_dir(), &["parser/err", "parser/inline/err"], "txt", |text, path| {
+        fuzz::check_parser(text);
         let parse = SourceFile::parse(text);
         let errors = parse.errors();
         assert_errors_are_present(errors, path);
@@ -21,6 +30,342 @@ fn parser_tests() {
     });
 }
 
+/// Replays the inputs which made the fuzz targets fail.
+#[test]
+fn parser_fuzz_failures() {
+    for (_, text) in collect_move_files(&test_data_dir(), &["fuzz-failures/parser"]) {
+        fuzz::check_parser(&text);
+    }
+}
+
+#[test]
+fn reparse_fuzz_failures() {
+    let dir = test_data_dir().join("fuzz-failures/reparse");
+    for path in files_in_dir(&dir, "txt") {
+        let data = read_text(&path);
+        let check = CheckReparse::from_data(data.as_bytes())
+            .unwrap_or_else(|| panic!("{} is not a valid edit", path.display()));
+        check.run();
+    }
+}
+
+#[test]
+fn finds_test_functions() {
+    let parse = SourceFile::parse(
+        r#"
+#[test_only]
+module M {
+    #[test]
+    fun a() {}
+    #[test(account = @0x1), expected_failure(abort_code = 1)]
+    fun b(account: signer) {}
+    #[test_only]
+    fun c() {}
+    fun d() {}
+}
+"#,
+    );
+    assert!(parse.errors().is_empty());
+    let syntax = parse.syntax_node();
+    let module = syntax.descendants().find_map(ast::ModuleDef::cast).unwrap();
+    assert!(module.has_attr("test_only"));
+    let tests: Vec<String> = syntax
+        .descendants()
+        .filter_map(ast::FunctionDef::cast)
+        .filter(|f| f.is_test())
+        .map(|f| f.name().unwrap().syntax().text().to_string())
+        .collect();
+    assert_eq!(tests, vec!["a", "b"]);
+}
+
+#[test]
+fn collects_doc_comments() {
+    let parse = SourceFile::parse(
+        r#"
+/// Coins.
+module M {
+    /// Mints
+    /// a coin.
+    fun mint() {}
+
+    /**
+     * Burns a coin.
+     *
+     * For real.
+     */
+    fun burn() {}
+
+    /// Not attached.
+
+    // Neither is this.
+    fun noop() {}
+}
+"#,
+    );
+    assert!(parse.errors().is_empty());
+    let syntax = parse.syntax_node();
+    let module = syntax.descendants().find_map(ast::ModuleDef::cast).unwrap();
+    assert_eq!(module.doc_comment_text().as_deref(), Some("Coins."));
+    let docs: Vec<Option<String>> = syntax
+        .descendants()
+        .filter_map(ast::FunctionDef::cast)
+        .map(|f| f.doc_comment_text())
+        .collect();
+    assert_eq!(
+        docs,
+        vec![
+            Some("Mints\na coin.".to_string()),
+            Some("Burns a coin.\n\nFor real.".to_string()),
+            None
+        ]
+    );
+}
+
+#[test]
+fn typed_access_to_items_and_bodies() {
+    let parse = SourceFile::parse(
+        r#"
+address 0x1 {
+module M {
+    struct S<T> has drop { value: T }
+    public fun f<T>(s: S<T>, n: u64): u64 acquires R {
+        let S { value } = s;
+        let sum: u64 = n + value;
+        g(sum) * 2
+    }
+}
+}
+"#,
+    );
+    assert!(parse.errors().is_empty());
+    let file = parse.tree();
+    let address = file.address_defs().next().unwrap();
+    let module = address.address_item_list().unwrap().module_defs().next().unwrap();
+
+    fn describe(item: &(impl HasName + HasTypeParams)) -> String {
+        let name = item.name().unwrap().syntax().text().to_string();
+        let n_params = item.generic_param_list().map_or(0, |it| it.type_params().count());
+        format!("{}<{}>", name, n_params)
+    }
+    let items: Vec<ast::Item> = module.item_list().unwrap().items().collect();
+    let (s, f) = match &items[..] {
+        [ast::Item::Struct(s), ast::Item::FunctionDef(f)] => (s, f),
+        _ => panic!("unexpected items: {:?}", items),
+    };
+    assert_eq!(describe(s), "S<1>");
+    assert_eq!(describe(f), "f<1>");
+    assert!(f.visibility().is_some());
+
+    let params: Vec<String> =
+        f.param_list().unwrap().params().map(|it| it.ty().unwrap().to_string()).collect();
+    assert_eq!(params, vec!["S<T>", "u64"]);
+    assert_eq!(f.ret_type().unwrap().ty().unwrap().to_string(), "u64");
+
+    let stmts: Vec<ast::Stmt> = f.body().unwrap().stmts().collect();
+    let record_pat = match &stmts[0] {
+        ast::Stmt::LetStmt(it) => it.pat().unwrap(),
+        _ => panic!("expected a let statement"),
+    };
+    assert!(matches!(record_pat, ast::Pat::RecordPat(_)));
+    let sum = match &stmts[1] {
+        ast::Stmt::LetStmt(it) => it.initializer().unwrap(),
+        _ => panic!("expected a let statement"),
+    };
+    let sum = match sum {
+        ast::Expr::BinExpr(it) => it,
+        _ => panic!("expected a binary expression"),
+    };
+    assert_eq!(sum.op_kind(), Some(T![+]));
+    assert_eq!(sum.lhs().unwrap().to_string(), "n");
+    assert_eq!(sum.rhs().unwrap().to_string(), "value");
+}
+
+#[test]
+fn validates_addresses_per_dialect() {
+    let text = r#"
+script {
+    fun main() {
+        @0x1;
+        @Std;
+        @0x0102030405060708090a0b0c0d0e0f10;
+        @0x0102030405060708090a0b0c0d0e0f1011121314;
+        @wallet1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5j53nz7;
+        @wallet1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5j53nz8;
+        @5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY;
+    }
+}
+"#;
+    assert!(SourceFile::parse(text).errors().is_empty());
+    let check = |dialect: Dialect| -> Vec<String> {
+        SourceFile::parse_with_dialect(text, dialect)
+            .errors()
+            .iter()
+            .map(|err| format!("{}: {}", &text[err.range()], err))
+            .collect()
+    };
+    assert_eq!(
+        check(Dialect::Diem),
+        vec![
+            "0x0102030405060708090a0b0c0d0e0f1011121314: \
+             address is too long, `diem` addresses are at most 16 bytes",
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY: \
+             `diem` addresses must be written in hex, like `0x1`",
+        ]
+    );
+    assert_eq!(
+        check(Dialect::DFinance),
+        vec![
+            "wallet1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5j53nz8: invalid bech32 address",
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY: \
+             `dfinance` addresses must be written in hex, like `0x1`",
+        ]
+    );
+    assert!(check(Dialect::Pontem).is_empty());
+}
+
+#[test]
+fn decodes_addresses_of_all_dialects() {
+    let hex = |text: &str| -> Option<String> {
+        let bytes = Dialect::decode_address(text)?;
+        Some(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+    };
+    assert_eq!(hex("0x1").as_deref(), Some("01"));
+    assert_eq!(hex("0x0102").as_deref(), Some("0102"));
+    assert_eq!(
+        hex("wallet1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5j53nz7").as_deref(),
+        Some("0102030405060708090a0b0c0d0e0f1011121314")
+    );
+    assert_eq!(
+        hex("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").as_deref(),
+        Some("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+    );
+    assert_eq!(hex("wallet1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5j53nz8"), None);
+    assert_eq!(hex("0x1g"), None);
+    assert_eq!(hex("0OIl"), None);
+}
+
+#[test]
+fn malformed_hex_address_is_a_syntax_error() {
+    let parse = SourceFile::parse("script { fun main() { @0x1g; } }");
+    let errors: Vec<String> = parse.errors().iter().map(|err| err.to_string()).collect();
+    assert_eq!(errors, vec!["invalid hex address"]);
+}
+
+#[test]
+fn reparse_checks_addresses_of_the_dialect() {
+    let text = "script { fun main() { @0x1; } }";
+    let parse = SourceFile::parse_with_dialect(text, Dialect::Diem);
+    assert!(parse.errors().is_empty());
+
+    let range = TextRange::at(TextSize::from(23), TextSize::of("0x1"));
+    let edit = Indel::replace(range, "0x0102030405060708090a0b0c0d0e0f1011".to_string());
+    let reparsed = parse.reparse(&edit);
+    let errors: Vec<String> = reparsed.errors().iter().map(|err| err.to_string()).collect();
+    assert_eq!(errors, vec!["address is too long, `diem` addresses are at most 16 bytes"]);
+
+    let range = TextRange::at(TextSize::from(23), TextSize::of(&edit.insert));
+    let reparsed = reparsed.reparse(&Indel::replace(range, "0x2".to_string()));
+    assert!(reparsed.errors().is_empty());
+}
+
+/// Applies random edits to the parser test files and checks that reparsing
+/// incrementally gives the same tree and errors as parsing from scratch. Every
+/// other file is parsed with the address checks of the `diem` dialect.
+#[test]
+fn reparse_matches_full_parse() {
+    const INSERTS: &[&str] = &[
+        "",
+        "a",
+        "x",
+        "1",
+        "u8",
+        " ",
+        "\n",
+        "\n\n",
+        "{",
+        "}",
+        "{}",
+        "(",
+        ")",
+        ";",
+        ",",
+        ":",
+        "::",
+        "=",
+        "<",
+        ">",
+        "@",
+        "&",
+        "*",
+        "\"",
+        "b\"",
+        "x\"",
+        "//",
+        "///",
+        "/*",
+        "*/",
+        "fun",
+        "let",
+        "struct",
+        "module",
+        "spec",
+        "schema",
+        "requires",
+        "has",
+        "drop",
+        "copy",
+        "if",
+        "else",
+        "fun f() {}",
+        "let x = 1;",
+        "/// doc\n",
+        "// c\n",
+        "0x1",
+        "0x0102030405060708090a0b0c0d0e0f1011",
+        "256u8",
+    ];
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    let mut rand = |n: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % n as u64) as usize
+    };
+    let files = collect_move_files(&test_data_dir(), &["parser/ok", "parser/err"]);
+    for (idx, (path, text)) in files.into_iter().enumerate() {
+        let dialect = if idx % 2 == 0 { None } else { Some(Dialect::Diem) };
+        let parse_text = |text: &str| match dialect {
+            Some(dialect) => SourceFile::parse_with_dialect(text, dialect),
+            None => SourceFile::parse(text),
+        };
+        let mut text = text;
+        let mut parse = parse_text(&text);
+        for _ in 0..100 {
+            let start = rand(text.len() + 1);
+            let end = (start + rand(8)).min(text.len());
+            if !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+                continue;
+            }
+            let insert = INSERTS[rand(INSERTS.len())];
+            let range = TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32));
+            let edit = Indel::replace(range, insert.to_string());
+
+            let incremental = parse.reparse(&edit);
+            edit.apply(&mut text);
+            let full = parse_text(&text);
+            assert_eq!(
+                incremental.debug_dump(),
+                full.debug_dump(),
+                "reparse of {} differs from a full parse after {:?}, new text:\n{}",
+                path.display(),
+                edit,
+                text,
+            );
+            parse = incremental;
+        }
+    }
+}
+
 pub fn test_data_dir() -> PathBuf {
     project_root().join("crates/syntax/test_data")
 }
@@ -73,11 +418,16 @@ fn collect_move_files(root_dir: &Path, paths: &[&str]) -> Vec<(PathBuf, String)>
 
 /// Collects paths to all `.move` files from `dir` in a sorted `Vec<PathBuf>`.
 fn move_files_in_dir(dir: &Path) -> Vec<PathBuf> {
+    files_in_dir(dir, "move")
+}
+
+/// Collects paths to all files with the `extension` from `dir` in a sorted `Vec<PathBuf>`.
+fn files_in_dir(dir: &Path, extension: &str) -> Vec<PathBuf> {
     let mut acc = Vec::new();
     for file in fs::read_dir(&dir).unwrap() {
         let file = file.unwrap();
         let path = file.path();
-        if path.extension().unwrap_or_default() == "move" {
+        if path.extension().unwrap_or_default() == extension {
             acc.push(path);
         }
     }
@@ -0,0 +1,219 @@
+//! This module implements syntax validation that the parser doesn't handle.
+//!
+//! A failed validation emits a diagnostic.
+
+use crate::ast::{self, AstNode, HasModuleItem, HasName};
+use crate::syntax_error::SyntaxError;
+use crate::syntax_node::{SyntaxNode, SyntaxToken};
+use crate::{Dialect, SyntaxKind::*, TextRange, TextSize};
+
+pub(crate) fn validate(root: &SyntaxNode) -> Vec<SyntaxError> {
+    let mut errors = Vec::new();
+    for node in root.descendants() {
+        if let Some(item_list) = ast::ItemList::cast(node) {
+            validate_item_list(item_list, &mut errors);
+        }
+    }
+    for token in root.descendants_with_tokens().filter_map(|it| it.into_token()) {
+        validate_token(&token, &mut errors);
+    }
+    errors
+}
+
+pub(crate) fn validate_token(token: &SyntaxToken, errors: &mut Vec<SyntaxError>) {
+    match token.kind() {
+        DIEM_ADDRESS if is_malformed_hex(token.text()) => {
+            errors.push(SyntaxError::new("invalid hex address", token.text_range()));
+        }
+        INTEGER_NUMBER => validate_integer(token, errors),
+        BYTE_STRING => validate_byte_string(token, errors),
+        HEX_STRING => validate_hex_string(token, errors),
+        COMMENT | DOC_COMMENT if token.text().starts_with("/*") => {
+            let (_, terminated) = parser::lex_block_comment(token.text());
+            if !terminated {
+                let start = token.text_range().start();
+                let range = TextRange::at(start, TextSize::of("/*"));
+                errors.push(SyntaxError::new("unterminated block comment", range));
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Checks the digits of `1`, `0xff` and `255u8` and that the value fits in the
+/// type given by the suffix. Unsuffixed literals may be inferred as `u128`.
+fn validate_integer(token: &SyntaxToken, errors: &mut Vec<SyntaxError>) {
+    let text = token.text();
+    let start = token.text_range().start();
+    let (digits_start, radix) = if text.starts_with("0x") { (2, 16) } else { (0, 10) };
+    let digits_len = text[digits_start..]
+        .find(|c: char| !c.is_digit(radix))
+        .unwrap_or(text.len() - digits_start);
+    let digits = &text[digits_start..digits_start + digits_len];
+    let suffix = &text[digits_start + digits_len..];
+    if digits.is_empty() {
+        errors.push(SyntaxError::new("expected hex digits after `0x`", token.text_range()));
+        return;
+    }
+    let (ty, max) = match suffix {
+        "" | "u128" => ("u128", u128::MAX),
+        "u8" => ("u8", u8::MAX as u128),
+        "u64" => ("u64", u64::MAX as u128),
+        _ => {
+            let range = TextRange::at(
+                start + TextSize::of(&text[..text.len() - suffix.len()]),
+                TextSize::of(suffix),
+            );
+            errors.push(SyntaxError::new(
+                format!("invalid suffix `{}` for number literal", suffix),
+                range,
+            ));
+            return;
+        }
+    };
+    match u128::from_str_radix(digits, radix) {
+        Ok(value) if value <= max => (),
+        _ => errors.push(SyntaxError::new(
+            format!("integer literal is out of range for `{}`", ty),
+            token.text_range(),
+        )),
+    }
+}
+
+/// Checks the escapes of `b"..."`: `\n`, `\r`, `\t`, `\0`, `\\`, `\"` and
+/// `\xHH`.
+fn validate_byte_string(token: &SyntaxToken, errors: &mut Vec<SyntaxError>) {
+    let start = token.text_range().start();
+    let text = token.text();
+    let mut chars = text.char_indices().skip(2);
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '"' => return,
+            '\\' => {
+                let escape_len = match chars.next() {
+                    Some((_, 'n' | 'r' | 't' | '0' | '\\' | '"')) => continue,
+                    Some((_, 'x')) => {
+                        let digits: String = text[offset + 2..].chars().take(2).collect();
+                        if digits.len() == 2 && digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                            chars.nth(1);
+                            continue;
+                        }
+                        let valid_len =
+                            digits.chars().take_while(|c| c.is_ascii_hexdigit()).count();
+                        2 + valid_len
+                    }
+                    Some((_, c)) => 1 + c.len_utf8(),
+                    None => break,
+                };
+                let range = TextRange::at(
+                    start + TextSize::from(offset as u32),
+                    TextSize::from(escape_len as u32),
+                );
+                let escape = &text[offset..offset + escape_len];
+                errors.push(SyntaxError::new(
+                    format!("invalid escape `{}` in byte string", escape),
+                    range,
+                ));
+            }
+            _ => (),
+        }
+    }
+    errors.push(SyntaxError::new("missing closing quote of byte string", token.text_range()));
+}
+
+/// Checks that `x"..."` is made of whole bytes written in hex.
+fn validate_hex_string(token: &SyntaxToken, errors: &mut Vec<SyntaxError>) {
+    let start = token.text_range().start();
+    let text = token.text();
+    let digits = match text[2..].strip_suffix('"') {
+        Some(it) => it,
+        None => {
+            errors
+                .push(SyntaxError::new("missing closing quote of hex string", token.text_range()));
+            return;
+        }
+    };
+    let mut valid = true;
+    for (offset, c) in digits.char_indices() {
+        if !c.is_ascii_hexdigit() {
+            let range = TextRange::at(start + TextSize::from(2 + offset as u32), TextSize::of(c));
+            errors.push(SyntaxError::new(format!("invalid hex digit `{}`", c), range));
+            valid = false;
+        }
+    }
+    if valid && digits.len() % 2 != 0 {
+        errors.push(SyntaxError::new(
+            "hex string must have an even number of digits",
+            token.text_range(),
+        ));
+    }
+}
+
+/// Checks address literals against the rules of the dialect, which the parser
+/// knows nothing about.
+pub(crate) fn validate_addresses(root: &SyntaxNode, dialect: Dialect) -> Vec<SyntaxError> {
+    let mut errors = Vec::new();
+    for token in root.descendants_with_tokens().filter_map(|it| it.into_token()) {
+        let is_address_literal = match token.kind() {
+            DIEM_ADDRESS => !is_malformed_hex(token.text()),
+            IDENT => is_named_address(&token) && dialect.is_address_literal_name(token.text()),
+            _ => false,
+        };
+        if !is_address_literal {
+            continue;
+        }
+        if let Err(message) = dialect.check_address(token.text()) {
+            errors.push(SyntaxError::new(message, token.text_range()));
+        }
+    }
+    errors
+}
+
+fn is_malformed_hex(text: &str) -> bool {
+    match text.strip_prefix("0x") {
+        Some(hex) => hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => false,
+    }
+}
+
+fn is_named_address(token: &SyntaxToken) -> bool {
+    matches!(token.parent(), Some(it) if it.kind() == NAMED_ADDRESS)
+}
+
+/// The parser accepts any module item inside `script { }`, but a script may
+/// only contain `use` and `const` declarations, specs and a single function.
+fn validate_item_list(item_list: ast::ItemList, errors: &mut Vec<SyntaxError>) {
+    if !matches!(item_list.syntax().parent(), Some(it) if it.kind() == SCRIPT_DEF) {
+        return;
+    }
+    let mut seen_function = false;
+    for item in item_list.items() {
+        match item {
+            ast::Item::FunctionDef(fun) => {
+                if seen_function {
+                    let range = match fun.name() {
+                        Some(name) => name.syntax().text_range(),
+                        None => fun.syntax().text_range(),
+                    };
+                    errors.push(SyntaxError::new(
+                        "a script can only contain a single function",
+                        range,
+                    ));
+                }
+                seen_function = true;
+            }
+            ast::Item::Struct(it) => errors.push(SyntaxError::new(
+                "structs cannot be declared inside a script",
+                it.syntax().text_range(),
+            )),
+            ast::Item::FriendDecl(it) => errors.push(SyntaxError::new(
+                "friend declarations are not allowed inside a script",
+                it.syntax().text_range(),
+            )),
+            ast::Item::ConstDef(_)
+            | ast::Item::UseDecl(_)
+            | ast::Item::SpecDef(_)
+            | ast::Item::SpecSchema(_) => (),
+        }
+    }
+}
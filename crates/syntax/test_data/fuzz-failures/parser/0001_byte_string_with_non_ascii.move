@@ -0,0 +1 @@
+script { fun main() { b"été"; } }
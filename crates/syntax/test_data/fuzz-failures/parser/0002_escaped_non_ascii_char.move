@@ -0,0 +1 @@
+module M { const C: vector<u8> = b"\éé"; }
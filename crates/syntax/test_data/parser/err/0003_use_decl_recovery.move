@@ -0,0 +1,8 @@
+module M {
+    use ;
+    use {Self};
+    use 0x1::Vector::{Self length, {Self}};
+    use Std::Vector::;
+    use 0x1::Signer as;
+    fun main() {}
+}
@@ -0,0 +1,7 @@
+module M {
+    const A = 1;
+    const B: u64;
+    const : u64 = 1;
+    const C: u64 = 1
+    fun main() {}
+}
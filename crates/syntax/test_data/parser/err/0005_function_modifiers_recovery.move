@@ -0,0 +1,9 @@
+module M {
+    public public fun a() {}
+    public(crate) fun b() {}
+    public(script fun c() {}
+    native fun d() {}
+    fun e();
+    public const A: u64 = 1;
+    entry entry fun f() {}
+}
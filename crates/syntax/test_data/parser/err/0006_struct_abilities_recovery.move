@@ -0,0 +1,8 @@
+module M {
+    struct S has copy, drip { f: u64 }
+    struct T has { f: u64 }
+    struct U<T: > { f: u64 }
+    native struct V { f: u64 }
+    struct W;
+    public struct X {}
+}
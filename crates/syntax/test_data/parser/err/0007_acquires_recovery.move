@@ -0,0 +1,5 @@
+module M {
+    fun f() acquires {}
+    fun g() acquires A, {}
+    fun h() acquires A B {}
+}
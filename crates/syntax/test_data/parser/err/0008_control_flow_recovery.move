@@ -0,0 +1,8 @@
+module M {
+    fun f() {
+        if true {};
+        while (a {};
+        abort;
+        loop
+    }
+}
@@ -0,0 +1,9 @@
+module M {
+    fun f() {
+        let a = -b;
+        move 1;
+        copy;
+        let c = &;
+        let v = empty<>();
+    }
+}
@@ -0,0 +1,8 @@
+module M {
+    fun f(): (u64, ; {
+        let a = (1 as );
+        let b = (1, 2 as u8);
+        let c = vector[1 2];
+        let d = vector<u8>;
+    }
+}
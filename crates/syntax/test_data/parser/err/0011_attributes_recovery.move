@@ -0,0 +1,11 @@
+module M {
+    # fun a() {}
+    #[] fun b() {}
+    #[test(account = )] fun c() {}
+    #[1] fun d() {}
+    #[test]
+}
+address 0x1 {
+    fun e() {}
+    #[test_only] module N {}
+}
@@ -0,0 +1,5 @@
+module M {
+    friend;
+    public friend 0x1::Coin;
+    friend 0x1::Coin
+}
@@ -0,0 +1,9 @@
+script {
+    use 0x1::Coin;
+    struct S {}
+    friend 0x1::Coin;
+    const A: u64 = 1;
+    fun main() {}
+    fun helper() {}
+    public fun other() {}
+}
@@ -0,0 +1,13 @@
+module M {
+    spec { requires true; }
+    spec fun { }
+    spec f {
+        1 + 2;
+        requires true
+        ensures false;
+        pragma;
+        ensures forall x: u64 x > 0;
+    }
+    spec schema { }
+    spec S
+}
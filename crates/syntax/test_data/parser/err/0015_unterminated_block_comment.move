@@ -0,0 +1,5 @@
+module M {
+    fun f() {}
+}
+/* unterminated /* nested */
+module N {}
@@ -0,0 +1,18 @@
+script {
+    fun main() {
+        255u8;
+        256u8;
+        18446744073709551616u64;
+        340282366920938463463374607431768211456;
+        0xffu8;
+        0x100u8;
+        0xZZ;
+        1abc;
+        10u32;
+        x"0a1";
+        x"0g";
+        b"ok\n\r\t\0\\\"\x41";
+        b"\q\x4";
+        b"unterminated;
+    }
+}
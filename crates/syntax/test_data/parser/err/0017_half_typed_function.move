@@ -0,0 +1,10 @@
+module M {
+    use 0x1::Signer
+
+    public fun transfer(from: &signer, to: address amount: u64 {
+        let sender = Signer::address_of(from);
+        let balance = borrow_global_mut<Balance>(sender) acquires
+    }
+
+    fun
+}
@@ -0,0 +1,13 @@
+module M {
+    struct S has key { value: u64 }
+    value: u64 = 1; ) ] other garbage
+    fun f() {}
+    123 456;
+}
+this is not Move;
+script {
+    fun main() {
+        let x = ) ] 1;
+        x;
+    }
+}
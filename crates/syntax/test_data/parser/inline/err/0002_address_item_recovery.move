@@ -0,0 +1 @@
+address 0x1 { fun f() {} #[test_only] module M {} }
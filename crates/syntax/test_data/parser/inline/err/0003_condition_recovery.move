@@ -0,0 +1,6 @@
+script {
+    fun main() {
+        if true {};
+        while (a {};
+    }
+}
@@ -0,0 +1 @@
+module M { const A = 1; }
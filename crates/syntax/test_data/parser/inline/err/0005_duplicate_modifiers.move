@@ -0,0 +1 @@
+module M { public public fun f() {} native native fun g(); }
@@ -0,0 +1 @@
+module M { friend; friend 0x1::Coin }
@@ -0,0 +1 @@
+module M { fun f(x y: u64, z, t: u64) {} }
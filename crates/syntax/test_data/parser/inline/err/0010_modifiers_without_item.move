@@ -0,0 +1 @@
+module M { public const A: u64 = 1; }
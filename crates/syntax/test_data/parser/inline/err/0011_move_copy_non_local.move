@@ -0,0 +1,6 @@
+script {
+    fun main() {
+        move 1;
+        copy;
+    }
+}
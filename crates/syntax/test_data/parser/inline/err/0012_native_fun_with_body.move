@@ -0,0 +1 @@
+module M { native fun f() {} }
@@ -0,0 +1 @@
+module M { native struct S { f: u64 } }
@@ -0,0 +1,6 @@
+module M {
+    spec f {
+        ensures forall x: u64 x > 0;
+        ensures forall x y: true;
+    }
+}
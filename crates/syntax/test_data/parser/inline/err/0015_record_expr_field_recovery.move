@@ -0,0 +1,6 @@
+script {
+    fun main() {
+        S { field ..S::default() };
+        S { x: 1 y: 2 };
+    }
+}
@@ -0,0 +1,5 @@
+module M {
+    spec { requires true; }
+    spec fun { }
+    spec f { requires; }
+}
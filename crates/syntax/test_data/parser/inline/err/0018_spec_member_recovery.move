@@ -0,0 +1,8 @@
+module M {
+    spec f {
+        1 + 2;
+        requires true
+        ensures false;
+        pragma;
+    }
+}
@@ -0,0 +1 @@
+script { fun main() { let b = (1, 2 as u8); } }
@@ -0,0 +1 @@
+module M { public struct S {} }
@@ -0,0 +1 @@
+module M { use {Self}; use 0x1::M::{{Self}}; }
@@ -0,0 +1 @@
+module M { public(crate) fun f() {} public(script fun g() {} }
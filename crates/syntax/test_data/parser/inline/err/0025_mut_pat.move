@@ -0,0 +1,7 @@
+script {
+    fun main() {
+        let mut b = 2;
+        let (mut a, b) = x;
+        let S { f, mut g } = s;
+    }
+}
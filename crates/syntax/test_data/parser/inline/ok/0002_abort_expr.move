@@ -0,0 +1,6 @@
+script {
+    fun main() {
+        abort 1;
+        abort ERR + 1
+    }
+}
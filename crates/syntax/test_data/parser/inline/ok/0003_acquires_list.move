@@ -0,0 +1,4 @@
+module M {
+    fun f(): u64 acquires Balance, Self::Coin, 0x1::M::R { 1 }
+    native fun g() acquires Balance;
+}
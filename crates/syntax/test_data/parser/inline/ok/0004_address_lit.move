@@ -0,0 +1,7 @@
+script {
+    fun main() {
+        let a = @0x1;
+        let b = @Std;
+        let c = @0x42 == @Std;
+    }
+}
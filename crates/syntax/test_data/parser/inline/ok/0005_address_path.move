@@ -0,0 +1 @@
+module M { use 0x1::Signer; }
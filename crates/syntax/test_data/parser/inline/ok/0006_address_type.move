@@ -0,0 +1 @@
+module M { const A: address = @0x1; }
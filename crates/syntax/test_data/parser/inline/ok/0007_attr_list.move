@@ -0,0 +1,9 @@
+#[test_only]
+module M {
+    #[test_only]
+    use Std::Debug;
+    #[test, expected_failure(abort_code = 1)]
+    fun f() {}
+    #[test(account = @0x1, other = @Std), allow(unused,)]
+    fun g(account: signer, other: signer) {}
+}
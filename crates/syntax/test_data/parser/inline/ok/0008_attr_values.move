@@ -0,0 +1,4 @@
+module M {
+    #[expected_failure(abort_code = 0x1::Errors::ENOT_FOUND, location = Self, ok = true)]
+    fun f() {}
+}
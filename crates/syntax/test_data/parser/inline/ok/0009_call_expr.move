@@ -0,0 +1,7 @@
+script {
+    fun main() {
+        foo(92);
+        Vector::push_back<u8>(&mut v, 1,);
+        f()(x);
+    }
+}
@@ -0,0 +1,6 @@
+script {
+    fun main() {
+        let a = (x as u128);
+        let b = ((a + 1) as u8) + 1;
+    }
+}
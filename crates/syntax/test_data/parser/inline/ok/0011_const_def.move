@@ -0,0 +1,6 @@
+module M {
+    const ENOT_OWNER: u64 = 1;
+    const MAX: u128 = 340282366920938463463374607431768211455;
+    const ADMIN: address = @0x1;
+    const ENABLED: bool = true;
+}
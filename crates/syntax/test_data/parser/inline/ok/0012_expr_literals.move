@@ -0,0 +1,12 @@
+script {
+    fun main() {
+        let _ = true;
+        let _ = false;
+        let _ = 1;
+        let _ = 255u8;
+        let _ = 0xFFu64;
+        let _ = b"bytes\n";
+        let _ = x"00ff";
+        let _ = @0x1;
+    }
+}
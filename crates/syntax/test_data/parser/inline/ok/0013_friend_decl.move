@@ -0,0 +1,5 @@
+module M {
+    friend 0x1::Coin;
+    friend Std::Vector;
+    friend Pool;
+}
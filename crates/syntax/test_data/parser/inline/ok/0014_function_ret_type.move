@@ -0,0 +1,4 @@
+module M {
+    fun foo() {}
+    fun bar(): u64 { 1 }
+}
@@ -0,0 +1 @@
+module M { fun f<T: copy, U>() {} }
@@ -0,0 +1,6 @@
+script {
+    fun main() {
+        let a = 1;
+        let (b, c) = (2, 3);
+    }
+}
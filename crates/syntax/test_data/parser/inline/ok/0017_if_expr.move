@@ -0,0 +1,9 @@
+script {
+    fun main() {
+        if (true) {};
+        if (true) {} else {};
+        if (a) 1 else if (b) 2 else 3;
+        if (a) return 1 else abort 2;
+        let x = if (a) b + 1 else c * 2;
+    }
+}
@@ -0,0 +1,5 @@
+module M {
+    spec module {
+        fun last(v: vector<u64>): u64 { v[len(v) - 1] + v[0] }
+    }
+}
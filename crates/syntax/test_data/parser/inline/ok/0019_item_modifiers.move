@@ -0,0 +1,9 @@
+module M {
+    public fun a() {}
+    public(script) fun b() {}
+    public(friend) fun c() {}
+    native fun d();
+    native public fun e(): u64;
+    public entry fun f() {}
+    entry fun g() {}
+}
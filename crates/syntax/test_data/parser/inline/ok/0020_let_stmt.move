@@ -0,0 +1,9 @@
+script {
+    fun main() {
+        let a;
+        let b: u64;
+        let c = 92;
+        let d: u64 = 92;
+        let (e, f): (u8, bool) = (1, true);
+    }
+}
@@ -0,0 +1,8 @@
+script {
+    fun main() {
+        loop {
+            if (a) break;
+            if (b) continue;
+        }
+    }
+}
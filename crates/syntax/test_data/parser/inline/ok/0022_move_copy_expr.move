@@ -0,0 +1,7 @@
+script {
+    fun main() {
+        let a = move x;
+        let b = copy y.f;
+        consume(move a, copy b);
+    }
+}
@@ -0,0 +1,5 @@
+module M {
+    fun f(v: vector<vector<u8>>): Option<Option<u64>> {
+        borrow_global<Coin<T>>(a);
+    }
+}
@@ -0,0 +1,4 @@
+module M {
+    fun f(a: u64, b: &mut S<T>,) {}
+    fun g(account: &signer) {}
+}
@@ -0,0 +1,6 @@
+script {
+    fun main() {
+        let Bar { f } = bar;
+        let Bar {} = bar;
+    }
+}
@@ -0,0 +1,7 @@
+module M {
+    fun f(): Option<u64> {
+        let s = borrow_global<S>(@0x1);
+        let b = a < c;
+        Vector::empty<u8>()
+    }
+}
@@ -0,0 +1,9 @@
+script {
+    fun main() {
+        let a = !b;
+        let r = &x;
+        let m = &mut borrow_global_mut<T>(addr).field;
+        *r = *m + 1;
+        !a && *&b == c;
+    }
+}
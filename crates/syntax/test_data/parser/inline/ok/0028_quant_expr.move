@@ -0,0 +1,8 @@
+module M {
+    spec f {
+        ensures forall x: u64: x >= 0;
+        ensures forall i in 0..len(v), j in 0..len(v) where i < j: v[i] <= v[j];
+        ensures exists a: address: exists<T>(a);
+        ensures result == choose x: u64 where x > 0;
+    }
+}
@@ -0,0 +1,8 @@
+script {
+    fun main() {
+        S {};
+        S { x, y: 32, };
+        Coin<T> { value: 0 };
+        0x1::M::S { inner: S { x } };
+    }
+}
@@ -0,0 +1 @@
+module M { struct S { a: u64, b: bool, } }
@@ -0,0 +1,7 @@
+script {
+    fun main() {
+        let S { x: y } = s;
+        let S { inner: Inner { value } } = s;
+        let S { f: (a, _) } = s;
+    }
+}
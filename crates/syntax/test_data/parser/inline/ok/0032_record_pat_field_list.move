@@ -0,0 +1,7 @@
+script {
+    fun main() {
+        let S {} = s;
+        let S { f, g } = s;
+        let S { h: _, } = s;
+    }
+}
@@ -0,0 +1,7 @@
+script {
+    fun main() {
+        return;
+        return 1 + 2;
+        if (a) return else return
+    }
+}
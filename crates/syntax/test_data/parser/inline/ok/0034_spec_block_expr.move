@@ -0,0 +1,7 @@
+module M {
+    fun f(x: u64) {
+        spec { assert x > 0; };
+        let y = x;
+        spec { assume y == x; }
+    }
+}
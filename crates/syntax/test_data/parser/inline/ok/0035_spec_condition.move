@@ -0,0 +1,15 @@
+module M {
+    spec f {
+        requires x > 0 && y > 0;
+        ensures x > 0 ==> result == old(x) + 1;
+        aborts_if x == 0 with 7;
+        aborts_with 1, 2;
+        modifies global<T>(@0x1);
+        emits Ev { } to handle if x > 0;
+        decreases x;
+    }
+    spec S {
+        invariant [abstract] value > 0;
+        invariant update old(value) <= value;
+    }
+}
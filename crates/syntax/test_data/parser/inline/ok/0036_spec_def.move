@@ -0,0 +1,7 @@
+module M {
+    spec module { pragma verify = false; }
+    spec fun f { requires x > 0; }
+    spec f { ensures result == x + 1; }
+    spec struct S { invariant value > 0; }
+    spec g<T> { aborts_if false; }
+}
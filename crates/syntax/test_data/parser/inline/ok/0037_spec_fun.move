@@ -0,0 +1,7 @@
+module M {
+    spec module {
+        fun sum(a: u64, b: u64): u64 { a + b }
+        native fun uninterpreted(x: u64): bool;
+        fun abstract_fun(): u64;
+    }
+}
@@ -0,0 +1,6 @@
+module M {
+    spec f {
+        include Increases<u64> { x: y };
+        include x > 0 ==> AbortsIfZero;
+    }
+}
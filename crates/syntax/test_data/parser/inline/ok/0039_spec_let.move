@@ -0,0 +1,6 @@
+module M {
+    spec f {
+        let a = x + 1;
+        let post b = a * 2;
+    }
+}
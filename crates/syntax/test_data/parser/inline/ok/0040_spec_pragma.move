@@ -0,0 +1,6 @@
+module M {
+    spec module {
+        pragma verify;
+        pragma aborts_if_is_strict = true, timeout = 100;
+    }
+}
@@ -0,0 +1,7 @@
+module M {
+    spec schema Increases<T> {
+        x: u64;
+        requires x > 0;
+        ensures result > x;
+    }
+}
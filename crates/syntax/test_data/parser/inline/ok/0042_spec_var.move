@@ -0,0 +1,10 @@
+module M {
+    spec module {
+        global counter: u64;
+        global sums<T>: num = 0;
+    }
+    spec f {
+        local y: u64;
+        x: u64;
+    }
+}
@@ -0,0 +1,5 @@
+module M {
+    struct Coin has key, store { value: u64 }
+    struct Wrapper<phantom T: store> has copy, drop { inner: u64 }
+    native struct Signer has drop;
+}
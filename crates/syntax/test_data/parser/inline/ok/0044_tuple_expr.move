@@ -0,0 +1,8 @@
+script {
+    fun main() {
+        ();
+        (1);
+        (1, true);
+        (a, b,);
+    }
+}
@@ -0,0 +1,9 @@
+script {
+    fun main() {
+        let (a, b) = (1, 2);
+        let (a,) = (1,);
+        let (a) = 1;
+        let () = ();
+        let (a, (b, c)) = x;
+    }
+}
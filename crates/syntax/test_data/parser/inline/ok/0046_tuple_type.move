@@ -0,0 +1,5 @@
+module M {
+    fun f(): () {}
+    fun g(): (u64, &mut vector<u8>) { (1, x) }
+    fun h(x: (u64)) {}
+}
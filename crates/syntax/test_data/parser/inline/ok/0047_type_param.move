@@ -0,0 +1,3 @@
+module M {
+    struct S<phantom T: store + drop, U, phantom> { f: U }
+}
@@ -0,0 +1,5 @@
+module M {
+    use 0x1::Signer;
+    use Std::Vector as V;
+    use Std::Vector::{Self, length as len};
+}
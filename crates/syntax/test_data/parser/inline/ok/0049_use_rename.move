@@ -0,0 +1 @@
+module M { use Std::Vector::{length as len}; }
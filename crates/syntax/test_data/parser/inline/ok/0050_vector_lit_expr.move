@@ -0,0 +1,8 @@
+script {
+    fun main() {
+        let a = vector[];
+        let b = vector[1, 2, 3,];
+        let c = vector<u8>[];
+        let d = vector<vector<u8>>[vector[1], b"2"];
+    }
+}
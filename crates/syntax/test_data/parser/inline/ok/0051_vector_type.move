@@ -0,0 +1 @@
+module M { fun f(v: vector<u8>, r: &vector<address>) {} }
@@ -0,0 +1,6 @@
+script {
+    fun main() {
+        while (true) {};
+        while (i < 10) i = i + 1;
+    }
+}
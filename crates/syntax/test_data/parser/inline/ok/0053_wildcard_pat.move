@@ -0,0 +1 @@
+script { fun main() { let _ = f(); let (_, x) = g(); } }
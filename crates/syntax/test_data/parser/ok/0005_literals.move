@@ -5,7 +5,7 @@ script {
         @StdAddr;
         true;
         false;
-        x"11122";
+        x"111222";
         b"1122";
     }
 }
\ No newline at end of file
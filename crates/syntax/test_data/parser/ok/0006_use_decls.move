@@ -0,0 +1,12 @@
+module M {
+    use 0x1::Signer;
+    use Std::Vector as V;
+    use Std::Vector::{Self, length as len};
+    use 0x1::Event::{};
+
+    fun main() {}
+}
+script {
+    use 0x1::Signer;
+    fun main() {}
+}
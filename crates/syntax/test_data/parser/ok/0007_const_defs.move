@@ -0,0 +1,11 @@
+module M {
+    const ENOT_OWNER: u64 = 1;
+    const MAX: u128 = 340282366920938463463374607431768211455;
+    const ADMIN: address = @0x1;
+    const ENABLED: bool = true;
+    const SEED: u8 = 1 + 2 * 3;
+}
+script {
+    const AMOUNT: u64 = 100;
+    fun main() {}
+}
@@ -0,0 +1,11 @@
+module M {
+    public fun a() {}
+    public(script) fun b() {}
+    public(friend) fun c() {}
+    native fun d();
+    native public fun e(): u64;
+    public native fun f(): u64;
+    public entry fun g() {}
+    entry fun h() {}
+    fun entry() {}
+}
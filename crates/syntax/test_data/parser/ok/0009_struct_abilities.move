@@ -0,0 +1,9 @@
+module M {
+    struct Coin has key, store { value: u64 }
+    struct Wrapper<phantom T: store + drop, U: copy> has copy, drop { inner: U }
+    struct Empty {}
+    struct NoAbilities<T> { f: T, }
+    native struct Signer has drop;
+    native struct Handle<T: key>;
+    fun f<T: copy + drop>(x: T) {}
+}
@@ -0,0 +1,10 @@
+module M {
+    struct Balance has key { value: u64 }
+    struct Coin has key { value: u64 }
+
+    fun f(): u64 acquires Balance, Self::Coin, 0x1::M::R { 1 }
+
+    public fun g() acquires Balance {}
+
+    native fun h() acquires Coin;
+}
@@ -0,0 +1,19 @@
+module M {
+    fun f(a: bool, b: u64): u64 {
+        if (a) {} else {};
+        if (a) 1 else if (b > 2) 2 else 3;
+        if (a && b == 1 || !a) return 1 else abort 2;
+        let x = if (a) b + 1 else b * 2;
+        while (b < 10) b = b + 1;
+        while (true) {
+            if (b >= 20) break;
+            continue
+        };
+        loop {
+            b = b << 1 | 1 ^ b & 2;
+            if (b != 0) break
+        };
+        if (a) return;
+        x % 3 + b / 2 - 1
+    }
+}
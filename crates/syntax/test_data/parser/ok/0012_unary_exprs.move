@@ -0,0 +1,19 @@
+module M {
+    fun f(addr: address, r: &mut u64) acquires Counter {
+        let a = !b;
+        let x = &y;
+        let m = &mut borrow_global_mut<Counter>(addr).value;
+        *r = *m + 1;
+        *&mut x.f = 2;
+        !a && *x == *m;
+        let c = copy a;
+        consume(move x, copy c);
+        let v = Vector::empty<u64>();
+        let s = Pair<u64, bool> { first: 1, second: true };
+        let lt = a < b;
+        if (x <c) return;
+        borrow_global<Counter>(addr).value > 0
+    }
+
+    fun g(): Option<u64> { none<u64>() }
+}
@@ -0,0 +1,20 @@
+module M {
+    const BYTES: vector<u8> = vector[1, 2, 3];
+
+    fun pair(): (u64, bool) {
+        (1, true)
+    }
+
+    fun unit(): () { () }
+
+    fun f(v: vector<vector<u8>>, r: &mut vector<address>): Option<Option<u64>> {
+        let empty = vector<u8>[];
+        let nested = vector<vector<u8>>[vector[], b"ab"];
+        let (a, b) = pair();
+        let wide = (a as u128) * 2;
+        let narrow = ((wide >> 8) as u8);
+        let c = borrow_global<Coin<T>>(@0x1);
+        let p: (u64) = (a);
+        none<Option<u64>>()
+    }
+}
@@ -0,0 +1,31 @@
+#[test_only]
+module 0x1::M {
+    #[test_only]
+    use Std::Debug;
+
+    #[test_only]
+    const ERR: u64 = 1;
+
+    #[test_only]
+    struct S has drop {}
+
+    #[test]
+    fun a() {}
+
+    #[test(account = @0x1, other = @Std)]
+    #[expected_failure(abort_code = 0x1::Errors::ENOT_FOUND)]
+    public fun b(account: signer, other: signer) {}
+
+    #[allow(unused,), deprecated]
+    native fun c();
+}
+
+address 0x2 {
+    #[test_only]
+    module N {}
+}
+
+#[test_only]
+script {
+    fun main() {}
+}
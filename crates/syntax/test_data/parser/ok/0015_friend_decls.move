@@ -0,0 +1,14 @@
+module 0x1::Coin {
+    friend 0x1::Pool;
+    friend Std::Vector;
+    #[test_only]
+    friend Tests;
+
+    public(friend) fun mint(): u64 { 1 }
+}
+
+script {
+    use 0x1::Coin;
+    const AMOUNT: u64 = 10;
+    fun main() { Coin::mint(); }
+}
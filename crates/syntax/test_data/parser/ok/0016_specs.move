@@ -0,0 +1,49 @@
+module 0x1::Counter {
+    struct Counter has key { value: u64 }
+
+    spec Counter {
+        invariant value < MAX;
+        invariant update [abstract] old(value) <= value;
+    }
+
+    public fun increment(addr: address, by: u64): u64 acquires Counter {
+        let c = borrow_global_mut<Counter>(addr);
+        spec { assert c.value + by <= MAX; };
+        c.value = c.value + by;
+        c.value
+    }
+
+    spec increment {
+        pragma opaque, timeout = 100;
+        let post new_value = global<Counter>(addr).value;
+        requires by > 0;
+        aborts_if !exists<Counter>(addr);
+        aborts_if old(global<Counter>(addr).value) + by > MAX with 1;
+        ensures result == new_value;
+        ensures by > 0 ==> result > old(global<Counter>(addr).value);
+        ensures (by == 0) <==> (result == old(global<Counter>(addr).value));
+        modifies global<Counter>(addr);
+        include IncrementAbortsIf { addr };
+    }
+
+    spec schema IncrementAbortsIf<T> {
+        addr: address;
+        local total: u64;
+        aborts_if !exists<Counter>(addr);
+    }
+
+    spec module {
+        pragma verify = true;
+        global sum_of_counters: num;
+        invariant forall a: address where exists<Counter>(a): global<Counter>(a).value < MAX;
+        fun is_sorted(v: vector<u64>): bool {
+            forall i in 0..len(v), j in 0..len(v) where i < j: v[i] <= v[j]
+        }
+        native fun uninterpreted(x: u64): u64;
+        axiom exists x: u64: x > 0;
+    }
+
+    spec fun helper {
+        ensures result == choose x: u64 where x > 0;
+    }
+}
@@ -0,0 +1,15 @@
+address Std {
+    module Vector {}
+}
+
+module Std::Signer {
+    public fun address_of(): address { @Std }
+}
+
+module 0x1::Coins {
+    use Std::Signer;
+
+    const OWNER: address = @0x42;
+
+    fun is_owner(a: address): bool { a == OWNER || a == @Std }
+}
@@ -0,0 +1,26 @@
+/// A coin.
+/** Really. */
+module 0x1::Coin {
+    /* a block comment */
+    use 0x1::Signer;
+
+    /* outer /* nested */ still a comment */
+    /// The coin resource.
+    struct Coin has key {
+        /// Amount of coins.
+        value: u64,
+    }
+
+    /// Detached by the blank line below.
+
+    fun f(/* inline */ x: u64): u64 { x /**/ }
+
+    /**
+     * Mints a coin.
+     */
+    #[test_only]
+    public fun mint(): u64 { 1 }
+
+    //// not a doc comment
+    const A: u64 = 1;
+}
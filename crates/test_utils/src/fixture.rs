@@ -75,6 +75,8 @@ pub struct Fixture {
     pub cfg_key_values: Vec<(String, String)>,
     pub edition: Option<String>,
     pub env: FxHashMap<String, String>,
+    /// Named addresses of the workspace, like `addresses:Std=0x1`.
+    pub addresses: FxHashMap<String, String>,
     pub introduce_new_source_root: Option<String>,
 }
 
@@ -116,26 +118,124 @@ impl Fixture {
                 );
             }
 
-            // if line.starts_with("//-") {
-            //     let meta = Fixture::parse_meta_line(line);
-            //     res.push(meta)
-            // } else {
-            if line.starts_with("// ")
-                && line.contains(':')
-                && !line.contains("::")
-                && line.chars().all(|it| !it.is_uppercase())
-            {
-                panic!("looks like invalid metadata line: {:?}", line)
-            }
+            if line.starts_with("//-") {
+                let meta = Fixture::parse_meta_line(line);
+                res.push(meta)
+            } else {
+                if line.starts_with("// ")
+                    && line.contains(':')
+                    && !line.contains("::")
+                    && line.chars().all(|it| !it.is_uppercase())
+                {
+                    panic!("looks like invalid metadata line: {:?}", line)
+                }
 
-            if let Some(entry) = res.last_mut() {
-                entry.text.push_str(line);
+                if let Some(entry) = res.last_mut() {
+                    entry.text.push_str(line);
+                }
             }
-            // }
         }
 
         res
     }
+
+    //- /lib.rs crate:foo deps:bar,baz cfg:foo=a,bar=b env:OUTDIR=path/to,OTHER=foo
+    fn parse_meta_line(meta: &str) -> Fixture {
+        assert!(meta.starts_with("//-"));
+        let meta = meta["//-".len()..].trim();
+        let components = meta.split_ascii_whitespace().collect::<Vec<_>>();
+
+        let path = components[0].to_string();
+        assert!(path.starts_with('/'), "fixture path does not start with `/`: {:?}", path);
+
+        let mut krate = None;
+        let mut deps = Vec::new();
+        let mut extern_prelude = None;
+        let mut edition = None;
+        let mut cfg_atoms = Vec::new();
+        let mut cfg_key_values = Vec::new();
+        let mut env = FxHashMap::default();
+        let mut addresses = FxHashMap::default();
+        let mut introduce_new_source_root = None;
+        for component in components[1..].iter() {
+            let (key, value) = component
+                .split_once(':')
+                .unwrap_or_else(|| panic!("invalid meta line: {:?}", meta));
+            match key {
+                "crate" => krate = Some(value.to_string()),
+                "deps" => deps = value.split(',').map(|it| it.to_string()).collect(),
+                "extern-prelude" => {
+                    extern_prelude = if value.is_empty() {
+                        Some(Vec::new())
+                    } else {
+                        Some(value.split(',').map(|it| it.to_string()).collect())
+                    }
+                }
+                "edition" => edition = Some(value.to_string()),
+                "cfg" => {
+                    for entry in value.split(',') {
+                        match entry.split_once('=') {
+                            Some((k, v)) => cfg_key_values.push((k.to_string(), v.to_string())),
+                            None => cfg_atoms.push(entry.to_string()),
+                        }
+                    }
+                }
+                "env" => {
+                    for key in value.split(',') {
+                        if let Some((k, v)) = key.split_once('=') {
+                            env.insert(k.into(), v.into());
+                        }
+                    }
+                }
+                "addresses" => {
+                    for entry in value.split(',') {
+                        if let Some((k, v)) = entry.split_once('=') {
+                            addresses.insert(k.into(), v.into());
+                        }
+                    }
+                }
+                "new_source_root" => introduce_new_source_root = Some(value.to_string()),
+                _ => panic!("bad component: {:?}", component),
+            }
+        }
+
+        Fixture {
+            path,
+            text: String::new(),
+            krate,
+            deps,
+            extern_prelude,
+            cfg_atoms,
+            cfg_key_values,
+            edition,
+            env,
+            addresses,
+            introduce_new_source_root,
+        }
+    }
+}
+
+#[test]
+fn parse_fixture_gets_full_meta() {
+    let parsed = Fixture::parse(
+        r"
+    //- /main.move new_source_root:library addresses:Std=0x1,Owner=0x2
+    module 0x1::M {}
+    //- /lib.move
+    module 0x1::L {}
+    ",
+    );
+    assert_eq!(parsed.len(), 2);
+
+    let meta = &parsed[0];
+    assert_eq!("module 0x1::M {}\n", meta.text);
+    assert_eq!("/main.move", meta.path);
+    assert_eq!(Some("library".to_string()), meta.introduce_new_source_root);
+    assert_eq!("0x1", meta.addresses["Std"]);
+    assert_eq!("0x2", meta.addresses["Owner"]);
+
+    assert_eq!("/lib.move", parsed[1].path);
+    assert_eq!(None, parsed[1].introduce_new_source_root);
 }
 
 #[test]
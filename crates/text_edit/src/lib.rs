@@ -0,0 +1,32 @@
+//! Representation of a text edit. The LSP converts incoming document changes
+//! into these, and the syntax tree uses them to reparse incrementally.
+
+pub use text_size::{TextRange, TextSize};
+
+/// A single deletion followed by an insertion at the same place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Indel {
+    pub insert: String,
+    /// Refers to offsets in the original text
+    pub delete: TextRange,
+}
+
+impl Indel {
+    pub fn insert(offset: TextSize, text: String) -> Indel {
+        Indel::replace(TextRange::empty(offset), text)
+    }
+
+    pub fn delete(range: TextRange) -> Indel {
+        Indel::replace(range, String::new())
+    }
+
+    pub fn replace(range: TextRange, replace_with: String) -> Indel {
+        Indel { delete: range, insert: replace_with }
+    }
+
+    pub fn apply(&self, text: &mut String) {
+        let start: usize = self.delete.start().into();
+        let end: usize = self.delete.end().into();
+        text.replace_range(start..end, &self.insert);
+    }
+}